@@ -0,0 +1,105 @@
+//! Benchmarks for the hot paths of the framework: inner products, time evolution, and sampling
+//! superpositions over a grid as the frontend does every frame.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use qwaviz::{framework::core::vectorspace::VectorSpace, prelude::*};
+
+/// Benchmark a single inner product `<n|m>` of harmonic well eigenstates at several grid sizes
+fn inner_product(c: &mut Criterion) {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let bra = Ket::adjoint(&hw.energy_eigenstate(3));
+    let ket = hw.energy_eigenstate(5);
+
+    let mut group = c.benchmark_group("inner_product");
+    for points in [100, 1_000, 10_000] {
+        let step_size = 16.0 / points as f32;
+        group.bench_with_input(
+            BenchmarkId::from_parameter(points),
+            &step_size,
+            |b, &step_size| b.iter(|| bra.apply(black_box(&ket), 0.5, step_size)),
+        );
+    }
+    group.finish();
+}
+
+/// Benchmark evolving a displaced gaussian in a harmonic well for an increasing number of
+/// eigenstates
+fn evolution(c: &mut Criterion) {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let initial_state = Ket::new(
+        |x: f32, _| Complex32::new((-(x - 1.0).powi(2)).exp(), 0.0),
+        SubDomain1D {
+            lower: -8.0,
+            upper: 8.0,
+        },
+    );
+
+    let mut group = c.benchmark_group("evolution");
+    for max_n in [5, 10, 20, 40] {
+        group.bench_with_input(BenchmarkId::from_parameter(max_n), &max_n, |b, &max_n| {
+            b.iter(|| hw.evolution(black_box(&initial_state), 0.0, 0.01, 1, max_n));
+        });
+    }
+    group.finish();
+}
+
+/// Benchmark sampling a 30 term superposition over the whole grid, as is done when rendering
+/// each frame
+fn superposition_sampling(c: &mut Criterion) {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let terms: Vec<(Complex32, i32)> = (1..=30)
+        .map(|n| (Complex32::new(1.0 / n as f32, 0.0), n))
+        .collect();
+    let superposition = hw.superposition(&terms);
+    let simplified = superposition.clone().simplify();
+
+    let mut group = c.benchmark_group("superposition_sampling");
+    group.bench_function("raw", |b| {
+        b.iter(|| superposition.sample_grid(&superposition.subdomain, black_box(0.5), 0.01));
+    });
+    group.bench_function("simplified", |b| {
+        b.iter(|| simplified.sample_grid(&simplified.subdomain, black_box(0.5), 0.01));
+    });
+    group.finish();
+}
+
+/// Benchmark building a 100 term weighted sum of eigenstates sharing one subdomain, against the
+/// same sum with each term on a slightly different subdomain
+fn weighted_sum(c: &mut Criterion) {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let same: Vec<(Complex32, Ket<Sign1D>)> = (1..=100)
+        .map(|n| (Complex32::new(1.0 / n as f32, 0.0), hw.energy_eigenstate(n)))
+        .collect();
+    let mixed: Vec<(Complex32, Ket<Sign1D>)> = same
+        .iter()
+        .enumerate()
+        .map(|(i, (c, ket))| {
+            let half_width = 8.0 - i as f32 * 0.01;
+            let region = SubDomain1D {
+                lower: -half_width,
+                upper: half_width,
+            };
+            (*c, ket.clone().restrict(region))
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("weighted_sum");
+    group.bench_function("same_subdomain", |b| {
+        b.iter(|| Ket::weighted_sum(black_box(same.clone())));
+    });
+    group.bench_function("mixed_subdomains", |b| {
+        b.iter(|| Ket::weighted_sum(black_box(mixed.clone())));
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    inner_product,
+    evolution,
+    superposition_sampling,
+    weighted_sum
+);
+criterion_main!(benches);
@@ -4,18 +4,28 @@
 use qwaviz::prelude::*;
 
 fn main() {
-    let ket = square_in_harmonic_well();
-    run_viz_1d(ket);
+    let (ket_0, hw, params) = square_in_harmonic_well();
+    run_viz_evolution(
+        hw,
+        &ket_0,
+        0.001,
+        1,
+        128,
+        params,
+        CacheInterpolation::CatmullRom,
+    );
 }
 
 /* -------------------------------------------------------------------------- */
 /*                          Some demo wavefunctions:                          */
 /* -------------------------------------------------------------------------- */
 
-/// A square wave offset from the centre of a harmonic well
+/// A square wave offset from the centre of a harmonic well, to be evolved in
+/// the well
 #[allow(unused)]
-fn square_in_harmonic_well() -> Ket<Sign1D> {
-    let hw = HarmonicWell::new(10.0, 1.0, 1.0, 4.0);
+fn square_in_harmonic_well() -> (Ket<Sign1D>, HarmonicWell, PhysicalParams) {
+    let (mass, hbar) = (1.0, 1.0);
+    let hw = HarmonicWell::new(10.0, mass, hbar, 4.0);
     let ket_0 = Ket::new(
         |_, _| Complex32::ONE,
         SubDomain1D {
@@ -24,13 +34,15 @@ fn square_in_harmonic_well() -> Ket<Sign1D> {
         },
     )
     .translate_space(1.5);
-    hw.evolution(&ket_0, 0.0, 0.001, 1, 128)
+    (ket_0, hw, PhysicalParams { mass, hbar })
 }
 
-/// The ground state of a small infinite square well in a larger one
+/// The ground state of a small infinite square well, to be evolved in a
+/// larger one
 #[allow(unused)]
-fn sudden_isw_expansion() -> Ket<Sign1D> {
-    let isw = InfiniteSquareWell::new(2.0, 1.0, 2.0);
+fn sudden_isw_expansion() -> (Ket<Sign1D>, InfiniteSquareWell, PhysicalParams) {
+    let (mass, hbar) = (1.0, 2.0);
+    let isw = InfiniteSquareWell::new(2.0, mass, hbar);
     let ket_0 = isw.expansion_state(1.0, 1);
-    isw.evolution(&ket_0, 0.0, 0.001, 1, 512)
+    (ket_0, isw, PhysicalParams { mass, hbar })
 }
@@ -0,0 +1,46 @@
+//! A test program rendering the probability density of 2-D quantum states
+//! as a heatmap.
+
+use std::f32::consts::SQRT_2;
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let ket = displaced_in_harmonic_well_2d();
+    run_viz_2d(ket, Point2D::new(0.02, 0.02), Colormap::Viridis);
+}
+
+/* -------------------------------------------------------------------------- */
+/*                          Some demo wavefunctions:                          */
+/* -------------------------------------------------------------------------- */
+
+/// The ground state of a 2D harmonic well
+#[allow(unused)]
+fn harmonic_well_2d_ground() -> Ket<Sign2D> {
+    let hw = HarmonicWell2D::new(2.0, 2.0, 1.0, 1.0, 3.0);
+    hw.energy_eigenstate(1)
+}
+
+/// A superposition of the ground state and degenerate first excited states of
+/// a 2D harmonic well, which circles the centre of the well
+#[allow(unused)]
+fn displaced_in_harmonic_well_2d() -> Ket<Sign2D> {
+    let hw = HarmonicWell2D::new(2.0, 2.0, 1.0, 1.0, 3.0);
+    hw.superposition(&[
+        (Complex32::new(0.7, 0.0), 1),
+        (Complex32::new(0.5, 0.0), 2),
+        (Complex32::new(0.0, 0.5), 3),
+    ])
+}
+
+/// A superposition of states of an anisotropic 2D harmonic well with
+/// incommensurate frequencies, whose probability density traces out a
+/// Lissajous-like pattern
+#[allow(unused)]
+fn lissajous_in_harmonic_well_2d() -> Ket<Sign2D> {
+    let hw = HarmonicWell2D::new(2.0, 2.0 * SQRT_2, 1.0, 1.0, 3.0).with_rotation(0.3);
+    hw.eigenstate_xy(0, 0)
+        + hw.eigenstate_xy(1, 0)
+        + hw.eigenstate_xy(0, 1)
+        + hw.eigenstate_xy(2, 1)
+}
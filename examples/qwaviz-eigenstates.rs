@@ -0,0 +1,15 @@
+//! A test program stepping through the energy eigenstates of a 1-D system.
+//! Use the up and down arrow keys to change eigenstate.
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let (mass, hbar) = (1.0, 1.0);
+    let hw = HarmonicWell::new(10.0, mass, hbar, 4.0);
+    run_viz_eigenstates(
+        hw,
+        16,
+        PhysicalParams { mass, hbar },
+        CacheInterpolation::CatmullRom,
+    );
+}
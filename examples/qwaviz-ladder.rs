@@ -0,0 +1,9 @@
+//! A test program drawing the energy eigenstates of a 1-D system stacked by
+//! energy inside its potential.
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let hw = HarmonicWell::new(2.0, 1.0, 1.0, 4.0);
+    run_viz_ladder(hw, 1, 6);
+}
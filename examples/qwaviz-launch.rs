@@ -0,0 +1,21 @@
+//! A test program visualising user-built kets with the `Qwaviz` builder: a
+//! gaussian packet with momentum, and the same packet at rest drawn behind it.
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let packet = |momentum: f32| {
+        Ket::<Sign1D>::new(
+            move |x: f32, _| (-x * x).exp() * Complex32::cis(momentum * x),
+            SubDomain1D {
+                lower: -4.0,
+                upper: 4.0,
+            },
+        )
+    };
+    Qwaviz::new()
+        .add_ket(packet(5.0))
+        .add_ket_behind(packet(0.0), 3.0)
+        .with_interpolation(CacheInterpolation::CatmullRom)
+        .run();
+}
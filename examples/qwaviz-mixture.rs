@@ -0,0 +1,9 @@
+//! A test program drawing the probability density of a thermal state of a
+//! harmonic well, which is smooth as its eigenstates do not interfere.
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    run_viz_mixture(hw.thermal_state(2.0, 1, 20));
+}
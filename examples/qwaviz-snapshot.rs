@@ -0,0 +1,20 @@
+//! A test program rendering the third eigenstate of an infinite square well
+//! to an image file, without any interaction. The output path may be given as
+//! the first argument.
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "isw_3.png".to_owned());
+    let (mass, hbar) = (1.0, 1.0);
+    let isw = InfiniteSquareWell::new(4.0, mass, hbar);
+    render_snapshot(
+        isw,
+        &[(Complex32::ONE, 3)],
+        0.5,
+        path,
+        PhysicalParams { mass, hbar },
+    );
+}
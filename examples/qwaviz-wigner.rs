@@ -0,0 +1,23 @@
+//! A test program drawing the Wigner function of an equal superposition of
+//! the first two excited states of a harmonic well, whose interference
+//! fringes dip below zero.
+
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use qwaviz::prelude::*;
+
+fn main() {
+    let (mass, hbar) = (1.0, 1.0);
+    let hw = HarmonicWell::new(1.0, mass, hbar, 5.0);
+    let ket = hw.superposition(&[
+        (Complex32::new(FRAC_1_SQRT_2, 0.0), 2),
+        (Complex32::new(FRAC_1_SQRT_2, 0.0), 3),
+    ]);
+    run_viz_wigner(
+        &ket,
+        (-5.0, 5.0),
+        Point2D::new(0.05, 0.05),
+        0.0,
+        PhysicalParams { mass, hbar },
+    );
+}
@@ -2,4 +2,6 @@
 
 pub mod braket;
 pub mod core;
+pub mod evolution;
+pub mod transform;
 pub mod wavefunction;
@@ -1,12 +1,34 @@
 //! Bra-ket notation, where bras are adjoints of kets and vice versa. Each form a vector space, with
 //! bras living in the dual space of kets. Applying a bra to a ket results in an inner product.
 
+mod adaptive_1d;
+mod density_matrix;
+mod energy_1d;
+mod matrix_elements;
+mod observables_1d;
 mod operations;
+mod operator;
+mod orthonormal;
+mod quadrature_1d;
+mod samples_1d;
+mod survival;
+mod tensor;
 mod wf_bra;
 mod wf_ket;
+mod wigner_1d;
 
+pub use density_matrix::DensityMatrix;
+pub use energy_1d::EnergyBreakdown;
+pub use matrix_elements::{matrix_element, operator_matrix};
 pub use operations::WFFunc;
+pub use operations::WFMap;
 pub use operations::WFOperation;
+pub use operator::{MultiplyByPosition, Operator};
+pub use orthonormal::gram_schmidt;
+pub use quadrature_1d::IntegrationMethod;
+pub use samples_1d::Interp;
+pub use survival::survival_probability;
+pub use tensor::tensor;
 pub use wf_bra::Bra;
 pub use wf_ket::Ket;
 
@@ -0,0 +1,98 @@
+//! Adaptive integration of inner products of 1D wavefunctions.
+
+use num_complex::Complex32;
+
+use super::super::{
+    core::domain::SubDomain1D,
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+use super::{AbstractKet, Bra, Ket};
+
+/// The number of times the integration interval is always halved, so that the
+/// integrand is sampled at least `2^MIN_DEPTH + 1` times before adapting. Features
+/// much narrower than the subdomain may otherwise be missed entirely.
+const MIN_DEPTH: u32 = 5;
+/// The maximum number of times the integration interval may be halved, which bounds
+/// the number of samples when the tolerance cannot be met
+const MAX_DEPTH: u32 = 20;
+
+/// The samples of the integrand at the ends and midpoint of an interval
+#[derive(Clone, Copy)]
+struct Panel {
+    /// The lower end of the interval, and the value there
+    a: (f32, Complex32),
+    /// The midpoint of the interval, and the value there
+    m: (f32, Complex32),
+    /// The upper end of the interval, and the value there
+    b: (f32, Complex32),
+}
+
+impl Panel {
+    /// Sample a new panel over `[a, b]`, given the samples at the ends
+    fn new(f: &impl Fn(f32) -> Complex32, a: (f32, Complex32), b: (f32, Complex32)) -> Self {
+        let m = 0.5 * (a.0 + b.0);
+        Self { a, m: (m, f(m)), b }
+    }
+
+    /// The Simpson's rule estimate of the integral over the panel
+    fn simpson(&self) -> Complex32 {
+        (self.b.0 - self.a.0) / 6.0 * (self.a.1 + 4.0 * self.m.1 + self.b.1)
+    }
+}
+
+/// Recursively integrate over a panel with Simpson's rule, subdividing until the
+/// estimated error is within `tol`. Subdivision also stops once the panel can no longer
+/// be split in `f32`, or the error estimate is not finite, as halving cannot improve it.
+fn adaptive_simpson(
+    f: &impl Fn(f32) -> Complex32,
+    panel: Panel,
+    whole: Complex32,
+    tol: f32,
+    depth: u32,
+) -> Complex32 {
+    if panel.m.0 == panel.a.0 || panel.m.0 == panel.b.0 {
+        return whole;
+    }
+    let left = Panel::new(f, panel.a, panel.m);
+    let right = Panel::new(f, panel.m, panel.b);
+    let (left_est, right_est) = (left.simpson(), right.simpson());
+    let delta = left_est + right_est - whole;
+
+    if depth >= MAX_DEPTH
+        || !delta.is_finite()
+        || (depth >= MIN_DEPTH && delta.norm() <= 15.0 * tol)
+    {
+        return left_est + right_est + delta / 15.0;
+    }
+    adaptive_simpson(f, left, left_est, 0.5 * tol, depth + 1)
+        + adaptive_simpson(f, right, right_est, 0.5 * tol, depth + 1)
+}
+
+impl Bra<Sign1D> {
+    /// Apply this bra to a ket at time `t` via adaptive Simpson integration over the
+    /// intersection of their subdomains, to within an absolute tolerance `tol`.
+    /// Unlike `apply`, no step size is needed: samples are concentrated where the
+    /// integrand varies quickly, such as around sharply peaked wavefunctions.
+    pub fn apply_adaptive(&self, ket: &Ket<Sign1D>, t: f32, tol: f32) -> Complex32 {
+        let SubDomain1D { lower, upper } = ket.subdomain.clone() * self.subdomain.clone();
+        if lower >= upper {
+            return Complex32::ZERO;
+        }
+
+        let integrand = |x: f32| self.f(x, t) * ket.f(x, t);
+        let panel = Panel::new(
+            &integrand,
+            (lower, integrand(lower)),
+            (upper, integrand(upper)),
+        );
+        adaptive_simpson(&integrand, panel, panel.simpson(), tol, 0)
+    }
+}
+
+impl Ket<Sign1D> {
+    /// Compute the squared norm at time `t` via adaptive Simpson integration, to within
+    /// an absolute tolerance `tol`
+    pub fn norm_sqr_adaptive(&self, t: f32, tol: f32) -> f32 {
+        Ket::adjoint(self).apply_adaptive(self, t, tol).re
+    }
+}
@@ -0,0 +1,78 @@
+//! Mixed states, as incoherent mixtures of pure states.
+
+use super::super::{
+    core::{
+        domain::SubDomain,
+        field::{Field, RealField},
+    },
+    wavefunction::{Wavefunction, signature::WFSignature},
+};
+use super::Ket;
+
+/// A mixed state `rho = sum_n p_n |psi_n><psi_n|`, held as the pure states `|psi_n>` of an
+/// ensemble along with the probability `p_n` of each. Unlike a superposition, the pure states
+/// do not interfere, so the probability density is the weighted sum of their densities.
+#[derive(Clone)]
+pub struct DensityMatrix<S: WFSignature> {
+    /// The probability of each pure state in the mixture, along with the state itself
+    states: Vec<(f32, Ket<S>)>,
+}
+
+impl<S: WFSignature> DensityMatrix<S> {
+    /// Return the mixture of the given `(p_n, |psi_n>)` pure states. The probabilities are
+    /// rescaled to sum to 1, and each state should be normalised.
+    ///
+    /// # Panics
+    /// Panics if the probabilities do not have a positive sum.
+    #[must_use]
+    pub fn new(states: Vec<(f32, Ket<S>)>) -> Self {
+        let total: f32 = states.iter().map(|(p, _)| p).sum();
+        assert!(
+            total > 0.0,
+            "Probabilities of a DensityMatrix must have a positive sum."
+        );
+        Self {
+            states: states
+                .into_iter()
+                .map(|(p, ket)| (p / total, ket))
+                .collect(),
+        }
+    }
+
+    /// Return the pure state `|psi><psi|`
+    #[must_use]
+    pub fn pure(ket: Ket<S>) -> Self {
+        Self {
+            states: vec![(1.0, ket)],
+        }
+    }
+
+    /// Return the probability of each pure state in the mixture, along with the state itself
+    pub fn states(&self) -> &[(f32, Ket<S>)] {
+        &self.states
+    }
+
+    /// Return the union of the subdomains of the pure states in the mixture
+    pub fn subdomain(&self) -> S::SubDom {
+        self.states
+            .iter()
+            .map(|(_, ket)| ket.subdomain.clone())
+            .reduce(|a, b| a + b)
+            .unwrap_or_else(S::SubDom::none)
+    }
+
+    /// Return the probability density `<x|rho|x> = sum_n p_n |psi_n(x, t)|^2` of the mixture
+    pub fn density(&self, x: S::Space, t: S::Time) -> f32 {
+        self.states
+            .iter()
+            .map(|(p, ket)| p * ket.f(x, t).modulus().to_f32().powi(2))
+            .sum()
+    }
+
+    /// Return the purity `tr(rho^2) = sum_n p_n^2`, which is 1 for pure states and smaller for
+    /// mixtures. This assumes the pure states in the mixture are orthogonal, as for
+    /// eigenstates.
+    pub fn purity(&self) -> f32 {
+        self.states.iter().map(|(p, _)| p * p).sum()
+    }
+}
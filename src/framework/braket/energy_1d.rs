@@ -0,0 +1,69 @@
+//! Kinetic and potential energy expectation values of 1D kets.
+
+use num_complex::Complex32;
+
+use super::super::wavefunction::{Wavefunction, signature::Sign1D};
+use super::Ket;
+
+/// Splits the energy of a 1D ket into its kinetic and potential parts, e.g. to check the virial
+/// theorem for bound states.
+pub struct EnergyBreakdown<'a, V: Fn(f32) -> f32> {
+    /// The ket whose energy is broken down
+    ket: &'a Ket<Sign1D>,
+    /// The mass of the particle
+    mass: f32,
+    /// The value to use for the reduced Planck's constant
+    hbar: f32,
+    /// The potential as a function of position
+    potential: V,
+}
+
+impl<'a, V: Fn(f32) -> f32> EnergyBreakdown<'a, V> {
+    /// Create a new energy breakdown of `ket` for a particle of the given mass moving in the
+    /// given potential.
+    pub fn new(ket: &'a Ket<Sign1D>, mass: f32, hbar: f32, potential: V) -> Self {
+        Self {
+            ket,
+            mass,
+            hbar,
+            potential,
+        }
+    }
+
+    /// Return the kinetic energy `<psi| -hbar^2/2m d^2/dx^2 |psi> / <psi|psi>` at time `t`,
+    /// with the second derivative taken by central differences.
+    pub fn kinetic(&self, t: f32, step_size: f32) -> f32 {
+        let (norm, kinetic) = self
+            .ket
+            .iter_with_step_size(step_size)
+            .map(|x| {
+                let value = self.ket.f(x, t);
+                let laplacian = (self.ket.f(x + step_size, t) - 2.0 * value
+                    + self.ket.f(x - step_size, t))
+                    / (step_size * step_size);
+                let kinetic: Complex32 =
+                    -self.hbar * self.hbar / (2.0 * self.mass) * value.conj() * laplacian;
+                (value.norm_sqr() * step_size, kinetic.re * step_size)
+            })
+            .fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+        kinetic / norm
+    }
+
+    /// Return the potential energy `<psi| V(x) |psi> / <psi|psi>` at time `t`
+    pub fn potential(&self, t: f32, step_size: f32) -> f32 {
+        let (norm, potential) = self
+            .ket
+            .iter_with_step_size(step_size)
+            .map(|x| {
+                let density = self.ket.p(x, t).re * step_size;
+                (density, (self.potential)(x) * density)
+            })
+            .fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+        potential / norm
+    }
+
+    /// Return the total energy `<T> + <V>` at time `t`
+    pub fn total(&self, t: f32, step_size: f32) -> f32 {
+        self.kinetic(t, step_size) + self.potential(t, step_size)
+    }
+}
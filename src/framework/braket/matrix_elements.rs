@@ -0,0 +1,39 @@
+//! Matrix elements of multiplicative operators between kets.
+
+use super::super::wavefunction::signature::WFSignature;
+use super::{AbstractBra, AbstractKet, Ket, WFOperation};
+
+/// Compute the matrix element `<bra|V|ket>` at time `t` of the multiplicative operator `V`,
+/// given as a function of space and time such as a potential.
+pub fn matrix_element<S: WFSignature>(
+    bra: &Ket<S>,
+    op: &WFOperation<S>,
+    ket: &Ket<S>,
+    t: S::Time,
+    step_size: S::Space,
+) -> S::Out {
+    let operated = Ket {
+        wavefunction: WFOperation::product(op.clone(), ket.wavefunction.clone()),
+        subdomain: ket.subdomain.clone(),
+    };
+    Ket::adjoint(bra).apply(&operated, t, step_size)
+}
+
+/// Compute the matrix `V_mn = <m|V|n>` at time `t` of the multiplicative operator `V` in the
+/// given basis, e.g. for assembling the perturbation matrix over a set of eigenstates.
+pub fn operator_matrix<S: WFSignature>(
+    basis: &[Ket<S>],
+    op: &WFOperation<S>,
+    t: S::Time,
+    step_size: S::Space,
+) -> Vec<Vec<S::Out>> {
+    basis
+        .iter()
+        .map(|bra| {
+            basis
+                .iter()
+                .map(|ket| matrix_element(bra, op, ket, t, step_size))
+                .collect()
+        })
+        .collect()
+}
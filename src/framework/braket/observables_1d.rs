@@ -0,0 +1,70 @@
+//! Position and momentum statistics of 1D kets.
+
+use num_complex::Complex32;
+
+use super::super::{
+    core::domain::SubDomain,
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+use super::Ket;
+
+impl Ket<Sign1D> {
+    /// Return the expectation value of position `<x>` at time `t`
+    pub fn expectation_x(&self, t: f32, step_size: f32) -> f32 {
+        let (norm, x, _) = self.position_moments(t, step_size);
+        x / norm
+    }
+
+    /// Return the position uncertainty `sqrt(<x^2> - <x>^2)` at time `t`
+    pub fn uncertainty_x(&self, t: f32, step_size: f32) -> f32 {
+        let (norm, x, x_sqr) = self.position_moments(t, step_size);
+        (x_sqr / norm - (x / norm).powi(2)).max(0.0).sqrt()
+    }
+
+    /// Return the expectation value of momentum `<p>` at time `t`
+    pub fn expectation_p(&self, t: f32, step_size: f32, hbar: f32) -> f32 {
+        let (norm, p, _) = self.momentum_moments(t, step_size, hbar);
+        p / norm
+    }
+
+    /// Return the momentum uncertainty `sqrt(<p^2> - <p>^2)` at time `t`.
+    /// Note that `<p^2>` is computed as `hbar^2 <psi'|psi'>`, which assumes the
+    /// wavefunction vanishes at the boundaries of its subdomain.
+    pub fn uncertainty_p(&self, t: f32, step_size: f32, hbar: f32) -> f32 {
+        let (norm, p, p_sqr) = self.momentum_moments(t, step_size, hbar);
+        (p_sqr / norm - (p / norm).powi(2)).max(0.0).sqrt()
+    }
+
+    /// Integrate `|psi|^2`, `x |psi|^2` and `x^2 |psi|^2` over the subdomain, with the same
+    /// trapezoidal weights as `apply`
+    fn position_moments(&self, t: f32, step_size: f32) -> (f32, f32, f32) {
+        self.subdomain
+            .iter_weighted::<f32>(step_size)
+            .map(|(x, weight)| {
+                let density = self.p(x, t).re * weight * step_size;
+                (density, x * density, x * x * density)
+            })
+            .fold((0.0, 0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2))
+    }
+
+    /// Integrate `|psi|^2`, `psi* p psi` and `|p psi|^2` over the subdomain,
+    /// with derivatives taken by central differences and the same trapezoidal
+    /// weights as `apply`
+    fn momentum_moments(&self, t: f32, step_size: f32, hbar: f32) -> (f32, f32, f32) {
+        self.subdomain
+            .iter_weighted::<f32>(step_size)
+            .map(|(x, weight)| {
+                let weight = weight * step_size;
+                let value = self.f(x, t);
+                let p_value =
+                    -Complex32::I * hbar * (self.f(x + step_size, t) - self.f(x - step_size, t))
+                        / (2.0 * step_size);
+                (
+                    value.norm_sqr() * weight,
+                    (value.conj() * p_value).re * weight,
+                    p_value.norm_sqr() * weight,
+                )
+            })
+            .fold((0.0, 0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2))
+    }
+}
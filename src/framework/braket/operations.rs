@@ -1,17 +1,33 @@
 //! Functionality for building and evaluating expression trees comprised of wavefunctions.
 
 use std::{
+    fmt,
     ops::{Add, Neg, Sub},
-    sync::Arc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
-use super::super::{core::field::Field, wavefunction::signature::WFSignature};
+use bevy::log::warn;
+use num_complex::Complex32;
+
+use super::super::{
+    core::{
+        domain::{Domain, SubDomain},
+        field::{Field, RealField},
+    },
+    wavefunction::signature::WFSignature,
+};
 
 /// A valid wavefunction with signature `S`
 pub type WFFunc<S> = dyn Fn(<S as WFSignature>::Space, <S as WFSignature>::Time) -> <S as WFSignature>::Out
     + Send
     + Sync;
 
+/// A pointwise map of wavefunction values with signature `S`
+pub type WFMap<S> = dyn Fn(<S as WFSignature>::Out) -> <S as WFSignature>::Out + Send + Sync;
+
 /// (Wrapper for) operations that can be done on the wavefunctions underlying bras (covectors) and kets (vectors)
 #[derive(Clone)]
 pub struct WFOperation<S: WFSignature>(WFOperationInner<S>);
@@ -25,6 +41,8 @@ enum WFOperationInner<S: WFSignature> {
     Sum(Arc<Vec<WFOperation<S>>>),
     /// Sum n wavefunctions pointwise with weights
     WeightedSum(Arc<Vec<(S::Out, WFOperation<S>)>>),
+    /// Multiply two wavefunctions pointwise
+    Product(Arc<WFOperation<S>>, Arc<WFOperation<S>>),
     /// Subtract two wavefunctions pointwise
     Sub(Arc<WFOperation<S>>, Arc<WFOperation<S>>),
     /// Scale a wavefunction by a scalar
@@ -33,10 +51,27 @@ enum WFOperationInner<S: WFSignature> {
     Neg(Arc<WFOperation<S>>),
     /// Take the adjoint of a wavefunction (conjugate pointwise)
     Adjoint(Arc<WFOperation<S>>),
+    /// Map a wavefunction pointwise through a function of its value
+    Map(Arc<WFMap<S>>, Arc<WFOperation<S>>),
     /// Translate the wave function in space
     TranslateSpace(S::Space, Arc<WFOperation<S>>),
     /// Translate the wave function in time
     TranslateTime(S::Time, Arc<WFOperation<S>>),
+    /// Reflect the wave function in space about the origin
+    ReflectSpace(Arc<WFOperation<S>>),
+    /// Dilate the wave function in space away from the origin by a factor
+    ScaleSpace(f32, Arc<WFOperation<S>>),
+    /// Evaluate the wave function at points wrapped into one period of a periodic subdomain
+    Wrap(S::SubDom, Arc<WFOperation<S>>),
+    /// Take the first derivative of the wave function along a spatial step by central
+    /// differences, scaled by a coefficient which includes the inverse of twice the step length
+    Derivative(S::Space, S::Out, Arc<WFOperation<S>>),
+    /// Take the second derivative of the wave function along a spatial step by central
+    /// differences, along with the inverse square of the step length
+    SecondDerivative(S::Space, S::Out, Arc<WFOperation<S>>),
+    /// Replace non-finite values of the wave function with zero, along with whether a
+    /// non-finite value has already been reported
+    Checked(Arc<AtomicBool>, Arc<WFOperation<S>>),
 }
 
 impl<S: WFSignature> WFOperation<S> {
@@ -57,6 +92,13 @@ impl<S: WFSignature> WFOperation<S> {
         Self(WFOperationInner::WeightedSum(Arc::new(summands)))
     }
 
+    /// Multiply two wavefunctions pointwise. This applies multiplicative operators such as
+    /// potentials to wavefunctions.
+    #[must_use]
+    pub fn product(f: Self, g: Self) -> Self {
+        Self(WFOperationInner::Product(Arc::new(f), Arc::new(g)))
+    }
+
     /// Scale a wavefunction by a scalar
     #[must_use]
     pub fn scale(s: S::Out, op: Self) -> Self {
@@ -68,14 +110,106 @@ impl<S: WFSignature> WFOperation<S> {
         Self(WFOperationInner::Adjoint(Arc::new(f)))
     }
 
-    /// Translate a wavefunction in space
+    /// Map a wavefunction pointwise through `f`, so that `psi(x) -> f(psi(x))`. Conjugation is
+    /// the special case given by `adjoint`.
+    pub fn map<F: Fn(S::Out) -> S::Out + Send + Sync + 'static>(f: F, op: Self) -> Self {
+        Self(WFOperationInner::Map(Arc::new(f), Arc::new(op)))
+    }
+
+    /// Translate a wavefunction in space. Translating an already translated wavefunction combines
+    /// the offsets, so the expression tree stays flat under repeated translations.
     pub fn translate_space(offset: S::Space, op: Self) -> Self {
-        Self(WFOperationInner::TranslateSpace(offset, Arc::new(op)))
+        match op.0 {
+            WFOperationInner::TranslateSpace(inner, f) => {
+                Self(WFOperationInner::TranslateSpace(inner + offset, f))
+            }
+            _ => Self(WFOperationInner::TranslateSpace(offset, Arc::new(op))),
+        }
     }
 
-    /// Translate a wavefunction in time
+    /// Translate a wavefunction in time. Translating an already translated wavefunction combines
+    /// the offsets, so the expression tree stays flat under repeated translations.
     pub fn translate_time(offset: S::Time, op: Self) -> Self {
-        Self(WFOperationInner::TranslateTime(offset, Arc::new(op)))
+        match op.0 {
+            WFOperationInner::TranslateTime(inner, f) => {
+                Self(WFOperationInner::TranslateTime(inner + offset, f))
+            }
+            _ => Self(WFOperationInner::TranslateTime(offset, Arc::new(op))),
+        }
+    }
+
+    /// Evaluate a wavefunction at points wrapped into one period of `subdomain`, so that a
+    /// wavefunction defined on one period of a ring extends periodically. Non-periodic
+    /// subdomains leave the wavefunction unchanged, and wrapping twice is the same as once.
+    pub fn wrap(subdomain: &S::SubDom, op: Self) -> Self {
+        match op.0 {
+            WFOperationInner::Wrap(..) => op,
+            _ if S::SubDom::PERIODIC => {
+                Self(WFOperationInner::Wrap(subdomain.clone(), Arc::new(op)))
+            }
+            _ => op,
+        }
+    }
+
+    /// Reflect a wavefunction in space about the origin
+    pub fn reflect_space(op: Self) -> Self {
+        Self(WFOperationInner::ReflectSpace(Arc::new(op)))
+    }
+
+    /// Dilate a wavefunction in space away from the origin by `factor`, so that `x -> x / factor`
+    pub fn scale_space(factor: f32, op: Self) -> Self {
+        Self(WFOperationInner::ScaleSpace(factor, Arc::new(op)))
+    }
+
+    /// Replace any non-finite (NaN or infinite) values of a wavefunction with zero, so that a
+    /// few bad samples, e.g. from overflowing special functions at extreme arguments, do not
+    /// poison integrals or rendering. The first non-finite value found is logged as a warning.
+    pub fn checked(op: Self) -> Self {
+        match op.0 {
+            WFOperationInner::Checked(..) => op,
+            _ => Self(WFOperationInner::Checked(
+                Arc::new(AtomicBool::new(false)),
+                Arc::new(op),
+            )),
+        }
+    }
+
+    /// Take the even part `(f(x) + f(-x)) / 2` of a wavefunction
+    pub fn even_part(op: Self) -> Self {
+        let half = S::Out::one() / (S::Out::one() + S::Out::one());
+        Self::weighted_sum(vec![(half, op.clone()), (half, Self::reflect_space(op))])
+    }
+
+    /// Take the odd part `(f(x) - f(-x)) / 2` of a wavefunction
+    pub fn odd_part(op: Self) -> Self {
+        let half = S::Out::one() / (S::Out::one() + S::Out::one());
+        Self::weighted_sum(vec![(half, op.clone()), (-half, Self::reflect_space(op))])
+    }
+}
+
+impl<S: WFSignature<Space = f32>> WFOperation<S> {
+    /// Take the second derivative of a wavefunction in space, using the central three-point
+    /// stencil `(f(x + dx) - 2 f(x) + f(x - dx)) / dx^2`. This is more accurate than
+    /// differentiating twice, as the stencil is half as wide.
+    pub fn laplacian(dx: f32, op: Self) -> Self {
+        Self(WFOperationInner::SecondDerivative(
+            dx,
+            S::Out::from_real(RealField::from_f64(1.0 / f64::from(dx * dx))),
+            Arc::new(op),
+        ))
+    }
+}
+
+impl<S: WFSignature<Space = f32, Out = Complex32>> WFOperation<S> {
+    /// Apply the momentum operator `-i hbar d/dx` to a wavefunction, using the central
+    /// difference `(f(x + h) - f(x - h)) / 2h`. The step `h` is independent of any step size
+    /// used to integrate the result, so either can be refined without the other.
+    pub fn momentum(hbar: f32, h: f32, op: Self) -> Self {
+        Self(WFOperationInner::Derivative(
+            h,
+            -Complex32::I * hbar / (2.0 * h),
+            Arc::new(op),
+        ))
     }
 }
 
@@ -103,9 +237,41 @@ impl<S: WFSignature> Neg for WFOperation<S> {
     }
 }
 
+/// Summarise the structure of the expression tree. Functions are opaque closures and sums are
+/// summarised by their number of terms, so that large superpositions stay readable.
+impl<S: WFSignature> fmt::Debug for WFOperation<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            WFOperationInner::Function(_) => write!(f, "Function"),
+            WFOperationInner::Sum(fs) => write!(f, "Sum({} terms)", fs.len()),
+            WFOperationInner::WeightedSum(fs) => write!(f, "WeightedSum({} terms)", fs.len()),
+            WFOperationInner::Product(g, h) => f.debug_tuple("Product").field(g).field(h).finish(),
+            WFOperationInner::Sub(g, h) => f.debug_tuple("Sub").field(g).field(h).finish(),
+            WFOperationInner::Scale(_, g) => f.debug_tuple("Scale").field(g).finish(),
+            WFOperationInner::Neg(g) => f.debug_tuple("Neg").field(g).finish(),
+            WFOperationInner::Adjoint(g) => f.debug_tuple("Adjoint").field(g).finish(),
+            WFOperationInner::Map(_, g) => f.debug_tuple("Map").field(g).finish(),
+            WFOperationInner::TranslateSpace(_, g) => {
+                f.debug_tuple("TranslateSpace").field(g).finish()
+            }
+            WFOperationInner::TranslateTime(_, g) => {
+                f.debug_tuple("TranslateTime").field(g).finish()
+            }
+            WFOperationInner::ReflectSpace(g) => f.debug_tuple("ReflectSpace").field(g).finish(),
+            WFOperationInner::Wrap(_, g) => f.debug_tuple("Wrap").field(g).finish(),
+            WFOperationInner::ScaleSpace(_, g) => f.debug_tuple("ScaleSpace").field(g).finish(),
+            WFOperationInner::Derivative(_, _, g) => f.debug_tuple("Derivative").field(g).finish(),
+            WFOperationInner::SecondDerivative(_, _, g) => {
+                f.debug_tuple("SecondDerivative").field(g).finish()
+            }
+            WFOperationInner::Checked(_, g) => f.debug_tuple("Checked").field(g).finish(),
+        }
+    }
+}
+
 impl<S: WFSignature> WFOperation<S> {
     /// Evaluate a `WFOperation` expression tree
-    pub(super) fn eval(&self, x: S::Space, t: S::Time) -> S::Out {
+    pub(crate) fn eval(&self, x: S::Space, t: S::Time) -> S::Out {
         match &self.0 {
             WFOperationInner::Function(f) => f(x, t),
             WFOperationInner::Sum(fs) => fs
@@ -116,12 +282,155 @@ impl<S: WFSignature> WFOperation<S> {
                 .iter()
                 .map(|(c, f)| *c * f.eval(x, t))
                 .fold(S::Out::zero(), |a, b| a + b),
+            WFOperationInner::Product(f, g) => f.eval(x, t) * g.eval(x, t),
             WFOperationInner::Sub(f, g) => f.eval(x, t) - g.eval(x, t),
             WFOperationInner::Scale(c, f) => *c * f.eval(x, t),
             WFOperationInner::Neg(f) => -f.eval(x, t),
             WFOperationInner::Adjoint(f) => f.eval(x, t).conjugate(),
+            WFOperationInner::Map(m, f) => m(f.eval(x, t)),
             WFOperationInner::TranslateSpace(dx, f) => f.eval(x - *dx, t),
             WFOperationInner::TranslateTime(dt, f) => f.eval(x, t - *dt),
+            WFOperationInner::ReflectSpace(f) => f.eval(S::Space::zero() - x, t),
+            WFOperationInner::Wrap(subdomain, f) => f.eval(subdomain.wrap(x), t),
+            WFOperationInner::ScaleSpace(a, f) => f.eval(x.scale(1.0 / a), t),
+            WFOperationInner::Derivative(dx, coef, f) => {
+                *coef * (f.eval(x + *dx, t) - f.eval(x - *dx, t))
+            }
+            WFOperationInner::SecondDerivative(dx, inv_dx_sqr, f) => {
+                let centre = f.eval(x, t);
+                *inv_dx_sqr * (f.eval(x + *dx, t) - centre - centre + f.eval(x - *dx, t))
+            }
+            WFOperationInner::Checked(reported, f) => {
+                let value = f.eval(x, t);
+                if value.modulus().is_finite() {
+                    value
+                } else {
+                    if !reported.swap(true, Ordering::Relaxed) {
+                        warn!("Non-finite wavefunction value replaced with zero.");
+                    }
+                    S::Out::zero()
+                }
+            }
+        }
+    }
+}
+
+impl<S: WFSignature> WFOperation<S> {
+    /// Simplify a `WFOperation` expression tree without changing its value. Nested sums are
+    /// flattened, with scalings and negations folded into the weights of weighted sums,
+    /// adjacent scalings are merged, and double negations are removed.
+    #[must_use]
+    pub fn simplify(self) -> Self {
+        match self.0 {
+            WFOperationInner::Function(_) => self,
+            WFOperationInner::Sum(fs) => {
+                let mut summands = Vec::with_capacity(fs.len());
+                for f in fs.iter() {
+                    match f.clone().simplify().0 {
+                        WFOperationInner::Sum(inner) => summands.extend(inner.iter().cloned()),
+                        other => summands.push(Self(other)),
+                    }
+                }
+                Self::sum(summands)
+            }
+            WFOperationInner::WeightedSum(fs) => {
+                let mut summands = Vec::with_capacity(fs.len());
+                for (c, f) in fs.iter() {
+                    f.clone().simplify().push_weighted(*c, &mut summands);
+                }
+                Self::weighted_sum(summands)
+            }
+            WFOperationInner::Product(f, g) => Self::product(
+                Arc::unwrap_or_clone(f).simplify(),
+                Arc::unwrap_or_clone(g).simplify(),
+            ),
+            WFOperationInner::Sub(f, g) => {
+                Arc::unwrap_or_clone(f).simplify() - Arc::unwrap_or_clone(g).simplify()
+            }
+            WFOperationInner::Scale(c, f) => match Arc::unwrap_or_clone(f).simplify().0 {
+                WFOperationInner::Scale(d, g) => Self(WFOperationInner::Scale(c * d, g)),
+                other => Self::scale(c, Self(other)),
+            },
+            WFOperationInner::Neg(f) => match Arc::unwrap_or_clone(f).simplify().0 {
+                WFOperationInner::Neg(g) => Arc::unwrap_or_clone(g),
+                other => -Self(other),
+            },
+            WFOperationInner::Adjoint(f) => Self::adjoint(Arc::unwrap_or_clone(f).simplify()),
+            WFOperationInner::Map(m, f) => Self(WFOperationInner::Map(
+                m,
+                Arc::new(Arc::unwrap_or_clone(f).simplify()),
+            )),
+            WFOperationInner::TranslateSpace(dx, f) => {
+                Self::translate_space(dx, Arc::unwrap_or_clone(f).simplify())
+            }
+            WFOperationInner::TranslateTime(dt, f) => {
+                Self::translate_time(dt, Arc::unwrap_or_clone(f).simplify())
+            }
+            WFOperationInner::ReflectSpace(f) => {
+                Self::reflect_space(Arc::unwrap_or_clone(f).simplify())
+            }
+            WFOperationInner::Wrap(subdomain, f) => {
+                Self::wrap(&subdomain, Arc::unwrap_or_clone(f).simplify())
+            }
+            WFOperationInner::ScaleSpace(a, f) => {
+                Self::scale_space(a, Arc::unwrap_or_clone(f).simplify())
+            }
+            WFOperationInner::Derivative(dx, coef, f) => Self(WFOperationInner::Derivative(
+                dx,
+                coef,
+                Arc::new(Arc::unwrap_or_clone(f).simplify()),
+            )),
+            WFOperationInner::SecondDerivative(dx, inv_dx_sqr, f) => {
+                Self(WFOperationInner::SecondDerivative(
+                    dx,
+                    inv_dx_sqr,
+                    Arc::new(Arc::unwrap_or_clone(f).simplify()),
+                ))
+            }
+            WFOperationInner::Checked(reported, f) => Self(WFOperationInner::Checked(
+                reported,
+                Arc::new(Arc::unwrap_or_clone(f).simplify()),
+            )),
+        }
+    }
+
+    /// Push this (simplified) expression onto the summands of a weighted sum with weight `c`,
+    /// flattening any sums, scalings and negations into the weights.
+    fn push_weighted(self, c: S::Out, summands: &mut Vec<(S::Out, Self)>) {
+        match self.0 {
+            WFOperationInner::Sum(fs) => summands.extend(fs.iter().map(|f| (c, f.clone()))),
+            WFOperationInner::WeightedSum(fs) => {
+                summands.extend(fs.iter().map(|(d, f)| (c * *d, f.clone())));
+            }
+            WFOperationInner::Scale(d, f) => summands.push((c * d, Arc::unwrap_or_clone(f))),
+            WFOperationInner::Neg(f) => summands.push((-c, Arc::unwrap_or_clone(f))),
+            other => summands.push((c, Self(other))),
+        }
+    }
+
+    /// Return the depth of a `WFOperation` expression tree, where a lone function has depth 1
+    pub fn depth(&self) -> usize {
+        1 + match &self.0 {
+            WFOperationInner::Function(_) => 0,
+            WFOperationInner::Sum(fs) => fs.iter().map(Self::depth).max().unwrap_or(0),
+            WFOperationInner::WeightedSum(fs) => {
+                fs.iter().map(|(_, f)| f.depth()).max().unwrap_or(0)
+            }
+            WFOperationInner::Product(f, g) | WFOperationInner::Sub(f, g) => {
+                f.depth().max(g.depth())
+            }
+            WFOperationInner::Scale(_, f)
+            | WFOperationInner::Neg(f)
+            | WFOperationInner::Adjoint(f)
+            | WFOperationInner::Map(_, f)
+            | WFOperationInner::TranslateSpace(_, f)
+            | WFOperationInner::TranslateTime(_, f)
+            | WFOperationInner::ReflectSpace(f)
+            | WFOperationInner::Wrap(_, f)
+            | WFOperationInner::ScaleSpace(_, f)
+            | WFOperationInner::Derivative(_, _, f)
+            | WFOperationInner::SecondDerivative(_, _, f)
+            | WFOperationInner::Checked(_, f) => f.depth(),
         }
     }
 }
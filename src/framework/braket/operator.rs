@@ -0,0 +1,37 @@
+//! Operators acting on kets, and their expectation values.
+
+use std::sync::Arc;
+
+use num_complex::Complex32;
+
+use super::super::wavefunction::signature::WFSignature;
+use super::{AbstractBra, AbstractKet, Ket, WFOperation};
+
+/// A linear operator acting on kets with signature `S`. Observables are Hermitian operators,
+/// for which `expectation` is real up to integration error.
+pub trait Operator<S: WFSignature> {
+    /// Apply this operator to a ket, returning `O|psi>`
+    fn apply_to_ket(&self, ket: &Ket<S>) -> Ket<S>;
+
+    /// Compute the expectation value `<psi|O|psi>` at time `t`. This is only the average of
+    /// the observable if the ket is normalised.
+    fn expectation(&self, ket: &Ket<S>, t: S::Time, step_size: S::Space) -> S::Out {
+        Ket::adjoint(ket).apply(&self.apply_to_ket(ket), t, step_size)
+    }
+}
+
+/// The position operator, which multiplies a wavefunction pointwise by `x`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MultiplyByPosition;
+
+impl<S: WFSignature<Space = f32, Out = Complex32>> Operator<S> for MultiplyByPosition {
+    fn apply_to_ket(&self, ket: &Ket<S>) -> Ket<S> {
+        Ket {
+            wavefunction: WFOperation::product(
+                WFOperation::func(Arc::new(|x: f32, _| Complex32::from(x))),
+                ket.wavefunction.clone(),
+            ),
+            subdomain: ket.subdomain.clone(),
+        }
+    }
+}
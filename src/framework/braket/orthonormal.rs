@@ -0,0 +1,44 @@
+//! Orthonormalisation of sets of kets.
+
+use super::super::{
+    core::{
+        field::{Field, RealField},
+        vectorspace::VectorSpace,
+    },
+    wavefunction::signature::WFSignature,
+};
+use super::{AbstractBra, AbstractKet, Ket};
+
+/// Squared norm below which a ket is considered linearly dependent on the kets before it
+const NORM_SQR_EPSILON: f32 = 1e-6;
+
+/// Orthonormalise a set of kets at time `t` via the modified Gram-Schmidt process, which
+/// projects out each basis ket from the residual left by the ones before it, rather than from
+/// the original ket. This keeps the output orthogonal to within the integration error even when
+/// the input kets are nearly parallel.
+/// Kets which are (nearly) linearly dependent on those before them have (nearly) zero norm
+/// after projection, and are skipped, so the output may contain fewer kets than the input.
+pub fn gram_schmidt<S: WFSignature>(
+    kets: Vec<Ket<S>>,
+    t: S::Time,
+    step_size: S::Space,
+) -> Vec<Ket<S>> {
+    let mut basis: Vec<Ket<S>> = Vec::with_capacity(kets.len());
+    for ket in kets {
+        // The residual is kept as one flat weighted sum, so that it is evaluated pointwise
+        // before each inner product
+        let mut terms = vec![(S::Out::one(), ket)];
+        for e in &basis {
+            let residual = Ket::weighted_sum(terms.clone());
+            terms.push((-Ket::adjoint(e).apply(&residual, t, step_size), e.clone()));
+        }
+        let orthogonal = Ket::weighted_sum(terms);
+
+        let norm_sqr = orthogonal.norm_sqr(t, step_size).modulus();
+        if norm_sqr.to_f32() < NORM_SQR_EPSILON {
+            continue;
+        }
+        basis.push(orthogonal.scale(S::Out::one() / S::Out::from_real(norm_sqr.sqrt())));
+    }
+    basis
+}
@@ -0,0 +1,67 @@
+//! Selectable quadrature rules for inner products of 1D wavefunctions.
+
+use num_complex::Complex32;
+
+use super::super::{
+    core::domain::SubDomain,
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+use super::{AbstractBra, Bra, Ket};
+
+/// The rule used to integrate sampled inner products of 1D wavefunctions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntegrationMethod {
+    /// The left Riemann sum `sum (x_{i+1} - x_i) f(x_i)`, which samples each panel at its left
+    /// edge only, so never samples the upper bound. It converges linearly in the step size unless
+    /// the integrand vanishes at the boundaries.
+    Riemann,
+    /// The trapezoidal rule, which half-weights the boundary samples. This is the rule used by
+    /// `apply`.
+    #[default]
+    Trapezoidal,
+    /// Composite Simpson's rule over pairs of panels. If the samples span an odd number of
+    /// panels, the last panel falls back to the trapezoidal rule.
+    Simpson,
+}
+
+impl Bra<Sign1D> {
+    /// Apply this bra to a ket at time `t`, integrating over the intersection of their
+    /// subdomains with the given step size and quadrature rule. The samples are those visited
+    /// by `apply`, so the final panel may be narrower or wider than the step size if the step does
+    /// not divide the subdomain; every rule uses the true width of each panel.
+    pub fn apply_with(
+        &self,
+        ket: &Ket<Sign1D>,
+        t: f32,
+        step_size: f32,
+        method: IntegrationMethod,
+    ) -> Complex32 {
+        let domain = ket.subdomain.clone() * self.subdomain.clone();
+        let samples = || -> Vec<(f32, Complex32)> {
+            domain
+                .iter_with_step_size(step_size)
+                .map(|x| (x, self.f(x, t) * ket.f(x, t)))
+                .collect()
+        };
+        match method {
+            IntegrationMethod::Riemann => samples()
+                .windows(2)
+                .map(|w| (w[1].0 - w[0].0) * w[0].1)
+                .sum(),
+            IntegrationMethod::Trapezoidal => self.apply(ket, t, step_size),
+            IntegrationMethod::Simpson => {
+                let samples = samples();
+                let pairs = samples.windows(3).step_by(2);
+                let mut sum: Complex32 = pairs
+                    .map(|w| (w[2].0 - w[0].0) / 6.0 * (w[0].1 + 4.0 * w[1].1 + w[2].1))
+                    .sum();
+                if samples.len().is_multiple_of(2)
+                    && let [.., a, b] = samples[..]
+                {
+                    sum += 0.5 * (b.0 - a.0) * (a.1 + b.1);
+                }
+                sum
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Construction of 1D kets from sampled values, such as experimental or externally computed data.
+
+use num_complex::Complex32;
+
+use super::super::{core::domain::SubDomain1D, wavefunction::signature::Sign1D};
+use super::Ket;
+
+/// How values are interpolated between samples
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interp {
+    /// Take the value of the nearest sample
+    Nearest,
+    /// Interpolate linearly between neighbouring samples
+    #[default]
+    Linear,
+}
+
+impl Ket<Sign1D> {
+    /// Return a time-independent ket interpolating `values` sampled at `lower`, `lower + step`,
+    /// ..., with the subdomain spanning the samples, so that the ket vanishes outside of them.
+    #[must_use]
+    pub fn from_samples(lower: f32, step: f32, values: Vec<Complex32>, interp: Interp) -> Self {
+        let upper = lower + values.len().saturating_sub(1) as f32 * step;
+        Ket::new(
+            move |x: f32, _| {
+                let pos = ((x - lower) / step).max(0.0);
+                match interp {
+                    Interp::Nearest => values
+                        .get(pos.round() as usize)
+                        .copied()
+                        .unwrap_or(Complex32::ZERO),
+                    Interp::Linear => {
+                        let i = pos.floor() as usize;
+                        match (values.get(i), values.get(i + 1)) {
+                            (Some(a), Some(b)) => {
+                                let frac = pos - i as f32;
+                                a * (1.0 - frac) + b * frac
+                            }
+                            (Some(a), None) => *a,
+                            _ => Complex32::ZERO,
+                        }
+                    }
+                }
+            },
+            SubDomain1D { lower, upper },
+        )
+    }
+}
@@ -0,0 +1,24 @@
+//! Survival probabilities of evolving states.
+
+use super::super::{
+    core::field::{Field, RealField},
+    wavefunction::{Wavefunction, signature::WFSignature},
+};
+use super::{AbstractBra, AbstractKet, Ket};
+
+/// Compute the survival probability `|<psi(t0)|psi(t)>|^2` of a state evolving from `initial` at
+/// time `t0`, as `evolved` at time `t`. This is normalised by the norms of both states, so it is
+/// unaffected by norm lost when `evolved` is built from a truncated basis.
+pub fn survival_probability<S: WFSignature>(
+    initial: &Ket<S>,
+    evolved: &Ket<S>,
+    t0: S::Time,
+    t: S::Time,
+    step_size: S::Space,
+) -> f32 {
+    let initial_at_t = initial.clone().translate_time(t - t0);
+    let overlap = Ket::adjoint(&initial_at_t).apply(evolved, t, step_size);
+    let norms =
+        initial.norm_sqr(t0, step_size).modulus() * evolved.norm_sqr(t, step_size).modulus();
+    (overlap.modulus() * overlap.modulus() / norms).to_f32()
+}
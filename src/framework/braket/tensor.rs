@@ -0,0 +1,27 @@
+//! Construction of product states in tensor product spaces.
+
+use super::super::{
+    core::domain::tensor_domains::TensorSubDomain,
+    wavefunction::{
+        Wavefunction,
+        signature::{TensorSignature, WFSignature},
+    },
+};
+use super::Ket;
+
+/// Return the product state `psi_a(x) psi_b(y)` of a ket of each subsystem, defined on the
+/// product of their subdomains. Inner products of product states factorise as
+/// `<a1|a2><b1|b2>`.
+pub fn tensor<A, B>(ket_a: Ket<A>, ket_b: Ket<B>) -> Ket<TensorSignature<A, B>>
+where
+    A: WFSignature + 'static,
+    B: WFSignature<Time = A::Time, Out = A::Out> + 'static,
+{
+    let subdomain = TensorSubDomain(ket_a.subdomain.clone(), ket_b.subdomain.clone());
+    Ket::new(
+        move |p: <TensorSignature<A, B> as WFSignature>::Space, t: A::Time| {
+            ket_a.f(p.0, t) * ket_b.f(p.1, t)
+        },
+        subdomain,
+    )
+}
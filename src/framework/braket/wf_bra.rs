@@ -1,6 +1,7 @@
 //! Generalised functionality for wavefunction bras in Dirac's Bra-Ket formalism.
 
 use std::{
+    fmt,
     ops::{Add, Neg, Sub},
     sync::Arc,
 };
@@ -36,6 +37,27 @@ impl<S: WFSignature> Bra<S> {
     }
 }
 
+impl<S: WFSignature> fmt::Debug for Bra<S>
+where
+    S::SubDom: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bra")
+            .field("subdomain", &self.subdomain)
+            .field("wavefunction", &self.wavefunction)
+            .finish()
+    }
+}
+
+impl<S: WFSignature> fmt::Display for Bra<S>
+where
+    S::SubDom: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{:?}| on {:?}", self.wavefunction, self.subdomain)
+    }
+}
+
 impl<S: WFSignature> Default for Bra<S> {
     fn default() -> Self {
         Self {
@@ -76,19 +98,29 @@ where
         }
     }
 
+    /// Summands sharing one subdomain, such as eigenstates of one system, skip combining their
+    /// subdomains.
     fn weighted_sum(summands: Vec<(S::Out, Self)>) -> Self {
+        let subdomain = match summands.split_first() {
+            Some(((_, first), rest))
+                if rest.iter().all(|(_, v)| v.subdomain == first.subdomain) =>
+            {
+                first.subdomain.clone()
+            }
+            _ => summands
+                .iter()
+                .map(|(_, v)| v.subdomain.clone())
+                .reduce(|a, b| a + b)
+                .unwrap_or_else(S::SubDom::none),
+        };
         Bra {
             wavefunction: WFOperation::weighted_sum(
                 summands
-                    .iter()
-                    .map(|(c, v)| (*c, v.wavefunction.clone()))
+                    .into_iter()
+                    .map(|(c, v)| (c, v.wavefunction))
                     .collect(),
             ),
-            subdomain: summands
-                .iter()
-                .map(|(_, v)| v.subdomain.clone())
-                .reduce(|a, b| a + b)
-                .unwrap_or_else(S::SubDom::none),
+            subdomain,
         }
     }
 }
@@ -115,9 +147,21 @@ impl<S: WFSignature> Wavefunction<S> for Bra<S> {
         }
     }
 
+    fn integrate(&self, t: S::Time, step_size: S::Space) -> S::Out {
+        let measure: <S::Out as Field>::Real = self.subdomain.measure(step_size);
+        self.subdomain
+            .iter_weighted(step_size)
+            .map(|(x, weight)| S::Out::from_real(S::jacobian(x) * weight * measure) * self.f(x, t))
+            .reduce(|a, b| a + b)
+            .unwrap_or_else(S::Out::zero)
+    }
+
     fn translate_space(self, offset: <S as WFSignature>::Space) -> Self {
         Self {
-            wavefunction: WFOperation::translate_space(offset, self.wavefunction),
+            wavefunction: WFOperation::translate_space(
+                offset,
+                WFOperation::wrap(&self.subdomain, self.wavefunction),
+            ),
             subdomain: self.subdomain.translate(offset),
         }
     }
@@ -128,6 +172,23 @@ impl<S: WFSignature> Wavefunction<S> for Bra<S> {
             subdomain: self.subdomain,
         }
     }
+
+    fn reflect_space(self) -> Self {
+        Self {
+            wavefunction: WFOperation::reflect_space(WFOperation::wrap(
+                &self.subdomain,
+                self.wavefunction,
+            )),
+            subdomain: self.subdomain.reflect(),
+        }
+    }
+
+    fn scale_space(self, factor: f32) -> Self {
+        Self {
+            wavefunction: WFOperation::scale_space(factor, self.wavefunction),
+            subdomain: self.subdomain.dilate(factor),
+        }
+    }
 }
 
 impl<S> Add for Bra<S>
@@ -173,6 +234,22 @@ where
     }
 }
 
+impl<S: WFSignature> Bra<S> {
+    /// Return the contribution of the point `x`, with the given quadrature weight, to the inner
+    /// product of this bra with `ket` over a subdomain of the given measure. The quadrature
+    /// weight, Jacobian and measure of the point are combined into a single real weight, so that
+    /// the serial and parallel inner products agree for any volume element.
+    fn integrand(
+        &self,
+        ket: &Ket<S>,
+        (x, weight): (S::Space, <S::Out as Field>::Real),
+        measure: <S::Out as Field>::Real,
+        t: S::Time,
+    ) -> S::Out {
+        S::Out::from_real(S::jacobian(x) * weight * measure) * self.f(x, t) * ket.f(x, t)
+    }
+}
+
 impl<S> AbstractBra<S> for Bra<S>
 where
     S: WFSignature,
@@ -182,9 +259,10 @@ where
     #[cfg(not(feature = "par_braket"))]
     fn apply(&self, ket: &Self::Ket, t: S::Time, step_size: S::Space) -> S::Out {
         let domain = ket.subdomain.clone() * self.subdomain.clone();
+        let measure = domain.measure(step_size);
         domain
-            .iter_with_step_size(step_size)
-            .map(|x| S::mul_to_codomain(step_size, self.f(x, t) * ket.f(x, t)))
+            .iter_weighted(step_size)
+            .map(|sample| self.integrand(ket, sample, measure, t))
             .reduce(|a, b| a + b)
             .unwrap_or_else(S::Out::zero)
     }
@@ -192,10 +270,11 @@ where
     #[cfg(feature = "par_braket")]
     fn apply(&self, ket: &Self::Ket, t: S::Time, step_size: S::Space) -> S::Out {
         let domain = ket.subdomain.clone() * self.subdomain.clone();
+        let measure = domain.measure(step_size);
         domain
-            .iter_with_step_size(step_size)
+            .iter_weighted(step_size)
             .par_bridge()
-            .map(|x| S::mul_to_codomain(step_size, self.f(x, t)) * ket.f(x, t))
+            .map(|sample| self.integrand(ket, sample, measure, t))
             .reduce(S::Out::zero, |a, b| a + b)
     }
 }
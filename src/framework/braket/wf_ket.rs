@@ -1,16 +1,35 @@
 //! Generalised functionality for wavefunction kets in Dirac's Bra-Ket formalism.
 
 use std::{
+    f32::consts::PI,
+    fmt,
     ops::{Add, Neg, Sub},
     sync::Arc,
 };
 
+use bevy::log::warn;
+use num_complex::Complex32;
+
 use super::super::{
-    core::{domain::SubDomain, field::Field, vectorspace::VectorSpace},
-    wavefunction::{Wavefunction, signature::WFSignature},
+    core::{
+        domain::{SubDomain, SubDomain1D},
+        field::{Field, RealField},
+        vectorspace::VectorSpace,
+    },
+    wavefunction::{
+        Wavefunction,
+        signature::{Sign1D, WFSignature},
+    },
 };
 use super::{AbstractBra, AbstractKet, Bra, WFOperation};
 
+/// The smallest norm a ket may have for `normalize` to rescale it
+const NORMALIZE_EPSILON: f32 = 1e-12;
+
+/// The number of standard deviations either side of its centre spanned by the default subdomain
+/// of `Ket::gaussian`, beyond which a negligible fraction of the probability lies
+const GAUSSIAN_WINDOW: f32 = 5.0;
+
 /// A ket (vector) holding a wavefunction
 #[derive(Clone)]
 pub struct Ket<S>
@@ -50,6 +69,185 @@ impl<S: WFSignature> Ket<S> {
     ) -> impl Iterator<Item = S::Space> + Sized + Send + Sync {
         self.subdomain.iter_with_step_size(step_size)
     }
+
+    /// Simplify the expression tree underlying this ket without changing its value. This is
+    /// worthwhile for kets built from many sums, which are otherwise evaluated as deep trees.
+    #[must_use]
+    pub fn simplify(self) -> Self {
+        Ket {
+            wavefunction: self.wavefunction.simplify(),
+            subdomain: self.subdomain,
+        }
+    }
+
+    /// Return the even part `(psi(x) + psi(-x)) / 2` of this ket
+    #[must_use]
+    pub fn even_part(self) -> Self {
+        Ket {
+            subdomain: self.subdomain.clone() + self.subdomain.clone().reflect(),
+            wavefunction: WFOperation::even_part(self.wavefunction),
+        }
+    }
+
+    /// Return the odd part `(psi(x) - psi(-x)) / 2` of this ket
+    #[must_use]
+    pub fn odd_part(self) -> Self {
+        Ket {
+            subdomain: self.subdomain.clone() + self.subdomain.clone().reflect(),
+            wavefunction: WFOperation::odd_part(self.wavefunction),
+        }
+    }
+
+    /// Map this ket pointwise through `f`, so that `psi(x) -> f(psi(x))`, e.g. to apply a gauge
+    /// phase or an absorbing mask depending only on the value of the wavefunction
+    #[must_use]
+    pub fn map<F: Fn(S::Out) -> S::Out + Send + Sync + 'static>(self, f: F) -> Self {
+        Ket {
+            wavefunction: WFOperation::map(f, self.wavefunction),
+            subdomain: self.subdomain,
+        }
+    }
+
+    /// Return this ket with any non-finite (NaN or infinite) values replaced by zero, so that
+    /// a few bad samples do not poison inner products or rendering. The first non-finite value
+    /// found is logged as a warning.
+    #[must_use]
+    pub fn checked(self) -> Self {
+        Ket {
+            wavefunction: WFOperation::checked(self.wavefunction),
+            subdomain: self.subdomain,
+        }
+    }
+
+    /// Restrict this ket to the intersection of its subdomain with `region`, keeping its
+    /// wavefunction, so that it evaluates to zero outside of `region`. The squared norm of the
+    /// restricted ket is the probability of finding the particle in `region`.
+    #[must_use]
+    pub fn restrict(self, region: S::SubDom) -> Self {
+        Ket {
+            wavefunction: self.wavefunction,
+            subdomain: self.subdomain * region,
+        }
+    }
+
+    /// Remove the component of this ket along `basis` at time `t`, returning
+    /// `|psi> - <basis|psi> / <basis|basis> |basis>`, which is orthogonal to `basis`. The basis
+    /// ket need not be normalised. Projecting out the zero ket leaves this ket unchanged.
+    #[must_use]
+    pub fn project_out(self, basis: &Ket<S>, t: S::Time, step_size: S::Space) -> Self {
+        let norm_sqr = basis.norm_sqr(t, step_size);
+        if norm_sqr == S::Out::zero() {
+            return self;
+        }
+        let overlap = Self::adjoint(basis).apply(&self, t, step_size);
+        self - basis.clone().scale(overlap / norm_sqr)
+    }
+
+    /// Compute the expectation value `<psi|P|psi>` of the parity operator about the origin,
+    /// where `P psi(x) = psi(-x)`. To find the parity about some other point, translate the
+    /// ket so that point lies at the origin first.
+    pub fn parity(&self, t: S::Time, step_size: S::Space) -> S::Out {
+        Self::adjoint(self).apply(&self.clone().reflect_space(), t, step_size)
+    }
+
+    /// Return this ket scaled to unit norm at time `t`. The zero ket, and any ket whose norm is
+    /// below `NORMALIZE_EPSILON`, cannot be normalised, so it is returned unchanged with a logged
+    /// warning.
+    #[must_use]
+    pub fn normalize(self, t: S::Time, step_size: S::Space) -> Self {
+        let norm = self.norm_sqr(t, step_size).modulus().sqrt();
+        if norm.to_f32() < NORMALIZE_EPSILON {
+            warn!("Cannot normalize a ket with norm {norm}; returning it unchanged.");
+            return self;
+        }
+        self.scale(S::Out::one() / S::Out::from_real(norm))
+    }
+
+    /// Check whether this ket is pointwise equal to `other` at time `t`, up to a maximum
+    /// difference of `tol`. Unlike the fidelity, this is sensitive to the global phase.
+    /// Both kets are sampled over the union of their subdomains, so kets with disjoint
+    /// subdomains are only equal if both are zero.
+    pub fn approx_eq(
+        &self,
+        other: &Ket<S>,
+        t: S::Time,
+        step_size: S::Space,
+        tol: <S::Out as Field>::Real,
+    ) -> bool {
+        (self.subdomain.clone() + other.subdomain.clone())
+            .iter_with_step_size(step_size)
+            .all(|x| (self.f(x, t) - other.f(x, t)).modulus() <= tol)
+    }
+}
+
+impl<S: WFSignature<Space = f32>> Ket<S> {
+    /// Return the second derivative `d^2 psi / dx^2` of this ket, taken by central differences
+    /// with spacing `dx`
+    #[must_use]
+    pub fn laplacian(self, dx: f32) -> Self {
+        Ket {
+            wavefunction: WFOperation::laplacian(dx, self.wavefunction),
+            subdomain: self.subdomain,
+        }
+    }
+}
+
+impl<S: WFSignature<Space = f32, Out = Complex32>> Ket<S> {
+    /// Return the momentum operator `-i hbar d/dx` applied to this ket, taken by central
+    /// differences with spacing `h`. Sandwiching it as `<psi|p|psi>` gives the expectation value
+    /// of momentum.
+    #[must_use]
+    pub fn momentum(self, hbar: f32, h: f32) -> Self {
+        Ket {
+            wavefunction: WFOperation::momentum(hbar, h, self.wavefunction),
+            subdomain: self.subdomain,
+        }
+    }
+}
+
+impl Ket<Sign1D> {
+    /// Return the normalised gaussian wavepacket `exp(-(x - x0)^2 / 4 sigma^2) e^(i k0 x)`, whose
+    /// probability density has mean `x0` and standard deviation `sigma`, and whose momentum is
+    /// centred on `hbar k0`. The subdomain defaults to `x0 +- 5 sigma`, outside of which the
+    /// packet is negligible. The packet is a snapshot at `t = 0` which does not evolve in time,
+    /// so evolve it in a system, e.g. with `RK4Evolver` or by expanding it in eigenstates.
+    #[must_use]
+    pub fn gaussian(x0: f32, k0: f32, sigma: f32, subdomain: Option<SubDomain1D<f32>>) -> Self {
+        let subdomain = subdomain.unwrap_or(SubDomain1D {
+            lower: x0 - GAUSSIAN_WINDOW * sigma,
+            upper: x0 + GAUSSIAN_WINDOW * sigma,
+        });
+        let amplitude = (2.0 * PI * sigma * sigma).powf(-0.25);
+        Ket::new(
+            move |x: f32, _| {
+                amplitude
+                    * (-(x - x0).powi(2) / (4.0 * sigma * sigma)).exp()
+                    * Complex32::cis(k0 * x)
+            },
+            subdomain,
+        )
+    }
+}
+
+impl<S: WFSignature> fmt::Debug for Ket<S>
+where
+    S::SubDom: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ket")
+            .field("subdomain", &self.subdomain)
+            .field("wavefunction", &self.wavefunction)
+            .finish()
+    }
+}
+
+impl<S: WFSignature> fmt::Display for Ket<S>
+where
+    S::SubDom: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "|{:?}> on {:?}", self.wavefunction, self.subdomain)
+    }
 }
 
 impl<S: WFSignature> Default for Ket<S> {
@@ -92,19 +290,29 @@ where
         }
     }
 
+    /// Summands sharing one subdomain, such as eigenstates of one system, skip combining their
+    /// subdomains.
     fn weighted_sum(summands: Vec<(S::Out, Self)>) -> Self {
+        let subdomain = match summands.split_first() {
+            Some(((_, first), rest))
+                if rest.iter().all(|(_, v)| v.subdomain == first.subdomain) =>
+            {
+                first.subdomain.clone()
+            }
+            _ => summands
+                .iter()
+                .map(|(_, v)| v.subdomain.clone())
+                .reduce(|a, b| a + b)
+                .unwrap_or_else(S::SubDom::none),
+        };
         Ket {
             wavefunction: WFOperation::weighted_sum(
                 summands
-                    .iter()
-                    .map(|(c, v)| (*c, v.wavefunction.clone()))
+                    .into_iter()
+                    .map(|(c, v)| (c, v.wavefunction))
                     .collect(),
             ),
-            subdomain: summands
-                .iter()
-                .map(|(_, v)| v.subdomain.clone())
-                .reduce(|a, b| a + b)
-                .unwrap_or_else(S::SubDom::none),
+            subdomain,
         }
     }
 }
@@ -131,9 +339,21 @@ impl<S: WFSignature> Wavefunction<S> for Ket<S> {
         }
     }
 
+    fn integrate(&self, t: S::Time, step_size: S::Space) -> S::Out {
+        let measure: <S::Out as Field>::Real = self.subdomain.measure(step_size);
+        self.subdomain
+            .iter_weighted(step_size)
+            .map(|(x, weight)| S::Out::from_real(S::jacobian(x) * weight * measure) * self.f(x, t))
+            .reduce(|a, b| a + b)
+            .unwrap_or_else(S::Out::zero)
+    }
+
     fn translate_space(self, offset: <S as WFSignature>::Space) -> Self {
         Self {
-            wavefunction: WFOperation::translate_space(offset, self.wavefunction),
+            wavefunction: WFOperation::translate_space(
+                offset,
+                WFOperation::wrap(&self.subdomain, self.wavefunction),
+            ),
             subdomain: self.subdomain.translate(offset),
         }
     }
@@ -144,6 +364,23 @@ impl<S: WFSignature> Wavefunction<S> for Ket<S> {
             subdomain: self.subdomain,
         }
     }
+
+    fn reflect_space(self) -> Self {
+        Self {
+            wavefunction: WFOperation::reflect_space(WFOperation::wrap(
+                &self.subdomain,
+                self.wavefunction,
+            )),
+            subdomain: self.subdomain.reflect(),
+        }
+    }
+
+    fn scale_space(self, factor: f32) -> Self {
+        Self {
+            wavefunction: WFOperation::scale_space(factor, self.wavefunction),
+            subdomain: self.subdomain.dilate(factor),
+        }
+    }
 }
 
 impl<S> Add for Ket<S>
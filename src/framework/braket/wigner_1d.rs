@@ -0,0 +1,55 @@
+//! The Wigner quasi-probability distribution of 1D kets.
+
+use std::f32::consts::PI;
+
+use num_complex::Complex32;
+
+use super::super::wavefunction::{Wavefunction, signature::Sign1D};
+use super::Ket;
+
+impl Ket<Sign1D> {
+    /// Return the Wigner function
+    /// `W(x, p) = 1/(pi hbar) int psi*(x+y) psi(x-y) e^(2ipy/hbar) dy`
+    /// at time `t` on the grid `x_grid` by `p_grid`, as one row of `W(x, p)`
+    /// over `p_grid` for each `x` in `x_grid`. The integral runs over the `y`
+    /// for which both `x + y` and `x - y` lie in the subdomain, sampled with
+    /// the given step size.
+    ///
+    /// `W` is real and integrates to the norm of the ket over phase space, but
+    /// unlike a probability density it may be negative, which is a signature
+    /// of non-classical states.
+    pub fn wigner(
+        &self,
+        x_grid: &[f32],
+        p_grid: &[f32],
+        t: f32,
+        step_size: f32,
+        hbar: f32,
+    ) -> Vec<Vec<f32>> {
+        let (lower, upper) = (self.subdomain.lower, self.subdomain.upper);
+        x_grid
+            .iter()
+            .map(|&x| {
+                // The integrand at -y is the conjugate of that at y, so only
+                // y >= 0 is sampled and the real part is doubled.
+                let half_width = (x - lower).min(upper - x).max(0.0);
+                let correlations: Vec<(f32, Complex32)> = (1..)
+                    .map(|k| k as f32 * step_size)
+                    .take_while(|&y| y <= half_width)
+                    .map(|y| (y, self.f(x + y, t).conj() * self.f(x - y, t)))
+                    .collect();
+                let origin = self.f(x, t).norm_sqr();
+                p_grid
+                    .iter()
+                    .map(|&p| {
+                        let sum: f32 = correlations
+                            .iter()
+                            .map(|&(y, c)| (c * Complex32::cis(2.0 * p * y / hbar)).re)
+                            .sum();
+                        (origin + 2.0 * sum) * step_size / (PI * hbar)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
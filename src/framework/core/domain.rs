@@ -1,12 +1,18 @@
 //! Functionality for domains (input types to wavefunctions), and subdomains (subsets of domains where wavefunctions are defined)
 
 mod domain_sect_1d;
+mod domain_sect_2d;
 pub mod finite_domains;
+pub mod periodic_domains;
+pub mod tensor_domains;
 
-pub use domain_sect_1d::{SubDomain1D, SubDomain1DIter};
+pub use domain_sect_1d::{SubDomain1D, SubDomain1DError, SubDomain1DIter};
+pub use domain_sect_2d::{Point2D, SubDomain2D};
 
 use std::ops::{Add, Mul, Sub};
 
+use super::field::RealField;
+
 /// Trait describing properties of the domain of a wavefunction.
 /// Note that partial ordering and addition are needed to iterate over the domain;
 /// for finite domains, these can be defined by assigning an arbitrary ordering.
@@ -27,10 +33,22 @@ pub trait Domain:
     fn last() -> Self;
     /// The zero of the domain
     fn zero() -> Self;
+    /// Scale this point away from the origin by `factor`
+    #[must_use]
+    fn scale(self, factor: f32) -> Self;
+    /// The volume of the box spanned by stepping from the origin to this point along each
+    /// coordinate axis, e.g. `dx dy` for the step `(dx, dy)`, in the precision of the real
+    /// field `R` in which it is used.
+    fn volume<R: RealField>(self) -> R;
 }
 
 /// Trait describing properties of a subset of a domain. Used largely for integration.
-pub trait SubDomain<D: Domain>: Clone + Add<Output = Self> + Mul<Output = Self> {
+pub trait SubDomain<D: Domain>:
+    Clone + PartialEq + Add<Output = Self> + Mul<Output = Self> + Send + Sync
+{
+    /// Whether the subdomain has periodic boundary conditions, so that points outside of one
+    /// period are identified with points inside it by `wrap`
+    const PERIODIC: bool = false;
     /// Check if a point is contained in this subset.
     fn contains(&self, x: D) -> bool;
     /// The entire domain
@@ -42,6 +60,41 @@ pub trait SubDomain<D: Domain>: Clone + Add<Output = Self> + Mul<Output = Self>
     /// Translate this subdomain
     #[must_use]
     fn translate(self, offset: D) -> Self;
+    /// Reflect this subdomain about the origin
+    #[must_use]
+    fn reflect(self) -> Self;
+    /// Dilate this subdomain away from the origin by a positive `factor`. Step sizes used to
+    /// iterate over the subdomain should be scaled by the same factor to visit corresponding
+    /// points.
+    #[must_use]
+    fn dilate(self, factor: f32) -> Self;
+    /// Return the weight of the sample at `x` when integrating over this subdomain with the
+    /// given step size. Subdomains with boundaries use this to apply the trapezoidal rule.
+    fn quadrature_weight<R: RealField>(&self, _x: D, _step_size: D) -> R {
+        R::one()
+    }
+    /// Return an iterator over this subdomain with the given step size, pairing each point with
+    /// its `quadrature_weight`. Subdomains whose weights depend on where the iteration ends
+    /// override this to find it once, rather than once per point.
+    fn iter_weighted<R: RealField>(
+        &self,
+        step_size: D,
+    ) -> impl Iterator<Item = (D, R)> + Send + Sync {
+        self.iter_with_step_size(step_size)
+            .map(move |x| (x, self.quadrature_weight(x, step_size)))
+    }
+    /// Return the coordinate volume element of each sample when integrating over this subdomain
+    /// with the given step size. This is the volume spanned by the step for continuous
+    /// subdomains, but is kept distinct from the step itself so that subdomains summed over
+    /// rather than integrated can weight each sample by 1 whatever the step.
+    fn measure<R: RealField>(&self, step_size: D) -> R {
+        step_size.volume()
+    }
+    /// Map a point to the equivalent point in one period of this subdomain. Only periodic
+    /// subdomains identify distinct points, so by default every point is left unchanged.
+    fn wrap(&self, x: D) -> D {
+        x
+    }
 }
 
 impl Domain for f32 {
@@ -56,6 +109,36 @@ impl Domain for f32 {
     fn zero() -> Self {
         0.0
     }
+
+    fn scale(self, factor: f32) -> Self {
+        self * factor
+    }
+
+    fn volume<R: RealField>(self) -> R {
+        R::from_f64(f64::from(self))
+    }
+}
+
+impl Domain for f64 {
+    fn first() -> Self {
+        f64::NEG_INFINITY
+    }
+
+    fn last() -> Self {
+        f64::INFINITY
+    }
+
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        self * f64::from(factor)
+    }
+
+    fn volume<R: RealField>(self) -> R {
+        R::from_f64(self)
+    }
 }
 
 impl Domain for i32 {
@@ -70,4 +153,13 @@ impl Domain for i32 {
     fn zero() -> Self {
         0
     }
+
+    /// Indices are scaled to the nearest index.
+    fn scale(self, factor: f32) -> Self {
+        (self as f32 * factor).round() as i32
+    }
+
+    fn volume<R: RealField>(self) -> R {
+        R::from_f64(f64::from(self))
+    }
 }
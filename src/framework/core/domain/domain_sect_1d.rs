@@ -2,9 +2,29 @@
 
 use std::ops::{Add, Mul};
 
+use thiserror::Error;
+
+use super::super::field::RealField;
 use super::{Domain, SubDomain};
 
-#[derive(Clone, Debug)]
+/// Errors from constructing a `SubDomain1D` with a step size it can't be iterated with.
+#[derive(Debug, Error)]
+pub enum SubDomain1DError {
+    /// The step size was zero, negative or NaN
+    #[error("SubDomain1D step size must be positive")]
+    InvalidStepSize,
+    /// Fewer than two points were requested
+    #[error("At least 2 points are needed to span a SubDomain1D")]
+    TooFewPoints,
+}
+
+/// Check whether `step_size` advances an iterator over a 1D subdomain. Zero, negative and NaN
+/// step sizes never reach the upper bound.
+fn valid_step<D: Domain>(step_size: D) -> bool {
+    step_size > D::zero()
+}
+
+#[derive(Clone, Debug, PartialEq)]
 /// A subdomain in one dimension for an arbitrary domain D
 pub struct SubDomain1D<D: Domain> {
     /// The lower bound of the subdomain
@@ -13,23 +33,174 @@ pub struct SubDomain1D<D: Domain> {
     pub upper: D,
 }
 
-/// An iterator over a 1D subdomain.
+impl<D: Domain> SubDomain1D<D> {
+    /// Return the subdomain `[lower, upper]` along with the given step size, for iterating over
+    /// the subdomain with a known spacing between points. If the step does not divide the
+    /// subdomain, the final panel is shortened or stretched to end at `upper`; see
+    /// [`SubDomain1DIter`]. Use [`SubDomain1D::from_points`] for an exact spacing.
+    ///
+    /// # Panics
+    /// Panics if `step_size` is not positive. See [`SubDomain1D::try_from_step`].
+    #[must_use]
+    pub fn from_step(lower: D, upper: D, step_size: D) -> (Self, D) {
+        Self::try_from_step(lower, upper, step_size).expect("Invalid SubDomain1D step size.")
+    }
+
+    /// Return the subdomain `[lower, upper]` along with the given step size, or an error if
+    /// `step_size` is zero, negative or NaN.
+    pub fn try_from_step(lower: D, upper: D, step_size: D) -> Result<(Self, D), SubDomain1DError> {
+        if valid_step(step_size) {
+            Ok((Self { lower, upper }, step_size))
+        } else {
+            Err(SubDomain1DError::InvalidStepSize)
+        }
+    }
+
+    /// Return the number of points visited when iterating over this subdomain with the given
+    /// step size
+    pub fn len(&self, step_size: D) -> usize {
+        self.iter_with_step_size(step_size).count()
+    }
+
+    /// Return the evenly spaced points `lower + i * step_size`, as many as are visited when
+    /// iterating over this subdomain. Unlike the iterator, the last point is not rounded to
+    /// `upper`, so it may fall up to half a step either side of it when the step does not divide
+    /// the subdomain. Use this to sample grids that are rebuilt or differenced assuming a
+    /// uniform spacing, such as by `Ket::from_samples`.
+    pub fn uniform_points(&self, step_size: D) -> impl Iterator<Item = D> + use<D> {
+        let (lower, count) = (self.lower, self.len(step_size));
+        (0..count).map(move |i| step_point(lower, step_size, i))
+    }
+
+    /// Return the number of steps from `lower` to the last point visited before `upper` when
+    /// iterating with the given step size, or `None` if `upper` is the only point visited
+    fn last_step_index(&self, step_size: D) -> Option<usize> {
+        if !short_of_upper(self.lower, self.upper, step_size) {
+            return None;
+        }
+        let steps = (self.upper - self.lower).volume::<f64>() / step_size.volume::<f64>();
+        let mut index = ((steps - 0.5).ceil() - 1.0).max(0.0) as usize;
+        // Correct the estimate for rounding, so that it agrees with the iterator
+        while short_of_upper(
+            step_point(self.lower, step_size, index + 1),
+            self.upper,
+            step_size,
+        ) {
+            index += 1;
+        }
+        while index > 0
+            && !short_of_upper(
+                step_point(self.lower, step_size, index),
+                self.upper,
+                step_size,
+            )
+        {
+            index -= 1;
+        }
+        Some(index)
+    }
+
+    /// Return the last point visited before `upper` when iterating with the given step size,
+    /// or `None` if `upper` is the only point visited
+    fn last_step_point(&self, step_size: D) -> Option<D> {
+        self.last_step_index(step_size)
+            .map(|last| step_point(self.lower, step_size, last))
+    }
+
+    /// Return the trapezoidal `quadrature_weight` of the sample at `x`, given the `last_point`
+    /// visited before `upper`
+    fn trapezoidal_weight<R: RealField>(&self, x: D, step_size: D, last_point: Option<D>) -> R {
+        let steps = |from: D, to: D| (to - from).volume::<R>() / step_size.volume::<R>();
+        let half = R::from_f64(0.5);
+        let Some(last_point) = last_point else {
+            // `upper` is the only sample, standing for the whole subdomain
+            return steps(self.lower, self.upper);
+        };
+        if !short_of_upper(x, self.upper, step_size) {
+            return half * steps(last_point, self.upper);
+        }
+        let left = if x <= self.lower { R::zero() } else { R::one() };
+        let right = if x < last_point {
+            R::one()
+        } else {
+            steps(x, self.upper)
+        };
+        half * (left + right)
+    }
+}
+
+impl SubDomain1D<f32> {
+    /// Return the subdomain `[lower, upper]` along with the step size which visits `n` evenly
+    /// spaced points, including both bounds, when iterating over the subdomain.
+    ///
+    /// # Panics
+    /// Panics if `n < 2`, as the bounds cannot both be visited, or if `lower` is not less than
+    /// `upper`. See [`SubDomain1D::try_from_points`].
+    #[must_use]
+    pub fn from_points(lower: f32, upper: f32, n: usize) -> (Self, f32) {
+        Self::try_from_points(lower, upper, n).expect("Invalid SubDomain1D points.")
+    }
+
+    /// Return the subdomain `[lower, upper]` along with the step size which visits `n` evenly
+    /// spaced points, or an error if `n < 2` or the bounds don't give a positive step size.
+    pub fn try_from_points(
+        lower: f32,
+        upper: f32,
+        n: usize,
+    ) -> Result<(Self, f32), SubDomain1DError> {
+        if n < 2 {
+            return Err(SubDomain1DError::TooFewPoints);
+        }
+        Self::try_from_step(lower, upper, (upper - lower) / (n - 1) as f32)
+    }
+}
+
+/// Check whether `value` should be visited when iterating up to `upper` with the given step size.
+/// Values overshooting `upper` by less than half a step are accepted, and visit `upper` itself.
+fn within_upper<D: Domain>(value: D, upper: D, step_size: D) -> bool {
+    value <= upper || (value - upper) + (value - upper) < step_size
+}
+
+/// Check whether `value` lies more than half a step below `upper`, so that it is visited as is
+/// when iterating up to `upper` with the given step size, rather than rounded to `upper`.
+fn short_of_upper<D: Domain>(value: D, upper: D, step_size: D) -> bool {
+    value < upper && {
+        let gap = upper - value;
+        gap > step_size - gap
+    }
+}
+
+/// An iterator over a 1D subdomain. The subdomain is treated as the closed interval
+/// `[lower, upper]`: the iterator visits `lower, lower + step, ...` and always ends at `upper`, so
+/// `n` points spanning it with `from_points` are all visited. If the step does not divide the
+/// subdomain, the last step is rounded to the nearest point: the last point short of `upper` by
+/// more than half a step is followed by `upper` itself, so the final panel is between half and one
+/// and a half steps wide. Iterating over `[lower, upper]` with step `h` thus visits
+/// `round((upper - lower) / h) + 1` points, which `quadrature_weight` weights by the trapezoidal
+/// rule using the true width of the final panel. For evenly spaced points, use
+/// [`SubDomain1D::uniform_points`].
 pub struct SubDomain1DIter<D: Domain> {
+    /// The lower bound of the iterator
+    lower: D,
     /// The upper bound of the iterator
-    pub(super) upper: D,
+    upper: D,
     /// The step size to use when iterating
-    pub(super) step_size: D,
-    /// The current value of the iterator
-    pub(super) value: D,
+    step_size: D,
+    /// The number of steps from `lower` to the next point
+    index: usize,
+    /// Whether `upper` has been visited, ending the iteration
+    finished: bool,
 }
 
 impl<D: Domain> SubDomain1DIter<D> {
     /// Create a new 1D domain iterator
-    fn new(domain: &SubDomain1D<D>, step_size: D) -> SubDomain1DIter<D> {
+    pub(super) fn new(domain: &SubDomain1D<D>, step_size: D) -> SubDomain1DIter<D> {
         SubDomain1DIter {
+            lower: domain.lower,
             upper: domain.upper,
             step_size,
-            value: domain.lower,
+            index: 0,
+            finished: false,
         }
     }
 }
@@ -38,16 +209,30 @@ impl<D: Domain> Iterator for SubDomain1DIter<D> {
     type Item = D;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.value > self.upper {
+        if self.finished || !valid_step(self.step_size) {
             return None;
         }
-
-        let res = self.value;
-        self.value = self.value + self.step_size;
-        Some(res)
+        let value = step_point(self.lower, self.step_size, self.index);
+        if short_of_upper(value, self.upper, self.step_size) {
+            self.index += 1;
+            Some(value)
+        } else {
+            // Round the last point to the upper bound. Having passed a point short of `upper`, the
+            // next point is within half a step of it, up to rounding, so only `lower` itself can
+            // overshoot too far.
+            self.finished = true;
+            (self.index > 0 || within_upper(value, self.upper, self.step_size))
+                .then_some(self.upper)
+        }
     }
 }
 
+/// Return the point `index` steps above `lower`. Points are found from their index rather than
+/// accumulated, so that `quadrature_weight` locates the same points as the iterator.
+fn step_point<D: Domain>(lower: D, step_size: D, index: usize) -> D {
+    lower + step_size.scale(index as f32)
+}
+
 impl<D: Domain> SubDomain<D> for SubDomain1D<D> {
     fn contains(&self, x: D) -> bool {
         self.lower <= x && x <= self.upper
@@ -67,6 +252,7 @@ impl<D: Domain> SubDomain<D> for SubDomain1D<D> {
         }
     }
 
+    /// Zero, negative and NaN step sizes visit no points, rather than never terminating.
     fn iter_with_step_size(&self, step_size: D) -> impl Iterator<Item = D> {
         SubDomain1DIter::new(self, step_size)
     }
@@ -77,6 +263,36 @@ impl<D: Domain> SubDomain<D> for SubDomain1D<D> {
             upper: self.upper + offset,
         }
     }
+
+    fn reflect(self) -> Self {
+        Self {
+            lower: D::zero() - self.upper,
+            upper: D::zero() - self.lower,
+        }
+    }
+
+    fn dilate(self, factor: f32) -> Self {
+        Self {
+            lower: self.lower.scale(factor),
+            upper: self.upper.scale(factor),
+        }
+    }
+
+    /// Weight each sample by the mean width of the panels either side of it, in steps, so that
+    /// integrals use the trapezoidal rule. The first and last samples are half-weighted, and the
+    /// final panel is weighted by its true width when the step does not divide the subdomain.
+    fn quadrature_weight<R: RealField>(&self, x: D, step_size: D) -> R {
+        self.trapezoidal_weight(x, step_size, self.last_step_point(step_size))
+    }
+
+    fn iter_weighted<R: RealField>(
+        &self,
+        step_size: D,
+    ) -> impl Iterator<Item = (D, R)> + Send + Sync {
+        let last_point = self.last_step_point(step_size);
+        self.iter_with_step_size(step_size)
+            .map(move |x| (x, self.trapezoidal_weight(x, step_size, last_point)))
+    }
 }
 
 impl<D: Domain> Add for SubDomain1D<D> {
@@ -0,0 +1,172 @@
+//! `Domain` and `SubDomain` impl's for 2D domain use-cases, as products of 1D domains.
+
+use std::ops::{Add, Mul, Sub};
+
+use super::super::field::RealField;
+use super::{Domain, SubDomain, SubDomain1D, SubDomain1DIter};
+
+/// A point in two dimensions.
+/// Points are ordered lexicographically, first by `x` and then by `y`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Point2D {
+    /// The x coordinate of the point
+    pub x: f32,
+    /// The y coordinate of the point
+    pub y: f32,
+}
+
+impl Point2D {
+    /// Create a new point
+    #[must_use]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point2D {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Point2D {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Domain for Point2D {
+    fn first() -> Self {
+        Self::new(f32::first(), f32::first())
+    }
+
+    fn last() -> Self {
+        Self::new(f32::last(), f32::last())
+    }
+
+    fn zero() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+
+    fn volume<R: RealField>(self) -> R {
+        self.x.volume::<R>() * self.y.volume::<R>()
+    }
+}
+
+/// A rectangular subdomain in two dimensions, the product of a subdomain in each dimension
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubDomain2D {
+    /// The subdomain in the x direction
+    pub x: SubDomain1D<f32>,
+    /// The subdomain in the y direction
+    pub y: SubDomain1D<f32>,
+}
+
+impl SubDomain<Point2D> for SubDomain2D {
+    fn contains(&self, p: Point2D) -> bool {
+        self.x.contains(p.x) && self.y.contains(p.y)
+    }
+
+    fn all() -> Self {
+        Self {
+            x: SubDomain1D::all(),
+            y: SubDomain1D::all(),
+        }
+    }
+
+    fn none() -> Self {
+        Self {
+            x: SubDomain1D::none(),
+            y: SubDomain1D::none(),
+        }
+    }
+
+    /// Iterate over the grid of points in this subdomain row by row, with the given step size
+    /// in each direction.
+    fn iter_with_step_size(
+        &self,
+        step_size: Point2D,
+    ) -> impl Iterator<Item = Point2D> + Sized + Send + Sync {
+        let x = self.x.clone();
+        self.y.iter_with_step_size(step_size.y).flat_map(move |y| {
+            SubDomain1DIter::new(&x, step_size.x).map(move |x| Point2D::new(x, y))
+        })
+    }
+
+    fn translate(self, offset: Point2D) -> Self {
+        Self {
+            x: self.x.translate(offset.x),
+            y: self.y.translate(offset.y),
+        }
+    }
+
+    fn reflect(self) -> Self {
+        Self {
+            x: self.x.reflect(),
+            y: self.y.reflect(),
+        }
+    }
+
+    fn dilate(self, factor: f32) -> Self {
+        Self {
+            x: self.x.dilate(factor),
+            y: self.y.dilate(factor),
+        }
+    }
+
+    fn quadrature_weight<R: RealField>(&self, p: Point2D, step_size: Point2D) -> R {
+        self.x.quadrature_weight::<R>(p.x, step_size.x)
+            * self.y.quadrature_weight::<R>(p.y, step_size.y)
+    }
+
+    /// Weight the grid row by row, finding the weights along a row once for every row.
+    fn iter_weighted<R: RealField>(
+        &self,
+        step_size: Point2D,
+    ) -> impl Iterator<Item = (Point2D, R)> + Send + Sync {
+        let row: Vec<(f32, R)> = self.x.iter_weighted(step_size.x).collect();
+        self.y
+            .iter_weighted::<R>(step_size.y)
+            .flat_map(move |(y, y_weight)| {
+                row.clone()
+                    .into_iter()
+                    .map(move |(x, x_weight)| (Point2D::new(x, y), x_weight * y_weight))
+            })
+    }
+}
+
+impl Add for SubDomain2D {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Mul for SubDomain2D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
@@ -3,10 +3,11 @@
 
 use std::ops::{Add, Mul};
 
-use super::{SubDomain, SubDomain1DIter};
+use super::super::field::RealField;
+use super::{Domain, SubDomain, SubDomain1D, SubDomain1DIter};
 
 /// A subdomain with finitely many coordinates
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FiniteSubDomain {
     /// The smallest domain index in the subdomain
     pub min_idx: i32,
@@ -34,11 +35,13 @@ impl SubDomain<i32> for FiniteSubDomain {
     }
 
     fn iter_with_step_size(&self, _: i32) -> impl Iterator<Item = i32> + Sized + Send + Sync {
-        SubDomain1DIter::<i32> {
-            upper: self.max_idx,
-            step_size: 1,
-            value: self.min_idx,
-        }
+        SubDomain1DIter::new(
+            &SubDomain1D {
+                lower: self.min_idx,
+                upper: self.max_idx,
+            },
+            1,
+        )
     }
 
     fn translate(self, offset: i32) -> Self {
@@ -47,6 +50,25 @@ impl SubDomain<i32> for FiniteSubDomain {
             max_idx: self.max_idx + offset,
         }
     }
+
+    fn reflect(self) -> Self {
+        Self {
+            min_idx: -self.max_idx,
+            max_idx: -self.min_idx,
+        }
+    }
+
+    fn dilate(self, factor: f32) -> Self {
+        Self {
+            min_idx: self.min_idx.scale(factor),
+            max_idx: self.max_idx.scale(factor),
+        }
+    }
+
+    /// Every index is visited whatever the step size, so integrals are plain sums.
+    fn measure<R: RealField>(&self, _: i32) -> R {
+        R::one()
+    }
 }
 
 impl Mul for FiniteSubDomain {
@@ -0,0 +1,90 @@
+//! Implementation of periodic subdomains.
+//! E.g., a particle on a ring has coordinates in [0, circumference), with the ends identified.
+
+use std::ops::{Add, Mul};
+
+use super::SubDomain;
+
+/// A 1D subdomain with periodic boundary conditions, covering one full period
+/// `[0, circumference)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeriodicSubDomain1D {
+    /// The length of one period of the subdomain
+    pub circumference: f32,
+}
+
+impl PeriodicSubDomain1D {
+    /// Wrap a coordinate into the range `[0, circumference)`
+    #[must_use]
+    pub fn wrap(&self, x: f32) -> f32 {
+        x.rem_euclid(self.circumference)
+    }
+}
+
+impl SubDomain<f32> for PeriodicSubDomain1D {
+    const PERIODIC: bool = true;
+
+    fn contains(&self, _: f32) -> bool {
+        true
+    }
+
+    fn all() -> Self {
+        Self {
+            circumference: f32::INFINITY,
+        }
+    }
+
+    fn none() -> Self {
+        Self { circumference: 0.0 }
+    }
+
+    /// Zero, negative and NaN step sizes visit no points, rather than never terminating.
+    fn iter_with_step_size(
+        &self,
+        step_size: f32,
+    ) -> impl Iterator<Item = f32> + Sized + Send + Sync {
+        let circumference = self.circumference;
+        (0..)
+            .map(move |i| i as f32 * step_size)
+            .take_while(move |x| step_size > 0.0 && *x < circumference)
+    }
+
+    fn translate(self, _: f32) -> Self {
+        // Translation wraps around the ring, so it covers the same period.
+        self
+    }
+
+    fn reflect(self) -> Self {
+        self
+    }
+
+    fn dilate(self, factor: f32) -> Self {
+        Self {
+            circumference: self.circumference * factor,
+        }
+    }
+
+    fn wrap(&self, x: f32) -> f32 {
+        PeriodicSubDomain1D::wrap(self, x)
+    }
+}
+
+impl Mul for PeriodicSubDomain1D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            circumference: self.circumference.min(rhs.circumference),
+        }
+    }
+}
+
+impl Add for PeriodicSubDomain1D {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            circumference: self.circumference.max(rhs.circumference),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Implementation of product domains, for wavefunctions of two subsystems such as two particles,
+//! or the spin and motion of a single particle.
+
+use std::ops::{Add, Mul, Sub};
+
+use super::super::field::RealField;
+use super::{Domain, SubDomain};
+
+/// A point in the product of two domains, with a coordinate in each.
+/// Points are ordered lexicographically, first by the first coordinate and then by the second.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct TensorPoint<A, B>(pub A, pub B);
+
+impl<A: Domain, B: Domain> Add for TensorPoint<A, B> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<A: Domain, B: Domain> Sub for TensorPoint<A, B> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl<A: Domain, B: Domain> Domain for TensorPoint<A, B> {
+    fn first() -> Self {
+        Self(A::first(), B::first())
+    }
+
+    fn last() -> Self {
+        Self(A::last(), B::last())
+    }
+
+    fn zero() -> Self {
+        Self(A::zero(), B::zero())
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        Self(self.0.scale(factor), self.1.scale(factor))
+    }
+
+    fn volume<R: RealField>(self) -> R {
+        self.0.volume::<R>() * self.1.volume::<R>()
+    }
+}
+
+/// The product of a subdomain of each of two domains
+#[derive(Clone, Debug, PartialEq)]
+pub struct TensorSubDomain<SA, SB>(pub SA, pub SB);
+
+impl<A, B, SA, SB> SubDomain<TensorPoint<A, B>> for TensorSubDomain<SA, SB>
+where
+    A: Domain,
+    B: Domain,
+    SA: SubDomain<A>,
+    SB: SubDomain<B>,
+{
+    const PERIODIC: bool = SA::PERIODIC || SB::PERIODIC;
+
+    fn contains(&self, p: TensorPoint<A, B>) -> bool {
+        self.0.contains(p.0) && self.1.contains(p.1)
+    }
+
+    fn all() -> Self {
+        Self(SA::all(), SB::all())
+    }
+
+    fn none() -> Self {
+        Self(SA::none(), SB::none())
+    }
+
+    /// Iterate over the grid of points in this subdomain, stepping through the first
+    /// coordinate for each value of the second, with the given step size in each coordinate.
+    fn iter_with_step_size(
+        &self,
+        step_size: TensorPoint<A, B>,
+    ) -> impl Iterator<Item = TensorPoint<A, B>> + Sized + Send + Sync {
+        let firsts: Vec<A> = self.0.iter_with_step_size(step_size.0).collect();
+        self.1
+            .iter_with_step_size(step_size.1)
+            .flat_map(move |b| firsts.clone().into_iter().map(move |a| TensorPoint(a, b)))
+    }
+
+    fn translate(self, offset: TensorPoint<A, B>) -> Self {
+        Self(self.0.translate(offset.0), self.1.translate(offset.1))
+    }
+
+    fn reflect(self) -> Self {
+        Self(self.0.reflect(), self.1.reflect())
+    }
+
+    fn dilate(self, factor: f32) -> Self {
+        Self(self.0.dilate(factor), self.1.dilate(factor))
+    }
+
+    fn quadrature_weight<R: RealField>(
+        &self,
+        p: TensorPoint<A, B>,
+        step_size: TensorPoint<A, B>,
+    ) -> R {
+        self.0.quadrature_weight::<R>(p.0, step_size.0)
+            * self.1.quadrature_weight::<R>(p.1, step_size.1)
+    }
+
+    /// Weight the grid in the same order as `iter_with_step_size`, finding the weights of the
+    /// first coordinate once for every value of the second.
+    fn iter_weighted<R: RealField>(
+        &self,
+        step_size: TensorPoint<A, B>,
+    ) -> impl Iterator<Item = (TensorPoint<A, B>, R)> + Send + Sync {
+        let firsts: Vec<(A, R)> = self.0.iter_weighted(step_size.0).collect();
+        self.1
+            .iter_weighted::<R>(step_size.1)
+            .flat_map(move |(b, b_weight)| {
+                firsts
+                    .clone()
+                    .into_iter()
+                    .map(move |(a, a_weight)| (TensorPoint(a, b), a_weight * b_weight))
+            })
+    }
+
+    /// The product of the measures of each subdomain, so that a discrete factor is summed
+    /// over while a continuous one is integrated.
+    fn measure<R: RealField>(&self, step_size: TensorPoint<A, B>) -> R {
+        self.0.measure::<R>(step_size.0) * self.1.measure::<R>(step_size.1)
+    }
+
+    fn wrap(&self, p: TensorPoint<A, B>) -> TensorPoint<A, B> {
+        TensorPoint(self.0.wrap(p.0), self.1.wrap(p.1))
+    }
+}
+
+impl<SA: Add<Output = SA>, SB: Add<Output = SB>> Add for TensorSubDomain<SA, SB> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<SA: Mul<Output = SA>, SB: Mul<Output = SB>> Mul for TensorSubDomain<SA, SB> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
@@ -1,8 +1,11 @@
 //! Functionality for representing mathematical fields
 
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
 
-use num_complex::Complex32;
+use num_complex::{Complex32, Complex64};
 
 /// Trait requiring properties of a field (the mathematical object) with an involution for conjugation.
 pub trait Field:
@@ -16,6 +19,9 @@ pub trait Field:
     + Div<Output = Self>
     + Neg<Output = Self>
 {
+    /// The real numbers embedded in this field, in the same precision. Moduli, and the weights and
+    /// volume elements of integrals over wavefunctions valued in this field, are of this type.
+    type Real: RealField;
     /// The additive identity of the field
     #[must_use]
     fn zero() -> Self;
@@ -29,9 +35,30 @@ pub trait Field:
     /// Take conjugate of the element.
     #[must_use]
     fn conjugate(self) -> Self;
+    /// The modulus (absolute value) of the element
+    fn modulus(&self) -> Self::Real;
+    /// Embed a real number in the field
+    #[must_use]
+    fn from_real(x: Self::Real) -> Self;
+}
+
+/// Trait for the fields of real numbers, in which moduli, quadrature weights and volume elements
+/// are computed.
+pub trait RealField: Field<Real = Self> + PartialOrd + fmt::Display + Send + Sync {
+    /// The non-negative square root of this number
+    #[must_use]
+    fn sqrt(self) -> Self;
+    /// Check that this number is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+    /// Round a double precision number to this field
+    fn from_f64(x: f64) -> Self;
+    /// Round this number to single precision, e.g. to report a probability
+    fn to_f32(self) -> f32;
 }
 
 impl Field for f32 {
+    type Real = f32;
+
     fn zero() -> Self {
         0.0
     }
@@ -55,9 +82,19 @@ impl Field for f32 {
     fn conjugate(self) -> Self {
         self
     }
+
+    fn modulus(&self) -> f32 {
+        self.abs()
+    }
+
+    fn from_real(x: f32) -> Self {
+        x
+    }
 }
 
 impl Field for Complex32 {
+    type Real = f32;
+
     fn zero() -> Self {
         Complex32::new(0.0, 0.0)
     }
@@ -81,4 +118,120 @@ impl Field for Complex32 {
     fn conjugate(self) -> Self {
         self.conj()
     }
+
+    fn modulus(&self) -> f32 {
+        self.norm()
+    }
+
+    fn from_real(x: f32) -> Self {
+        Complex32::new(x, 0.0)
+    }
+}
+
+impl Field for f64 {
+    type Real = f64;
+
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(1.0 / *self)
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0.0
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn modulus(&self) -> f64 {
+        self.abs()
+    }
+
+    fn from_real(x: f64) -> Self {
+        x
+    }
+}
+
+impl Field for Complex64 {
+    type Real = f64;
+
+    fn zero() -> Self {
+        Complex64::new(0.0, 0.0)
+    }
+
+    fn one() -> Self {
+        Complex64::new(1.0, 0.0)
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.inv())
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Complex64::ZERO
+    }
+
+    fn conjugate(self) -> Self {
+        self.conj()
+    }
+
+    fn modulus(&self) -> f64 {
+        self.norm()
+    }
+
+    fn from_real(x: f64) -> Self {
+        Complex64::new(x, 0.0)
+    }
+}
+
+impl RealField for f32 {
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+impl RealField for f64 {
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
 }
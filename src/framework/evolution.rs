@@ -0,0 +1,8 @@
+//! Numerical time evolution of wavefunctions under the Schroedinger equation, for potentials
+//! without known eigenstates.
+
+mod crank_nicolson;
+mod rk4;
+
+pub use crank_nicolson::CrankNicolsonEvolver;
+pub use rk4::RK4Evolver;
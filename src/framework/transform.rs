@@ -0,0 +1,7 @@
+//! Transforms between different representations of wavefunctions.
+
+mod convolution;
+mod fourier;
+
+pub use convolution::Edges;
+pub(crate) use fourier::momentum_samples;
@@ -0,0 +1,90 @@
+//! Convolution of sampled 1D wavefunctions with kernels via FFT, e.g. for smoothing or modelling
+//! finite-resolution measurements.
+
+use num_complex::Complex32;
+use rustfft::FftPlanner;
+
+use super::super::{
+    braket::{Interp, Ket},
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+
+/// How a convolution treats samples beyond the edges of the sampled grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Edges {
+    /// Treat samples beyond the edges as zero
+    #[default]
+    ZeroPad,
+    /// Wrap samples beyond one edge around to the other
+    Periodic,
+}
+
+impl Ket<Sign1D> {
+    /// Return the convolution of this ket at time `t` with `kernel`, sampled over the subdomain of
+    /// the ket at the evenly spaced points `SubDomain1D::uniform_points` with the given
+    /// `step_size`, using `edges` to handle samples beyond the grid.
+    ///
+    /// The kernel is sampled with the same spacing as the ket, and is centred on its middle
+    /// sample (rounding down for even lengths), so `[1]` or `[0, 1, 0]` leave the ket unchanged.
+    /// The kernel is not rescaled, so it should sum to one to preserve the norm when smoothing.
+    ///
+    /// The returned ket is a snapshot at time `t`, so it is constant in time. Values between
+    /// sampled points are linearly interpolated.
+    #[must_use]
+    pub fn convolve(
+        &self,
+        kernel: &[Complex32],
+        t: f32,
+        step_size: f32,
+        edges: Edges,
+    ) -> Ket<Sign1D> {
+        let lower = self.subdomain.lower;
+        let samples: Vec<Complex32> = self
+            .subdomain
+            .uniform_points(step_size)
+            .map(|x| self.f(x, t))
+            .collect();
+        let (n, m) = (samples.len(), kernel.len());
+        if n == 0 || m == 0 {
+            return Ket::default();
+        }
+        let centre = (m - 1) / 2;
+
+        // Zero padding to the full length of the linear convolution prevents wrapping around
+        let len = match edges {
+            Edges::ZeroPad => n + m - 1,
+            Edges::Periodic => n,
+        };
+        let mut signal = vec![Complex32::ZERO; len];
+        signal[..n].copy_from_slice(&samples);
+        let mut filter = vec![Complex32::ZERO; len];
+        for (j, &k) in kernel.iter().enumerate() {
+            let index = match edges {
+                Edges::ZeroPad => j,
+                // Shift the centre of the kernel to the origin
+                Edges::Periodic => (j + n * m - centre) % n,
+            };
+            filter[index] += k;
+        }
+
+        let mut planner = FftPlanner::new();
+        let forward = planner.plan_fft_forward(len);
+        forward.process(&mut signal);
+        forward.process(&mut filter);
+        for (s, f) in signal.iter_mut().zip(&filter) {
+            *s *= f / len as f32;
+        }
+        planner.plan_fft_inverse(len).process(&mut signal);
+
+        let offset = match edges {
+            Edges::ZeroPad => centre,
+            Edges::Periodic => 0,
+        };
+        Ket::from_samples(
+            lower,
+            step_size,
+            signal[offset..offset + n].to_vec(),
+            Interp::Linear,
+        )
+    }
+}
@@ -0,0 +1,89 @@
+//! Discrete Fourier transforms taking 1D wavefunctions from position space to momentum space.
+
+use std::f32::consts::PI;
+
+use num_complex::Complex32;
+use rustfft::FftPlanner;
+
+use super::super::{
+    braket::{Interp, Ket},
+    core::{domain::SubDomain1D, vectorspace::VectorSpace},
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+
+impl Ket<Sign1D> {
+    /// Return the momentum space representation of this ket at time `t`.
+    ///
+    /// The ket is sampled at the evenly spaced points `SubDomain1D::uniform_points` of its
+    /// subdomain with the given `step_size` and transformed via FFT.
+    /// The `Space` axis of the returned ket represents the wavenumber `k`, sampled with spacing
+    /// `dk = 2 pi / (N dx)` and centred on `k = 0`. Amplitudes use the unitary `1/sqrt(N)`
+    /// normalisation, rescaled by `sqrt(dx / dk)` so that the norm of the ket is preserved.
+    ///
+    /// The returned ket is a snapshot at time `t`, so it is constant in time. Values between
+    /// sampled wavenumbers are linearly interpolated.
+    #[must_use]
+    pub fn to_momentum_space(&self, t: f32, step_size: f32) -> Ket<Sign1D> {
+        let samples: Vec<Complex32> = self
+            .subdomain
+            .uniform_points(step_size)
+            .map(|x| self.f(x, t))
+            .collect();
+        if samples.is_empty() {
+            return Ket::default();
+        }
+
+        let (k_min, dk, values) = momentum_samples(samples, self.subdomain.lower, step_size);
+        Ket::from_samples(k_min, dk, values, Interp::Linear)
+    }
+
+    /// Return the momentum space wavefunction
+    /// `phi(p) = (1 / sqrt(2 pi hbar)) int psi(x) e^(-ipx / hbar) dx` of this ket at time `t`,
+    /// restricted to the momenta in `p_domain`.
+    ///
+    /// This is `to_momentum_space` with the wavenumber axis rescaled to `p = hbar k`, and the
+    /// amplitudes by `1 / sqrt(hbar)` so that `|phi(p)|^2` is a density over momentum. Momenta are
+    /// sampled with spacing `hbar dk`, so the subdomain of this ket and `step_size` should be
+    /// chosen so that the samples resolve and span `p_domain`.
+    #[must_use]
+    pub fn to_momentum_space_p(
+        &self,
+        t: f32,
+        step_size: f32,
+        hbar: f32,
+        p_domain: SubDomain1D<f32>,
+    ) -> Ket<Sign1D> {
+        self.to_momentum_space(t, step_size)
+            .scale_space(hbar)
+            .scale(Complex32::from(hbar.sqrt().recip()))
+            .restrict(p_domain)
+    }
+}
+
+/// Transform the samples of a wavefunction taken every `step_size` from `x_min` to momentum
+/// space via FFT, returning the smallest wavenumber `k_min`, the spacing `dk` of wavenumbers,
+/// and the amplitude at each wavenumber in increasing order, normalised as in
+/// `Ket::to_momentum_space`. There must be at least one sample.
+pub(crate) fn momentum_samples(
+    mut buffer: Vec<Complex32>,
+    x_min: f32,
+    step_size: f32,
+) -> (f32, f32, Vec<Complex32>) {
+    let n = buffer.len();
+    FftPlanner::new().plan_fft_forward(n).process(&mut buffer);
+
+    let dk = 2.0 * PI / (n as f32 * step_size);
+    let norm = (step_size / dk).sqrt() / (n as f32).sqrt();
+    let half = n / 2;
+    let k_min = -(half as f32) * dk;
+
+    // Reorder the FFT output so wavenumbers are increasing, and correct the phase for
+    // the samples not starting at the origin.
+    let values = (0..n)
+        .map(|j| {
+            let k = k_min + j as f32 * dk;
+            buffer[(j + n - half) % n] * norm * Complex32::cis(-k * x_min)
+        })
+        .collect();
+    (k_min, dk, values)
+}
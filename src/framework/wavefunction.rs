@@ -2,18 +2,56 @@
 
 pub mod signature;
 
+use num_complex::Complex32;
 use signature::WFSignature;
 
+use super::core::domain::SubDomain;
+
 /// Require ability to evaluate a wavefunction at points in domain
 pub trait Wavefunction<S: WFSignature> {
     /// Evaluate the wavefunction at a point in space and time
     fn f(&self, x: S::Space, t: S::Time) -> S::Out;
     /// Evaluate the probability density at a point in space and time
     fn p(&self, x: S::Space, t: S::Time) -> S::Out;
+    /// Integrate the wavefunction itself (not its probability density) over its subdomain at
+    /// time `t`, sampling with the given `step_size`.
+    fn integrate(&self, t: S::Time, step_size: S::Space) -> S::Out;
+    /// Sample the wavefunction at time `t` over `subdomain` with the given `step_size`, returning
+    /// the sampled points and the values at each, in iteration order. The last point is `upper`
+    /// even when the step does not divide the subdomain, so the grid need not be uniform.
+    fn sample_grid(
+        &self,
+        subdomain: &S::SubDom,
+        t: S::Time,
+        step_size: S::Space,
+    ) -> (Vec<S::Space>, Vec<S::Out>) {
+        let points: Vec<S::Space> = subdomain.iter_with_step_size(step_size).collect();
+        let values = points.iter().map(|&x| self.f(x, t)).collect();
+        (points, values)
+    }
+    /// Evaluate the probability current `j = (hbar / m) Im(psi* d psi / dx)` at a point in space
+    /// and time, with the derivative taken by central differences with spacing `h`. This
+    /// vanishes for real wavefunctions such as stationary states of real potentials, and is
+    /// positive where probability flows towards larger `x`.
+    fn current(&self, x: f32, t: f32, hbar: f32, mass: f32, h: f32) -> f32
+    where
+        S: WFSignature<Space = f32, Time = f32, Out = Complex32>,
+    {
+        let derivative = (self.f(x + h, t) - self.f(x - h, t)) / (2.0 * h);
+        hbar / mass * (self.f(x, t).conj() * derivative).im
+    }
     /// Return the wavefunction with a translation applied in space.
     #[must_use]
     fn translate_space(self, offset: S::Space) -> Self;
     /// Return the wavefunction with a translation applied in space.
     #[must_use]
     fn translate_time(self, offset: S::Time) -> Self;
+    /// Return the wavefunction reflected in space about the origin.
+    #[must_use]
+    fn reflect_space(self) -> Self;
+    /// Return the wavefunction dilated in space away from the origin by a positive `factor`, so
+    /// that `x -> x / factor`. The amplitude is unchanged, so it should be multiplied by
+    /// `factor^(-d/2)` in `d` dimensions to preserve normalisation.
+    #[must_use]
+    fn scale_space(self, factor: f32) -> Self;
 }
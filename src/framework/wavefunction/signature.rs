@@ -1,10 +1,22 @@
 //! Function signatures stores type and type-interaction information about functions.
 
 mod wf_1d;
+mod wf_1d_f64;
+mod wf_1d_real;
+mod wf_2d;
 mod wf_finite;
+mod wf_radial;
+mod wf_ring;
+mod wf_tensor;
 
 pub use wf_1d::Sign1D;
+pub use wf_1d_f64::Sign1DF64;
+pub use wf_1d_real::Sign1DReal;
+pub use wf_2d::Sign2D;
 pub use wf_finite::SigFinite;
+pub use wf_radial::SignRadial;
+pub use wf_ring::SigRing;
+pub use wf_tensor::TensorSignature;
 
 use super::super::{
     core::domain::{Domain, SubDomain},
@@ -21,7 +33,15 @@ pub trait WFSignature: Clone {
     type Out: Field + Send + Sync;
     /// The type implementing functionality for handling subsets of the domain.
     type SubDom: SubDomain<Self::Space> + Send + Sync;
-    /// Combine elements in space with wavefunction output.
-    /// This defines how to multiply integrands by d(space) when computing inner products.
-    fn mul_to_codomain(a: Self::Space, b: Self::Out) -> Self::Out;
+    /// The number of continuous spatial dimensions of the domain. Discrete domains have none,
+    /// as their points are summed over rather than integrated.
+    const SPATIAL_DIMS: usize;
+    /// The Jacobian of the coordinates at the point `x`, in the real field of the output type.
+    /// Integrands at `x` are multiplied by this and by the coordinate volume element
+    /// `SubDomain::measure` when computing inner products, so curvilinear coordinates only need
+    /// to override this, e.g. with `r^2` for the radial part of a 3D wavefunction. Cartesian
+    /// coordinates keep the default of 1.
+    fn jacobian(_x: Self::Space) -> <Self::Out as Field>::Real {
+        Field::one()
+    }
 }
@@ -14,8 +14,5 @@ impl WFSignature for Sign1D {
     type Time = f32;
     type Out = Complex32;
     type SubDom = SubDomain1D<Self::Space>;
-
-    fn mul_to_codomain(a: Self::Space, b: Self::Out) -> Self::Out {
-        a * b
-    }
+    const SPATIAL_DIMS: usize = 1;
 }
@@ -0,0 +1,20 @@
+//! Implementation of wavefunction signature for 1D domains in double precision.
+
+use num_complex::Complex64;
+
+use super::super::super::core::domain::SubDomain1D;
+use super::WFSignature;
+
+/// Wavefunction signature for 1 spatial dimension and 1 temporal dimension, like `Sign1D` but
+/// with space, time and values in double precision. Quadrature weights, volume elements and the
+/// sums in inner products are all computed in double precision too.
+#[derive(Clone)]
+pub struct Sign1DF64;
+
+impl WFSignature for Sign1DF64 {
+    type Space = f64;
+    type Time = f64;
+    type Out = Complex64;
+    type SubDom = SubDomain1D<Self::Space>;
+    const SPATIAL_DIMS: usize = 1;
+}
@@ -0,0 +1,18 @@
+//! Implementation of wavefunction signature for real-valued fields on 1D domains.
+
+use super::super::super::core::domain::SubDomain1D;
+use super::WFSignature;
+
+/// Wavefunction signature for real-valued fields in 1 spatial dimension and 1 temporal
+/// dimension, such as the modes of a vibrating string. Conjugation is the identity, so the
+/// adjoint of a ket has the same values as the ket itself.
+#[derive(Clone)]
+pub struct Sign1DReal;
+
+impl WFSignature for Sign1DReal {
+    type Space = f32;
+    type Time = f32;
+    type Out = f32;
+    type SubDom = SubDomain1D<Self::Space>;
+    const SPATIAL_DIMS: usize = 1;
+}
@@ -0,0 +1,18 @@
+//! Implementation of wavefunction signature for 2D domains.
+
+use num_complex::Complex32;
+
+use super::super::super::core::domain::{Point2D, SubDomain2D};
+use super::WFSignature;
+
+/// Standard wavefunction signature for 2 spatial dimensions and 1 temporal dimension.
+#[derive(Clone)]
+pub struct Sign2D;
+
+impl WFSignature for Sign2D {
+    type Space = Point2D;
+    type Time = f32;
+    type Out = Complex32;
+    type SubDom = SubDomain2D;
+    const SPATIAL_DIMS: usize = 2;
+}
@@ -13,8 +13,5 @@ impl WFSignature for SigFinite {
     type Time = f32;
     type Out = Complex32;
     type SubDom = FiniteSubDomain;
-
-    fn mul_to_codomain(a: Self::Space, b: Self::Out) -> Self::Out {
-        (a as f32) * b
-    }
+    const SPATIAL_DIMS: usize = 0;
 }
@@ -0,0 +1,24 @@
+//! Implementation of wavefunction signature for the radial part of 3D wavefunctions.
+
+use num_complex::Complex32;
+
+use super::super::super::core::domain::SubDomain1D;
+use super::WFSignature;
+
+/// Wavefunction signature for the radial part `R(r)` of a 3D wavefunction
+/// `R(r) Y(theta, phi)` with 1 temporal dimension. The angular part is taken to be normalised
+/// separately, so inner products integrate over `r` with the volume element `r^2 dr`.
+#[derive(Clone)]
+pub struct SignRadial;
+
+impl WFSignature for SignRadial {
+    type Space = f32;
+    type Time = f32;
+    type Out = Complex32;
+    type SubDom = SubDomain1D<Self::Space>;
+    const SPATIAL_DIMS: usize = 1;
+
+    fn jacobian(x: Self::Space) -> f32 {
+        x * x
+    }
+}
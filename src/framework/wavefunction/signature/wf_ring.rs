@@ -0,0 +1,17 @@
+//! Implementation of wavefunction signature for periodic 1D domains.
+
+use num_complex::Complex32;
+
+use super::{super::super::core::domain::periodic_domains::PeriodicSubDomain1D, WFSignature};
+
+/// Wavefunction signature for 1 periodic spatial dimension and 1 temporal dimension.
+#[derive(Clone)]
+pub struct SigRing;
+
+impl WFSignature for SigRing {
+    type Space = f32;
+    type Time = f32;
+    type Out = Complex32;
+    type SubDom = PeriodicSubDomain1D;
+    const SPATIAL_DIMS: usize = 1;
+}
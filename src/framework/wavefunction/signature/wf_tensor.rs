@@ -0,0 +1,34 @@
+//! Implementation of wavefunction signature for product (tensor) spaces of two subsystems.
+
+use std::marker::PhantomData;
+
+use super::super::super::core::domain::tensor_domains::{TensorPoint, TensorSubDomain};
+use super::super::super::core::field::Field;
+use super::WFSignature;
+
+/// Wavefunction signature for the tensor product of two subsystems with signatures `A` and `B`,
+/// sharing the same time and output types. Points in space hold a coordinate of each subsystem,
+/// and integrals over the product space are products of integrals over each subsystem.
+pub struct TensorSignature<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> Clone for TensorSignature<A, B> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B> WFSignature for TensorSignature<A, B>
+where
+    A: WFSignature,
+    B: WFSignature<Time = A::Time, Out = A::Out>,
+{
+    type Space = TensorPoint<A::Space, B::Space>;
+    type Time = A::Time;
+    type Out = A::Out;
+    type SubDom = TensorSubDomain<A::SubDom, B::SubDom>;
+    const SPATIAL_DIMS: usize = A::SPATIAL_DIMS + B::SPATIAL_DIMS;
+
+    fn jacobian(x: Self::Space) -> <Self::Out as Field>::Real {
+        A::jacobian(x.0) * B::jacobian(x.1)
+    }
+}
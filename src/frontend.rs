@@ -1,8 +1,19 @@
 //! Bevy ECS frontend for the app
 
+mod color_scheme;
+mod launch;
 mod run;
 mod startup;
 mod wf_1d_vis;
+mod wf_2d_vis;
 mod wf_component;
 
-pub use run::run_viz_1d;
+pub use color_scheme::{ColorScheme, LinePalette};
+pub use launch::Qwaviz;
+pub use run::{
+    add_viz_1d, render_snapshot, run_viz_1d, run_viz_1d_in, run_viz_1d_with, run_viz_2d,
+    run_viz_eigenstates, run_viz_evolution, run_viz_ladder, run_viz_mixture, run_viz_wigner,
+};
+pub use wf_1d_vis::CacheInterpolation;
+pub use wf_2d_vis::Colormap;
+pub use wf_component::PhysicalParams;
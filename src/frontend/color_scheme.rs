@@ -0,0 +1,158 @@
+//! A resource holding the palettes used to draw every visualisation in the scene.
+
+use bevy::{
+    color::{Alpha, Color, LinearRgba},
+    ecs::{
+        resource::Resource,
+        system::{Res, ResMut},
+    },
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use super::{wf_2d_vis::Colormap, wf_component::WFType};
+
+/// Palettes for the polylines and fills of 1D wavefunctions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LinePalette {
+    /// Red for real parts, blue for imaginary parts, and white for densities
+    #[default]
+    Classic,
+    /// Colors sampled from the viridis colormap
+    Viridis,
+}
+
+/// The palettes used to draw the scene. Changing this resource recolours every
+/// visualisation at runtime.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorScheme {
+    /// The palette of 1D wavefunction polylines and fills
+    pub lines: LinePalette,
+    /// The colormap for probability densities
+    pub density: Colormap,
+    /// The (cyclic) colormap for phases
+    pub phase: Colormap,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl ColorScheme {
+    /// Classic RGB lines, with rainbow densities
+    #[must_use]
+    pub fn classic() -> Self {
+        Self {
+            lines: LinePalette::Classic,
+            density: Colormap::Turbo,
+            phase: Colormap::Twilight,
+        }
+    }
+
+    /// Perceptually uniform viridis lines and densities
+    #[must_use]
+    pub fn viridis() -> Self {
+        Self {
+            lines: LinePalette::Viridis,
+            density: Colormap::Viridis,
+            phase: Colormap::Twilight,
+        }
+    }
+
+    /// Switch to the next named scheme, cycling between `classic` and `viridis`
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self.lines {
+            LinePalette::Classic => Self::viridis(),
+            LinePalette::Viridis => Self::classic(),
+        }
+    }
+}
+
+impl LinePalette {
+    /// Get the (HDR) polyline color for a particular `WFType`
+    pub(in crate::frontend) fn polyline_color(self, wf_type: &WFType) -> LinearRgba {
+        match self {
+            LinePalette::Classic => match wf_type {
+                WFType::Full => LinearRgba::rgb(1.0, 2.0, 1.0),
+                WFType::Real => LinearRgba::rgb(15.0, 0.0, 0.0),
+                WFType::Imag => LinearRgba::rgb(0.0, 0.0, 15.0),
+                WFType::Density => LinearRgba::rgb(10.0, 10.0, 10.0),
+                WFType::Potential => LinearRgba::rgb(15.0, 2.0, 0.0),
+                WFType::Classical => LinearRgba::rgb(0.0, 10.0, 2.0),
+                WFType::LogDensity => LinearRgba::rgb(10.0, 6.0, 0.0),
+                WFType::MomentumDensity => LinearRgba::rgb(8.0, 0.0, 10.0),
+                WFType::Current => LinearRgba::rgb(0.0, 8.0, 10.0),
+            },
+            LinePalette::Viridis => {
+                let intensity = match wf_type {
+                    WFType::Full => 2.0,
+                    WFType::Real | WFType::Imag | WFType::Potential | WFType::Current => 15.0,
+                    WFType::Density
+                    | WFType::Classical
+                    | WFType::LogDensity
+                    | WFType::MomentumDensity => 10.0,
+                };
+                let color = LinearRgba::from(viridis_color(wf_type));
+                LinearRgba::rgb(
+                    color.red * intensity,
+                    color.green * intensity,
+                    color.blue * intensity,
+                )
+            }
+        }
+    }
+
+    /// Get the `FilledWave` color for a particular `WFType`, or `None` for
+    /// `WFType::Full`, `WFType::Potential`, `WFType::Classical`,
+    /// `WFType::LogDensity`, `WFType::MomentumDensity` and `WFType::Current`,
+    /// which cannot be filled
+    pub(in crate::frontend) fn fill_color(self, wf_type: &WFType) -> Option<Color> {
+        let color = match (self, wf_type) {
+            (
+                _,
+                WFType::Full
+                | WFType::Potential
+                | WFType::Classical
+                | WFType::LogDensity
+                | WFType::MomentumDensity
+                | WFType::Current,
+            ) => {
+                return None;
+            }
+            (LinePalette::Classic, WFType::Real) => Color::srgb(1.0, 0.2, 0.2),
+            (LinePalette::Classic, WFType::Imag) => Color::srgb(0.3, 0.3, 1.0),
+            (LinePalette::Classic, WFType::Density) => Color::srgb(1.0, 1.0, 1.0),
+            (LinePalette::Viridis, _) => viridis_color(wf_type),
+        };
+        Some(color.with_alpha(0.7))
+    }
+}
+
+/// Get the color of a particular `WFType` in the viridis line palette
+fn viridis_color(wf_type: &WFType) -> Color {
+    let value = match wf_type {
+        WFType::Full => 0.95,
+        WFType::Real => 0.6,
+        WFType::Imag => 0.25,
+        WFType::Density => 0.8,
+        WFType::Potential => 0.45,
+        WFType::Classical => 0.05,
+        WFType::LogDensity => 0.7,
+        WFType::MomentumDensity => 0.35,
+        WFType::Current => 0.15,
+    };
+    let [r, g, b] = Colormap::Viridis.rgb(value);
+    Color::srgb(r, g, b)
+}
+
+/// Switch to the next named `ColorScheme` whenever `C` is pressed
+pub(in crate::frontend) fn cycle_color_scheme_system(
+    mut scheme: ResMut<ColorScheme>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    if key_input.just_pressed(KeyCode::KeyC) {
+        *scheme = scheme.next();
+    }
+}
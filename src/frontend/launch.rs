@@ -0,0 +1,146 @@
+//! A builder for visualising kets built by library users, without assembling
+//! the bevy app by hand.
+
+use std::sync::Arc;
+
+use bevy::prelude::{PreUpdate, Startup, Update};
+
+use crate::{
+    framework::{braket::Ket, wavefunction::signature::Sign1D},
+    frontend::wf_1d_vis::{
+        CacheInterpolation, WFComponentBuilder, export_csv_system, fit_camera_system,
+        info_label_system, recenter_system, recolor_wavefunction_system, toggle_current_system,
+        toggle_ghost_system, toggle_log_density_system, toggle_momentum_system,
+        update_cache_system, wf_animation_system,
+    },
+    quantum_system::DiscreteSystem,
+};
+
+use super::{
+    color_scheme::ColorScheme, run::viz_app, startup::get_setup_kets, wf_component::PhysicalParams,
+};
+
+/// A builder for a visualisation of any number of 1D kets, which constructs
+/// the bevy app and starts it with `run`. Each ket is drawn with the same
+/// polylines and keyboard controls as `run_viz_1d`.
+#[derive(Default)]
+pub struct Qwaviz {
+    /// The kets to visualise, each with its depth along -z
+    kets: Vec<(Ket<Sign1D>, f32)>,
+    /// The system whose potential is drawn with each ket, if any
+    system: Option<Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>>,
+    /// The physical constants of the visualised particles
+    params: PhysicalParams,
+    /// The template from which the component drawing each ket is built
+    component: WFComponentBuilder,
+    /// The palettes the scene is drawn with
+    color_scheme: ColorScheme,
+}
+
+impl Qwaviz {
+    /// Create an empty visualisation, with default physical constants,
+    /// interpolation and colour scheme.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a ket to the visualisation, drawn at the origin.
+    #[must_use]
+    pub fn add_ket(self, ket: Ket<Sign1D>) -> Self {
+        self.add_ket_behind(ket, 0.0)
+    }
+
+    /// Add a ket to the visualisation, drawn `depth` behind the origin along
+    /// -z, so that several kets can be compared side by side.
+    #[must_use]
+    pub fn add_ket_behind(mut self, ket: Ket<Sign1D>, depth: f32) -> Self {
+        self.kets.push((ket, depth));
+        self
+    }
+
+    /// Draw the potential of `system` with each ket, and flag its special
+    /// times as they are reached.
+    #[must_use]
+    pub fn in_system(
+        mut self,
+        system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    ) -> Self {
+        self.system = Some(Arc::new(system));
+        self
+    }
+
+    /// Use the given physical constants
+    #[must_use]
+    pub fn with_params(mut self, params: PhysicalParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Use the given interpolation between cached samples of each ket
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolation: CacheInterpolation) -> Self {
+        self.component = self.component.interpolation(interpolation);
+        self
+    }
+
+    /// Animate each ket at the given time scale. Lower values are slower.
+    #[must_use]
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.component = self.component.time_scale(time_scale);
+        self
+    }
+
+    /// Draw each ket at the time `time_fn(s)` after it has evolved for `s`
+    /// seconds of wall-clock time, in place of the linear time scale. Pausing
+    /// stops the wall clock, so e.g. `|s| 2.0 * (s % 5.0)` loops over the
+    /// first 10 time units.
+    #[must_use]
+    pub fn with_time_fn(mut self, time_fn: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        self.component = self.component.time_fn(time_fn);
+        self
+    }
+
+    /// Render each ket every `render_step_size`, interpolating between its
+    /// cached samples
+    #[must_use]
+    pub fn with_render_step_size(mut self, render_step_size: f32) -> Self {
+        self.component = self.component.render_step_size(render_step_size);
+        self
+    }
+
+    /// Draw the scene with the given palettes
+    #[must_use]
+    pub fn with_color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = color_scheme;
+        self
+    }
+
+    /// Construct the bevy app, spawn the kets and run the app until its
+    /// window is closed.
+    pub fn run(self) {
+        viz_app()
+            .insert_resource(self.color_scheme)
+            .add_systems(
+                Startup,
+                get_setup_kets(self.kets, self.system, self.params, self.component),
+            )
+            .add_systems(PreUpdate, (update_cache_system,))
+            .add_systems(
+                Update,
+                (
+                    wf_animation_system,
+                    export_csv_system,
+                    info_label_system,
+                    fit_camera_system,
+                    recolor_wavefunction_system,
+                    toggle_ghost_system,
+                    recenter_system,
+                    toggle_log_density_system,
+                    toggle_momentum_system,
+                    toggle_current_system,
+                ),
+            )
+            .run();
+    }
+}
@@ -1,26 +1,348 @@
 //! Functionality to start the frontend by running an bevy app.
 
+use std::{path::Path, sync::Arc};
+
 use bevy::{
     app::PreUpdate,
     color::Color,
     dev_tools::fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin, FrameTimeGraphConfig},
-    prelude::{App, DefaultPlugins, Startup, Update},
+    ecs::schedule::common_conditions::resource_exists,
+    prelude::{App, DefaultPlugins, IntoScheduleConfigs, Startup, Update},
     text::TextFont,
 };
 use bevy_infinite_grid::InfiniteGridPlugin;
 use bevy_panorbit_camera::PanOrbitCameraPlugin;
 use bevy_polyline::PolylinePlugin;
+use num_complex::Complex32;
 
 use crate::{
-    framework::{braket::Ket, wavefunction::signature::Sign1D},
-    frontend::wf_1d_vis::update_cache_system,
+    framework::{
+        braket::{DensityMatrix, Ket},
+        core::{
+            domain::{Point2D, SubDomain, SubDomain1D},
+            vectorspace::VectorSpace,
+        },
+        wavefunction::signature::{Sign1D, Sign2D},
+    },
+    frontend::wf_1d_vis::{
+        CacheInterpolation, EigenstateSelector, ExpansionSource, SnapshotRequest,
+        basis_size_system, classical_density_system, eigenstate_cycle_system, export_csv_system,
+        fit_camera_system, info_label_system, mixture_animation_system, rebuild_expansion_system,
+        recenter_system, recolor_wavefunction_system, snapshot_system, toggle_current_system,
+        toggle_ghost_system, toggle_log_density_system, toggle_momentum_system,
+        update_cache_system,
+    },
+    quantum_system::DiscreteSystem,
 };
 
-use super::{startup::get_setup, wf_1d_vis::wf_animation_system};
+use super::{
+    color_scheme::{ColorScheme, cycle_color_scheme_system},
+    startup::{get_setup, get_setup_2d, get_setup_ladder, get_setup_mixture, get_setup_wigner},
+    wf_1d_vis::wf_animation_system,
+    wf_2d_vis::{Colormap, update_heatmap_system},
+    wf_component::PhysicalParams,
+};
 
-/// Run the application and visualise a single 1D wavefunction.
+/// Run the application and visualise a single 1D wavefunction, with the
+/// default physical constants and interpolation between cached samples of the
+/// wavefunction. Use the `Qwaviz` builder to choose the interpolation.
 pub fn run_viz_1d(ket: Ket<Sign1D>) {
-    App::new()
+    run_viz_1d_with(ket, PhysicalParams::default());
+}
+
+/// Run the application and visualise a single 1D wavefunction with the given
+/// physical constants, as in `run_viz_1d`.
+pub fn run_viz_1d_with(ket: Ket<Sign1D>, params: PhysicalParams) {
+    add_viz_1d(&mut viz_app(), ket, params).run();
+}
+
+/// Add the systems visualising a single 1D wavefunction with the given
+/// physical constants to `app`, as in `run_viz_1d_with`, without running it.
+/// The app must provide the asset, rendering and input plugins which
+/// `run_viz_1d` adds.
+pub fn add_viz_1d(app: &mut App, ket: Ket<Sign1D>, params: PhysicalParams) -> &mut App {
+    add_viz_1d_systems(
+        app,
+        ket,
+        None,
+        None,
+        None,
+        params,
+        CacheInterpolation::default(),
+    )
+}
+
+/// Run the application and visualise a single 1D wavefunction evolving in
+/// `system`, along with the potential of the system. The special times of the
+/// system, such as its revival time, are flagged as they are reached.
+pub fn run_viz_1d_in(
+    ket: Ket<Sign1D>,
+    system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    params: PhysicalParams,
+    interpolation: CacheInterpolation,
+) {
+    add_viz_1d_systems(
+        &mut viz_app(),
+        ket,
+        Some(Arc::new(system)),
+        None,
+        None,
+        params,
+        interpolation,
+    )
+    .run();
+}
+
+/// Run the application and visualise `initial_state(t=0)` evolving in
+/// `system`, expanded in the energy eigenstates `min_n..=max_n` with the
+/// given `step_size`. The eigenstates are sampled once, so each frame only
+/// updates the phase of each of them, which keeps large expansions cheap to
+/// animate. The potential and special times of the system are drawn as in
+/// `run_viz_1d_in`. The `=` and `-` keys add or remove the highest eigenstate
+/// of the expansion, which resamples the eigenstates without restarting the
+/// evolution.
+pub fn run_viz_evolution(
+    system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    initial_state: &Ket<Sign1D>,
+    step_size: f32,
+    min_n: i32,
+    max_n: i32,
+    params: PhysicalParams,
+    interpolation: CacheInterpolation,
+) {
+    let source = ExpansionSource::new(
+        Arc::new(system),
+        initial_state.clone(),
+        step_size,
+        params.hbar,
+        min_n,
+        max_n,
+    );
+    let spectrum = source.expansion();
+    let ket = Ket::weighted_sum(
+        spectrum
+            .iter()
+            .map(|(c, _, ket)| (*c, ket.clone()))
+            .collect(),
+    );
+    add_viz_1d_systems(
+        &mut viz_app(),
+        ket,
+        Some(source.system()),
+        Some(spectrum),
+        Some(source),
+        params,
+        interpolation,
+    )
+    .run();
+}
+
+/// Add the systems visualising a single 1D wavefunction to `app`, along with
+/// the potential of `system` if given, and updating the cache from the
+/// expansion of the wavefunction in energy eigenstates if given. The expansion
+/// is rebuilt whenever its `source` is changed.
+fn add_viz_1d_systems(
+    app: &mut App,
+    ket: Ket<Sign1D>,
+    system: Option<Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>>,
+    spectrum: Option<Vec<(Complex32, f32, Ket<Sign1D>)>>,
+    source: Option<ExpansionSource>,
+    params: PhysicalParams,
+    interpolation: CacheInterpolation,
+) -> &mut App {
+    if let Some(source) = source {
+        app.insert_resource(source);
+    }
+    app.add_systems(
+        Startup,
+        get_setup(ket, system, spectrum, params, interpolation),
+    )
+    .add_systems(
+        PreUpdate,
+        (
+            (basis_size_system, rebuild_expansion_system)
+                .chain()
+                .run_if(resource_exists::<ExpansionSource>),
+            update_cache_system,
+        )
+            .chain(),
+    )
+    .add_systems(
+        Update,
+        (
+            wf_animation_system,
+            export_csv_system,
+            info_label_system,
+            fit_camera_system,
+            recolor_wavefunction_system,
+            toggle_ghost_system,
+            recenter_system,
+            toggle_log_density_system,
+            toggle_momentum_system,
+            toggle_current_system,
+        ),
+    )
+}
+
+/// Run the application and visualise the energy eigenstates `1..=max_n` of a
+/// 1D system, starting from the ground state, along with the potential of the
+/// system and the probability density of a classical particle with the same
+/// energy. The up and down arrow keys step through the eigenstates.
+pub fn run_viz_eigenstates(
+    system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    max_n: i32,
+    params: PhysicalParams,
+    interpolation: CacheInterpolation,
+) {
+    let ket = system.energy_eigenstate(1);
+    let system: Arc<dyn DiscreteSystem<Sign1D> + Send + Sync> = Arc::new(system);
+    viz_app()
+        .insert_resource(EigenstateSelector::new(system.clone(), max_n))
+        .add_systems(
+            Startup,
+            get_setup(ket, Some(system), None, params, interpolation),
+        )
+        .add_systems(
+            PreUpdate,
+            (
+                eigenstate_cycle_system,
+                classical_density_system,
+                update_cache_system,
+            )
+                .chain(),
+        )
+        .add_systems(
+            Update,
+            (
+                wf_animation_system,
+                export_csv_system,
+                info_label_system,
+                fit_camera_system,
+                recolor_wavefunction_system,
+                toggle_ghost_system,
+                recenter_system,
+                toggle_log_density_system,
+                toggle_momentum_system,
+                toggle_current_system,
+            ),
+        )
+        .run();
+}
+
+/// Run the application and draw the energy eigenstates `min_n..=max_n` of a 1D
+/// system as a ladder, each offset vertically by its energy inside the
+/// potential.
+pub fn run_viz_ladder(
+    system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    min_n: i32,
+    max_n: i32,
+) {
+    viz_app()
+        .add_systems(Startup, get_setup_ladder(system, min_n, max_n))
+        .run();
+}
+
+/// Render the superposition of the energy eigenstates of `system` with the
+/// given `(coefficient, n)` terms at time `t` to the image file at `path`,
+/// whose extension sets its format (e.g. `.png`), then exit. A window is
+/// opened to render into, but needs no interaction. The potential of the
+/// system is drawn along with the state, and the camera is fitted to it.
+pub fn render_snapshot(
+    system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    terms: &[(Complex32, i32)],
+    t: f32,
+    path: impl AsRef<Path>,
+    params: PhysicalParams,
+) {
+    let ket = system.superposition(terms);
+    viz_app()
+        .insert_resource(SnapshotRequest {
+            time: t,
+            path: path.as_ref().to_owned(),
+        })
+        .add_systems(
+            Startup,
+            get_setup(
+                ket,
+                Some(Arc::new(system)),
+                None,
+                params,
+                CacheInterpolation::CatmullRom,
+            ),
+        )
+        .add_systems(PreUpdate, (snapshot_system, update_cache_system).chain())
+        .add_systems(
+            Update,
+            (
+                wf_animation_system,
+                fit_camera_system,
+                info_label_system,
+                recolor_wavefunction_system,
+            ),
+        )
+        .run();
+}
+
+/// Run the application and visualise the probability density
+/// `sum_n p_n |psi_n|^2` of a 1D mixed state. The pure states of the mixture
+/// do not interfere, so unlike a superposition of the same states, its
+/// density shows no interference fringes.
+pub fn run_viz_mixture(rho: DensityMatrix<Sign1D>) {
+    viz_app()
+        .add_systems(Startup, get_setup_mixture(rho))
+        .add_systems(
+            Update,
+            (mixture_animation_system, recolor_wavefunction_system),
+        )
+        .run();
+}
+
+/// Run the application and visualise the probability density of a single 2D
+/// wavefunction as a heatmap, sampled every `step` and drawn with `colormap`.
+pub fn run_viz_2d(ket: Ket<Sign2D>, step: Point2D, colormap: Colormap) {
+    viz_app()
+        .insert_resource(ColorScheme {
+            density: colormap,
+            ..Default::default()
+        })
+        .add_systems(Startup, get_setup_2d(ket, step))
+        .add_systems(PreUpdate, (update_heatmap_system,))
+        .run();
+}
+
+/// Run the application and draw the Wigner quasi-probability distribution of
+/// `ket` at time `t` as a heatmap over phase space, spanning the subdomain of
+/// the ket in position and `p_range` in momentum, sampled every `step.x` in
+/// position and `step.y` in momentum. Negative regions, which have no
+/// classical analogue, are drawn in blue and positive regions in red.
+pub fn run_viz_wigner(
+    ket: &Ket<Sign1D>,
+    p_range: (f32, f32),
+    step: Point2D,
+    t: f32,
+    params: PhysicalParams,
+) {
+    let x_range = (ket.subdomain.lower, ket.subdomain.upper);
+    let x_grid: Vec<f32> = ket.iter_with_step_size(step.x).collect();
+    let p_grid: Vec<f32> = SubDomain1D {
+        lower: p_range.0,
+        upper: p_range.1,
+    }
+    .iter_with_step_size(step.y)
+    .collect();
+    // Half the position step samples psi(x + y) and psi(x - y) at the
+    // spacing of the position grid
+    let wigner = ket.wigner(&x_grid, &p_grid, t, step.x / 2.0, params.hbar);
+    viz_app()
+        .add_systems(Startup, get_setup_wigner(wigner, x_range, p_range))
+        .run();
+}
+
+/// Create an app with the plugins shared by all visualisations
+pub(in crate::frontend) fn viz_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<ColorScheme>()
+        .add_systems(PreUpdate, cycle_color_scheme_system)
         .add_plugins(DefaultPlugins)
         .add_plugins(PolylinePlugin)
         .add_plugins(InfiniteGridPlugin)
@@ -39,9 +361,6 @@ pub fn run_viz_1d(ket: Ket<Sign1D>) {
                 },
                 ..Default::default()
             },
-        })
-        .add_systems(Startup, get_setup(ket))
-        .add_systems(PreUpdate, (update_cache_system,))
-        .add_systems(Update, (wf_animation_system,))
-        .run();
+        });
+    app
 }
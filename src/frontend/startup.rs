@@ -1,10 +1,11 @@
 //! Startup functionality
 
-use std::f32::consts::PI;
+use std::{f32::consts::PI, sync::Arc};
 
 use bevy::{
-    camera::{Camera, Camera3d},
+    camera::{Camera, Camera3d, visibility::Visibility},
     core_pipeline::tonemapping::Tonemapping,
+    image::Image,
     light::DirectionalLight,
     math::Quat,
     post_process::bloom::Bloom,
@@ -13,17 +14,37 @@ use bevy::{
 use bevy_infinite_grid::{InfiniteGridBundle, InfiniteGridSettings};
 use bevy_panorbit_camera::PanOrbitCamera;
 use bevy_polyline::prelude::{Polyline, PolylineMaterial};
+use num_complex::Complex32;
 
-use super::wf_component::WFComponent;
+use super::wf_component::PhysicalParams;
 use crate::{
-    framework::{braket::Ket, wavefunction::signature::Sign1D},
-    frontend::wf_1d_vis::spawn_wavefunction,
+    framework::{
+        braket::{DensityMatrix, Ket},
+        core::domain::Point2D,
+        wavefunction::signature::{Sign1D, Sign2D},
+    },
+    frontend::{
+        wf_1d_vis::{
+            CacheInterpolation, MixtureComponent, WFComponentBuilder, spawn_info_label,
+            spawn_ladder, spawn_mixture, spawn_wavefunction,
+        },
+        wf_2d_vis::{WFHeatmap, spawn_heatmap, spawn_wigner},
+    },
+    quantum_system::DiscreteSystem,
 };
 
-/// Get a bevy startup system that visualises the given ket.
+/// Get a bevy startup system that visualises the given ket with the given
+/// physical constants, interpolating between cached samples with
+/// `interpolation`. The potential of `system` is drawn with the ket, if given.
+/// If the `(c_n, E_n, |n>)` terms of the expansion of the ket in energy
+/// eigenstates are given, they are used to update the cache cheaply.
 #[allow(clippy::type_complexity)]
 pub fn get_setup(
     ket: Ket<Sign1D>,
+    system: Option<Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>>,
+    spectrum: Option<Vec<(Complex32, f32, Ket<Sign1D>)>>,
+    params: PhysicalParams,
+    interpolation: CacheInterpolation,
 ) -> impl FnMut(
     Commands,
     ResMut<Assets<Mesh>>,
@@ -38,10 +59,33 @@ pub fn get_setup(
           mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
           mut polylines: ResMut<Assets<Polyline>>| {
         let ket = ket.take().expect("Startup system ran more than once!");
-        let wf_component = WFComponent::new(ket, 0.05, 0.01, 0.1).unwrap();
+        let mut wf_component = WFComponentBuilder::default()
+            .wf(ket)
+            .interpolation(interpolation)
+            .hbar(params.hbar)
+            .build()
+            .unwrap();
+        if let Some(system) = &system {
+            wf_component = wf_component.with_potential(system.clone());
+        }
+        if let Some(spectrum) = &spectrum {
+            wf_component = wf_component.with_spectrum(spectrum, params.hbar);
+        }
+
+        // ghost of the initial state, hidden until toggled
+        let ghost_entity = spawn_wavefunction(
+            wf_component.clone().frozen_at(0.0),
+            Transform::IDENTITY,
+            &mut commands,
+            &mut meshes,
+            &mut standard_materials,
+            &mut polyline_materials,
+            &mut polylines,
+        );
+        commands.entity(ghost_entity).insert(Visibility::Hidden);
 
         // wavefunction group
-        spawn_wavefunction(
+        let wf_entity = spawn_wavefunction(
             wf_component,
             Transform::IDENTITY,
             &mut commands,
@@ -50,44 +94,217 @@ pub fn get_setup(
             &mut polyline_materials,
             &mut polylines,
         );
+        commands.entity(wf_entity).insert(params);
+        spawn_info_label(&mut commands);
 
-        // grid
-        commands.spawn(InfiniteGridBundle {
-            settings: InfiniteGridSettings {
-                x_axis_color: Color::WHITE,
-                ..Default::default()
-            },
-            ..Default::default()
-        });
-
-        // light
-        commands.spawn((
-            DirectionalLight {
-                color: Color::WHITE,
-                illuminance: 1.0,
-                ..Default::default()
-            },
-            Transform::from_rotation(Quat::from_rotation_x(PI / 4.0)),
-        ));
-
-        // camera
-        commands.spawn((
-            Camera3d::default(),
-            Camera {
-                clear_color: bevy::camera::ClearColorConfig::Custom(Color::srgb(0.05, 0.05, 0.05)),
-                ..Default::default()
-            },
-            Tonemapping::TonyMcMapface,
-            Bloom::NATURAL,
-            Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
-            PanOrbitCamera {
-                orbit_smoothness: 0.08,
-                pan_smoothness: 0.1,
-                zoom_smoothness: 0.2,
-                ..Default::default()
-            },
-        ));
+        spawn_environment(&mut commands);
 
         // action!
     }
 }
+
+/// Get a bevy startup system that visualises each of the given kets, offset
+/// along -z by its depth, with the given physical constants. Each ket is
+/// drawn by a `WFComponent` built from `component`, which sets how it is
+/// sampled and animated. The potential of `system` is drawn with each ket, if
+/// given.
+#[allow(clippy::type_complexity)]
+pub fn get_setup_kets(
+    kets: Vec<(Ket<Sign1D>, f32)>,
+    system: Option<Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>>,
+    params: PhysicalParams,
+    component: WFComponentBuilder,
+) -> impl FnMut(
+    Commands,
+    ResMut<Assets<Mesh>>,
+    ResMut<Assets<StandardMaterial>>,
+    ResMut<Assets<PolylineMaterial>>,
+    ResMut<Assets<Polyline>>,
+) {
+    let mut kets = Some(kets);
+    move |mut commands: Commands,
+          mut meshes: ResMut<Assets<Mesh>>,
+          mut standard_materials: ResMut<Assets<StandardMaterial>>,
+          mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
+          mut polylines: ResMut<Assets<Polyline>>| {
+        let kets = kets.take().expect("Startup system ran more than once!");
+
+        // wavefunction groups
+        for (ket, depth) in kets {
+            let mut wf_component = component.clone().wf(ket).hbar(params.hbar).build().unwrap();
+            if let Some(system) = &system {
+                wf_component = wf_component.with_potential(system.clone());
+            }
+            let wf_entity = spawn_wavefunction(
+                wf_component,
+                Transform::from_xyz(0.0, 0.0, -depth),
+                &mut commands,
+                &mut meshes,
+                &mut standard_materials,
+                &mut polyline_materials,
+                &mut polylines,
+            );
+            commands.entity(wf_entity).insert(params);
+        }
+        spawn_info_label(&mut commands);
+
+        spawn_environment(&mut commands);
+
+        // action!
+    }
+}
+
+/// Get a bevy startup system that visualises the probability density of the
+/// given 2D ket as a heatmap, sampled every `step`.
+#[allow(clippy::type_complexity)]
+pub fn get_setup_2d(
+    ket: Ket<Sign2D>,
+    step: Point2D,
+) -> impl FnMut(Commands, ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>, ResMut<Assets<Image>>)
+{
+    let mut ket = Some(ket);
+    move |mut commands: Commands,
+          mut meshes: ResMut<Assets<Mesh>>,
+          mut standard_materials: ResMut<Assets<StandardMaterial>>,
+          mut images: ResMut<Assets<Image>>| {
+        let ket = ket.take().expect("Startup system ran more than once!");
+        let heatmap = WFHeatmap::new(ket, step, 0.1);
+
+        // wavefunction heatmap
+        spawn_heatmap(
+            heatmap,
+            &mut commands,
+            &mut meshes,
+            &mut standard_materials,
+            &mut images,
+        );
+
+        spawn_environment(&mut commands);
+
+        // action!
+    }
+}
+
+/// Get a bevy startup system that draws the Wigner function `wigner` as a
+/// heatmap over the phase space `x_range` by `p_range`.
+#[allow(clippy::type_complexity)]
+pub fn get_setup_wigner(
+    wigner: Vec<Vec<f32>>,
+    x_range: (f32, f32),
+    p_range: (f32, f32),
+) -> impl FnMut(Commands, ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>, ResMut<Assets<Image>>)
+{
+    move |mut commands: Commands,
+          mut meshes: ResMut<Assets<Mesh>>,
+          mut standard_materials: ResMut<Assets<StandardMaterial>>,
+          mut images: ResMut<Assets<Image>>| {
+        // phase space heatmap
+        spawn_wigner(
+            &wigner,
+            x_range,
+            p_range,
+            &mut commands,
+            &mut meshes,
+            &mut standard_materials,
+            &mut images,
+        );
+
+        spawn_environment(&mut commands);
+
+        // action!
+    }
+}
+
+/// Get a bevy startup system that draws the energy eigenstates `min_n..=max_n`
+/// of a 1D system stacked by energy inside its potential.
+#[allow(clippy::type_complexity)]
+pub fn get_setup_ladder(
+    system: impl DiscreteSystem<Sign1D> + Send + Sync + 'static,
+    min_n: i32,
+    max_n: i32,
+) -> impl FnMut(Commands, ResMut<Assets<PolylineMaterial>>, ResMut<Assets<Polyline>>) {
+    move |mut commands: Commands,
+          mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
+          mut polylines: ResMut<Assets<Polyline>>| {
+        // eigenstate ladder
+        spawn_ladder(
+            &system,
+            min_n,
+            max_n,
+            0.01,
+            &mut commands,
+            &mut polyline_materials,
+            &mut polylines,
+        );
+
+        spawn_environment(&mut commands);
+
+        // action!
+    }
+}
+
+/// Get a bevy startup system that visualises the probability density of the
+/// given mixed state.
+#[allow(clippy::type_complexity)]
+pub fn get_setup_mixture(
+    rho: DensityMatrix<Sign1D>,
+) -> impl FnMut(Commands, ResMut<Assets<PolylineMaterial>>, ResMut<Assets<Polyline>>) {
+    let mut rho = Some(rho);
+    move |mut commands: Commands,
+          mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
+          mut polylines: ResMut<Assets<Polyline>>| {
+        let rho = rho.take().expect("Startup system ran more than once!");
+
+        // mixed state density
+        spawn_mixture(
+            MixtureComponent::new(rho, 0.01, 0.1),
+            &mut commands,
+            &mut polyline_materials,
+            &mut polylines,
+        );
+
+        spawn_environment(&mut commands);
+
+        // action!
+    }
+}
+
+/// Spawn the grid, lighting and camera shared by all visualisations
+fn spawn_environment(commands: &mut Commands) {
+    // grid
+    commands.spawn(InfiniteGridBundle {
+        settings: InfiniteGridSettings {
+            x_axis_color: Color::WHITE,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    // light
+    commands.spawn((
+        DirectionalLight {
+            color: Color::WHITE,
+            illuminance: 1.0,
+            ..Default::default()
+        },
+        Transform::from_rotation(Quat::from_rotation_x(PI / 4.0)),
+    ));
+
+    // camera
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            clear_color: bevy::camera::ClearColorConfig::Custom(Color::srgb(0.05, 0.05, 0.05)),
+            ..Default::default()
+        },
+        Tonemapping::TonyMcMapface,
+        Bloom::NATURAL,
+        Transform::from_translation(Vec3::new(0.0, 1.5, 5.0)),
+        PanOrbitCamera {
+            orbit_smoothness: 0.08,
+            pan_smoothness: 0.1,
+            zoom_smoothness: 0.2,
+            ..Default::default()
+        },
+    ));
+}
@@ -4,20 +4,56 @@ mod animation_system;
 mod bundle;
 mod cache_1d;
 mod cache_1d_system;
+mod current_system;
+mod eigenstate_system;
+mod expansion_system;
+mod export_system;
 mod filled_wave;
+mod framing_system;
+mod ghost_system;
+mod label_system;
+mod ladder;
+mod log_density_system;
+mod mixture;
+mod momentum_system;
+mod recenter_system;
+mod recolor_system;
+mod snapshot_system;
 
 use std::f32::consts::PI;
 
 pub(in crate::frontend) use animation_system::wf_animation_system;
-pub(in crate::frontend) use bundle::{WFFilledWaveBundle, WFPolylineBundle};
+pub(in crate::frontend) use bundle::{WFComponentBuilder, WFFilledWaveBundle, WFPolylineBundle};
+pub use cache_1d::CacheInterpolation;
 pub(in crate::frontend) use cache_1d::{Cache1D, Cache1DError};
 pub(in crate::frontend) use cache_1d_system::update_cache_system;
+pub(in crate::frontend) use current_system::toggle_current_system;
+pub(in crate::frontend) use eigenstate_system::{
+    EigenstateSelector, classical_density_system, eigenstate_cycle_system,
+};
+pub(in crate::frontend) use expansion_system::{
+    ExpansionSource, basis_size_system, rebuild_expansion_system,
+};
+pub(in crate::frontend) use export_system::export_csv_system;
 pub(in crate::frontend) use filled_wave::FilledWave;
+pub(in crate::frontend) use framing_system::fit_camera_system;
+pub(in crate::frontend) use ghost_system::toggle_ghost_system;
+pub(in crate::frontend) use label_system::{info_label_system, spawn_info_label};
+pub(in crate::frontend) use ladder::spawn_ladder;
+pub(in crate::frontend) use log_density_system::toggle_log_density_system;
+pub(in crate::frontend) use mixture::{MixtureComponent, mixture_animation_system, spawn_mixture};
+pub(in crate::frontend) use momentum_system::toggle_momentum_system;
+pub(in crate::frontend) use recenter_system::recenter_system;
+pub(in crate::frontend) use recolor_system::recolor_wavefunction_system;
+pub(in crate::frontend) use snapshot_system::{SnapshotRequest, snapshot_system};
 
 use bevy::{
     asset::Assets,
     camera::visibility::Visibility,
-    ecs::system::{Commands, ResMut},
+    ecs::{
+        entity::Entity,
+        system::{Commands, ResMut},
+    },
     math::Quat,
     mesh::{Mesh, Mesh3d},
     pbr::StandardMaterial,
@@ -27,11 +63,19 @@ use bevy_polyline::prelude::{
     Polyline, PolylineBundle, PolylineHandle, PolylineMaterial, PolylineMaterialHandle,
 };
 
-use super::wf_component::{WFComponent, WFType};
+use super::{
+    color_scheme::LinePalette,
+    wf_component::{WFComponent, WFType},
+};
 
-/// Spawn a 1D wavefunction visualiser.
+/// Spawn a 1D wavefunction visualiser, returning the entity holding the
+/// `WFComponent`.
 /// Spawns two polylines with fill for the real and imaginary parts, a polyline for the full wavefunction,
-/// and a polyline for the probability density.
+/// a polyline for the probability density, and polylines for the potential and the classical
+/// probability density, which are empty unless the `WFComponent` has a potential, and hidden
+/// polylines for the logarithm of the probability density, the momentum-space probability
+/// density and the probability current. All of these are children of the one entity, so they share its
+/// cache and stay in sync.
 pub fn spawn_wavefunction(
     wf_component: WFComponent,
     transform: Transform,
@@ -40,85 +84,75 @@ pub fn spawn_wavefunction(
     standard_materials: &mut ResMut<Assets<StandardMaterial>>,
     polyline_materials: &mut ResMut<Assets<PolylineMaterial>>,
     polylines: &mut ResMut<Assets<Polyline>>,
-) {
-    let fill_re = FilledWave::from_wf_component(&wf_component, 4.0, meshes);
-    let fill_im = FilledWave::from_wf_component(&wf_component, 4.0, meshes);
-    let fill_p = FilledWave::from_wf_component(&wf_component, 2.0, meshes);
+) -> Entity {
+    // Drawn in the default palette until `color_scheme_system` applies the
+    // current `ColorScheme`
+    let palette = LinePalette::default();
+    let density_offset = Transform::from_xyz(0.0, 0.0, -2.0);
+    let log_density_offset = Transform::from_xyz(0.0, 0.0, -4.0);
+    let momentum_offset = Transform::from_xyz(0.0, 0.0, -6.0);
+    let current_offset = Transform::from_xyz(0.0, 0.0, -8.0);
+    let mut line = |wf_type: WFType, transform: Transform| WFPolylineBundle {
+        polyline: PolylineBundle {
+            polyline: PolylineHandle(polylines.add(Polyline::default())),
+            material: PolylineMaterialHandle(polyline_materials.add(wf_type.polyline_mat(palette))),
+            transform,
+            ..Default::default()
+        },
+        wf_type,
+    };
+    let mut log_density = line(WFType::LogDensity, log_density_offset);
+    log_density.polyline.visibility = Visibility::Hidden;
+    let mut momentum_density = line(WFType::MomentumDensity, momentum_offset);
+    momentum_density.polyline.visibility = Visibility::Hidden;
+    let mut current = line(WFType::Current, current_offset);
+    current.polyline.visibility = Visibility::Hidden;
+    let lines = [
+        line(WFType::Full, Transform::default()),
+        line(WFType::Real, Transform::default()),
+        line(WFType::Imag, Transform::default()),
+        line(WFType::Density, density_offset),
+        line(WFType::Potential, Transform::default()),
+        line(WFType::Classical, density_offset),
+        log_density,
+        momentum_density,
+        current,
+    ];
+    let mut fill = |wf_type: WFType, scale: f32, transform: Transform| {
+        let fill = FilledWave::from_wf_component(&wf_component, scale, meshes);
+        WFFilledWaveBundle {
+            mesh: Mesh3d(fill.mesh_handle().clone()),
+            fill,
+            material: bevy::pbr::MeshMaterial3d(
+                standard_materials.add(
+                    wf_type
+                        .filled_mat(palette)
+                        .expect("Filled wavefunction types have a fill material"),
+                ),
+            ),
+            wf_type,
+            transform,
+            ..Default::default()
+        }
+    };
+    let fills = [
+        fill(WFType::Real, 4.0, Transform::default()),
+        fill(
+            WFType::Imag,
+            4.0,
+            Transform::from_rotation(Quat::from_rotation_x(PI / 2.0)),
+        ),
+        fill(WFType::Density, 2.0, density_offset),
+    ];
     commands
         .spawn((wf_component, transform, Visibility::default()))
         .with_children(|parent| {
-            parent.spawn(WFPolylineBundle {
-                polyline: PolylineBundle {
-                    polyline: PolylineHandle(polylines.add(Polyline::default())),
-                    material: PolylineMaterialHandle(
-                        polyline_materials.add(WFType::Full.polyline_mat()),
-                    ),
-                    ..Default::default()
-                },
-                wf_type: WFType::Full,
-            });
-
-            parent.spawn(WFPolylineBundle {
-                polyline: PolylineBundle {
-                    polyline: PolylineHandle(polylines.add(Polyline::default())),
-                    material: PolylineMaterialHandle(
-                        polyline_materials.add(WFType::Real.polyline_mat()),
-                    ),
-                    ..Default::default()
-                },
-                wf_type: WFType::Real,
-            });
-            parent.spawn(WFFilledWaveBundle {
-                mesh: Mesh3d(fill_re.mesh_handle().clone()),
-                fill: fill_re,
-                material: bevy::pbr::MeshMaterial3d(
-                    standard_materials.add(WFType::Real.filled_mat().unwrap()),
-                ),
-                wf_type: WFType::Real,
-                ..Default::default()
-            });
-
-            parent.spawn(WFPolylineBundle {
-                polyline: PolylineBundle {
-                    polyline: PolylineHandle(polylines.add(Polyline::default())),
-                    material: PolylineMaterialHandle(
-                        polyline_materials.add(WFType::Imag.polyline_mat()),
-                    ),
-                    ..Default::default()
-                },
-                wf_type: WFType::Imag,
-            });
-            parent.spawn(WFFilledWaveBundle {
-                mesh: Mesh3d(fill_im.mesh_handle().clone()),
-                fill: fill_im,
-                material: bevy::pbr::MeshMaterial3d(
-                    standard_materials.add(WFType::Imag.filled_mat().unwrap()),
-                ),
-                wf_type: WFType::Imag,
-                transform: Transform::from_rotation(Quat::from_rotation_x(PI / 2.0)),
-                ..Default::default()
-            });
-
-            parent.spawn(WFPolylineBundle {
-                polyline: PolylineBundle {
-                    polyline: PolylineHandle(polylines.add(Polyline::default())),
-                    material: PolylineMaterialHandle(
-                        polyline_materials.add(WFType::Density.polyline_mat()),
-                    ),
-                    transform: Transform::from_xyz(0.0, 0.0, -2.0),
-                    ..Default::default()
-                },
-                wf_type: WFType::Density,
-            });
-            parent.spawn(WFFilledWaveBundle {
-                mesh: Mesh3d(fill_p.mesh_handle().clone()),
-                fill: fill_p,
-                material: bevy::pbr::MeshMaterial3d(
-                    standard_materials.add(WFType::Density.filled_mat().unwrap()),
-                ),
-                wf_type: WFType::Density,
-                transform: Transform::from_xyz(0.0, 0.0, -2.0),
-                ..Default::default()
-            });
-        });
+            for line in lines {
+                parent.spawn(line);
+            }
+            for fill in fills {
+                parent.spawn(fill);
+            }
+        })
+        .id()
 }
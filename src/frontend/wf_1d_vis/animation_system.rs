@@ -6,7 +6,7 @@ use thiserror::Error;
 
 use crate::frontend::wf_1d_vis::filled_wave::FilledWave;
 
-use super::super::wf_component::{WFComponent, WFType};
+use super::super::wf_component::{PhysicalParams, WFComponent, WFType};
 
 #[derive(Debug, Error)]
 #[allow(clippy::missing_docs_in_private_items)]
@@ -21,6 +21,16 @@ pub enum FilledWaveMeshError {
     InvalidVertices,
     #[error("FilledWave cannot be applied to WFType::Full wavefunctions")]
     AppliedToFullWF,
+    #[error("FilledWave cannot be applied to WFType::Potential")]
+    AppliedToPotential,
+    #[error("FilledWave cannot be applied to WFType::Classical")]
+    AppliedToClassical,
+    #[error("FilledWave cannot be applied to WFType::LogDensity")]
+    AppliedToLogDensity,
+    #[error("FilledWave cannot be applied to WFType::MomentumDensity")]
+    AppliedToMomentumDensity,
+    #[error("FilledWave cannot be applied to WFType::Current")]
+    AppliedToCurrent,
 }
 
 #[derive(Error, Debug)]
@@ -36,24 +46,39 @@ pub fn wf_animation_system(
     mut polylines: ResMut<Assets<Polyline>>,
     poly_query: Query<(&PolylineHandle, &WFType)>,
     filled_query: Query<(&FilledWave, &WFType)>,
-    wf_component_query: Query<(&WFComponent, &Children)>,
+    wf_component_query: Query<(&WFComponent, &Children, Option<&PhysicalParams>)>,
 ) -> Result<(), BevyError> {
-    for (wf, children) in wf_component_query.iter() {
+    for (wf, children, params) in wf_component_query.iter() {
+        let mass = params.copied().unwrap_or_default().mass;
         for child in children {
             if let Ok((PolylineHandle(handle), wf_type)) = poly_query.get(*child) {
                 let polyline = polylines
                     .get_mut(handle)
                     .ok_or(WFPolylineError::MissingPolyline)?;
+                // The momentum-space density is drawn over wavenumbers rather
+                // than the render points
+                if matches!(wf_type, WFType::MomentumDensity) {
+                    polyline.vertices = wf
+                        .momentum_points()
+                        .map(|(k, height)| vec3(k, height, 0.0))
+                        .collect();
+                    continue;
+                }
                 polyline.vertices = wf
                     .iter_render_points()
-                    .map(|x| {
+                    .filter_map(|x| {
                         let value = wf.cache_at(x);
-                        match wf_type {
+                        Some(match wf_type {
                             WFType::Full => vec3(x, value.re, value.im),
                             WFType::Real => vec3(x, value.re, 0.0),
                             WFType::Imag => vec3(x, 0.0, value.im),
-                            WFType::Density => vec3(x, value.norm_sqr().abs(), 0.0),
-                        }
+                            WFType::Density => vec3(x, wf.density_at(x), 0.0),
+                            WFType::LogDensity => vec3(x, wf.log_density_at(x), 0.0),
+                            WFType::MomentumDensity => return None,
+                            WFType::Current => vec3(x, wf.current_at(x, mass), 0.0),
+                            WFType::Potential => vec3(x, wf.potential_height(x)?, 0.0),
+                            WFType::Classical => vec3(x, wf.classical_height(x)?, 0.0),
+                        })
                     })
                     .collect();
             }
@@ -89,7 +114,24 @@ pub fn wf_animation_system(
                                 }
                                 WFType::Real => wf.cache_at(x).re,
                                 WFType::Imag => wf.cache_at(x).im,
-                                WFType::Density => wf.cache_at(x).norm_sqr().abs(),
+                                WFType::Density => wf.density_at(x),
+                                WFType::Potential => {
+                                    return Err(FilledWaveMeshError::AppliedToPotential.into());
+                                }
+                                WFType::Classical => {
+                                    return Err(FilledWaveMeshError::AppliedToClassical.into());
+                                }
+                                WFType::LogDensity => {
+                                    return Err(FilledWaveMeshError::AppliedToLogDensity.into());
+                                }
+                                WFType::MomentumDensity => {
+                                    return Err(
+                                        FilledWaveMeshError::AppliedToMomentumDensity.into()
+                                    );
+                                }
+                                WFType::Current => {
+                                    return Err(FilledWaveMeshError::AppliedToCurrent.into());
+                                }
                             };
                             val_p[1] = y;
                             *domain_c = [y * fill.intensity(); 4];
@@ -1,11 +1,16 @@
 //! Bevy `Bundles` associated with visualising 1D wavefunctions.
 
+use std::sync::Arc;
+
 use bevy::prelude::*;
 use bevy_polyline::prelude::PolylineBundle;
 
-use crate::frontend::wf_1d_vis::filled_wave::FilledWave;
+use crate::{
+    framework::{braket::Ket, wavefunction::signature::Sign1D},
+    frontend::wf_1d_vis::{CacheInterpolation, filled_wave::FilledWave},
+};
 
-use super::super::wf_component::WFType;
+use super::super::wf_component::{TimeFn, WFComponent, WFComponentError, WFType};
 
 /// A polyline that visualises a wavefunction
 #[derive(Bundle, Default)]
@@ -25,10 +30,109 @@ pub struct WFFilledWaveBundle {
     pub mesh: Mesh3d,
     /// Material of the `FilledWave`
     pub material: MeshMaterial3d<StandardMaterial>,
-    /// The type of wavefunction. Cannot be `WFType::Full`
+    /// The type of wavefunction. Cannot be `WFType::Full` or `WFType::Potential`
     pub wf_type: WFType,
     /// The transform of the bundle
     pub transform: Transform,
     /// The visibility of the bundle
     pub visibility: Visibility,
 }
+
+/// A builder for a `WFComponent`, starting from the step sizes and time scale
+/// used by the `run_viz_*` functions. The builder may be cloned as a template
+/// for several wavefunctions drawn alike.
+#[derive(Clone)]
+pub(in crate::frontend) struct WFComponentBuilder {
+    /// The wavefunction to visualise
+    ket: Ket<Sign1D>,
+    /// The step size at which the wavefunction is sampled for the cache
+    cache_step_size: f32,
+    /// The step size at which the wavefunction is rendered each frame
+    render_step_size: f32,
+    /// The interpolation used between cached samples
+    interpolation: CacheInterpolation,
+    /// The time scale at which to render the wavefunction
+    time_scale: f32,
+    /// The map from wall-clock seconds to the time at which to render the
+    /// wavefunction, if any, in place of the time scale
+    time_fn: Option<TimeFn>,
+    /// The value of hbar used to find the rotating frame's angular frequency
+    hbar: f32,
+}
+
+impl Default for WFComponentBuilder {
+    fn default() -> Self {
+        Self {
+            ket: Ket::default(),
+            cache_step_size: 0.05,
+            render_step_size: 0.01,
+            interpolation: CacheInterpolation::default(),
+            time_scale: 0.1,
+            time_fn: None,
+            hbar: 1.0,
+        }
+    }
+}
+
+impl WFComponentBuilder {
+    /// Visualise the given wavefunction
+    #[must_use]
+    pub fn wf(mut self, ket: Ket<Sign1D>) -> Self {
+        self.ket = ket;
+        self
+    }
+
+    /// Render the wavefunction at the given time scale. Lower values are
+    /// slower.
+    #[must_use]
+    pub fn time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Render the wavefunction at the time `time_fn(s)` after it has evolved
+    /// for `s` seconds of wall-clock time, in place of the time scale
+    #[must_use]
+    pub fn time_fn(mut self, time_fn: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        self.time_fn = Some(Arc::new(time_fn));
+        self
+    }
+
+    /// Render the wavefunction every `render_step_size`, interpolating
+    /// between cached samples
+    #[must_use]
+    pub fn render_step_size(mut self, render_step_size: f32) -> Self {
+        self.render_step_size = render_step_size;
+        self
+    }
+
+    /// Use the given interpolation between cached samples
+    #[must_use]
+    pub fn interpolation(mut self, interpolation: CacheInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Use the given value of hbar
+    #[must_use]
+    pub fn hbar(mut self, hbar: f32) -> Self {
+        self.hbar = hbar;
+        self
+    }
+
+    /// Build the `WFComponent`, sampling the wavefunction for its cache
+    pub fn build(self) -> Result<WFComponent, WFComponentError> {
+        let component = WFComponent::new(
+            self.ket,
+            self.cache_step_size,
+            self.render_step_size,
+            self.interpolation,
+            self.time_scale,
+        )?
+        .with_hbar(self.hbar);
+        Ok(match self.time_fn {
+            Some(time_fn) => component.with_time_fn(time_fn),
+            None => component,
+        })
+    }
+}
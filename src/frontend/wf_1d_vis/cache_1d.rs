@@ -1,5 +1,5 @@
-//! Caching for 1D wavefunction domains with Catmull-Rom interpolation
-//! between sampled points.
+//! Caching for 1D wavefunction domains with linear or Catmull-Rom
+//! interpolation between sampled points.
 
 use num_complex::Complex32;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
@@ -11,24 +11,61 @@ use crate::framework::{
     wavefunction::{Wavefunction, signature::Sign1D},
 };
 
+/// Samples of the terms of a superposition of energy eigenstates, from which
+/// the superposition can be evaluated at any time by only updating the phase
+/// of each term.
+#[derive(Clone)]
+struct SpectralSamples {
+    /// The angular frequency `E_n / hbar` of each term
+    frequencies: Vec<f32>,
+    /// The samples `c_n psi_n(x, 0)` of each term at each sample point
+    terms: Vec<Vec<Complex32>>,
+}
+
 /// A cache holding values of a wavefunction at a point in time.
 /// This is updated each frame in `PreUpdate`, and is kept to prevent
 /// repeat calculations and allow interpolation between sampled points via
-/// a Catmull-Rom spline.
+/// a spline.
 ///
 /// Note that the probability density can be inferred from the wavefunction,
 /// so no cache is kept of the evaluation of `WFKet<WF1D>::p(&self, x, t)`.
 #[derive(Clone)]
 pub struct Cache1D {
+    /// The points at which the wavefunction is sampled, in the same order as the spline keys
+    sample_points: Vec<f32>,
     /// A cache of the real part of the wavefunction
     spline_re: Spline<f32, f32>,
     /// A cache of the imaginary part of the wavefunction
     spline_im: Spline<f32, f32>,
+    /// Samples of the eigenstates making up the wavefunction, if known. When
+    /// present, these are used to update the cache in place of the wavefunction.
+    spectral: Option<SpectralSamples>,
 }
 
 impl Default for Cache1D {
     fn default() -> Self {
-        Self::new(0.0, 1.0, 0.1).unwrap()
+        Self::new(0.0, 1.0, 0.1, CacheInterpolation::default()).unwrap()
+    }
+}
+
+/// The interpolation used to find values between sampled points of a `Cache1D`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheInterpolation {
+    /// Linearly interpolate between sampled points. This is the cheapest, but
+    /// the rendered wavefunction will have kinks at each sampled point.
+    Linear,
+    /// Interpolate between sampled points via a Catmull-Rom spline.
+    #[default]
+    CatmullRom,
+}
+
+impl CacheInterpolation {
+    /// Get the matching spline interpolation
+    fn spline_interpolation(self) -> Interpolation<f32, f32> {
+        match self {
+            CacheInterpolation::Linear => Interpolation::Linear,
+            CacheInterpolation::CatmullRom => Interpolation::CatmullRom,
+        }
     }
 }
 
@@ -43,8 +80,13 @@ pub enum Cache1DError {
 
 impl Cache1D {
     /// Create a new cache ranging from min to max with the given `step_size`
-    /// for sampling values.
-    pub fn new(min: f32, max: f32, step_size: f32) -> Result<Cache1D, Cache1DError> {
+    /// for sampling values, and the given `interpolation` between samples.
+    pub fn new(
+        min: f32,
+        max: f32,
+        step_size: f32,
+        interpolation: CacheInterpolation,
+    ) -> Result<Cache1D, Cache1DError> {
         if min > max {
             return Err(Cache1DError::InvalidMinMax);
         }
@@ -52,41 +94,88 @@ impl Cache1D {
             return Err(Cache1DError::NegativeStepSize);
         }
 
-        let mut keys: Vec<Key<f32, f32>> = vec![];
+        let interpolation = interpolation.spline_interpolation();
+        let mut sample_points: Vec<f32> = vec![];
 
+        // Pad with two points either side of the range, as Catmull-Rom
+        // interpolation needs neighbouring keys.
         let mut x = min;
-        keys.push(Key::new(
-            x - 2.0 * step_size,
-            0.0,
-            Interpolation::CatmullRom,
-        ));
-        keys.push(Key::new(x - step_size, 0.0, Interpolation::CatmullRom));
+        sample_points.push(x - 2.0 * step_size);
+        sample_points.push(x - step_size);
         while x <= max {
-            keys.push(Key::new(x, 0.0, Interpolation::CatmullRom));
+            sample_points.push(x);
             x += step_size;
         }
-        keys.push(Key::new(x, 0.0, Interpolation::CatmullRom));
-        keys.push(Key::new(x + step_size, 0.0, Interpolation::CatmullRom));
+        sample_points.push(x);
+        sample_points.push(x + step_size);
+
+        let keys: Vec<Key<f32, f32>> = sample_points
+            .iter()
+            .map(|&x| Key::new(x, 0.0, interpolation))
+            .collect();
 
         Ok(Cache1D {
+            sample_points,
             spline_re: Spline::from_vec(keys.clone()),
-            spline_im: Spline::from_vec(keys.clone()),
+            spline_im: Spline::from_vec(keys),
+            spectral: None,
         })
     }
 
     /// Create a cache matching the subdomain of a 1D ket.
-    pub fn from_ket(ket: &Ket<Sign1D>, step_size: f32) -> Result<Cache1D, Cache1DError> {
-        Self::new(ket.subdomain.lower, ket.subdomain.upper, step_size)
+    pub fn from_ket(
+        ket: &Ket<Sign1D>,
+        step_size: f32,
+        interpolation: CacheInterpolation,
+    ) -> Result<Cache1D, Cache1DError> {
+        Self::new(
+            ket.subdomain.lower,
+            ket.subdomain.upper,
+            step_size,
+            interpolation,
+        )
+    }
+
+    /// Sample the `(c_n, E_n, |n>)` terms of a superposition of energy
+    /// eigenstates at time `0`, so that later updates only recompute the phase
+    /// `e^{-i E_n t / hbar}` of each term rather than evaluating the
+    /// wavefunction. The superposition should match the wavefunction the cache
+    /// is updated with.
+    pub fn set_spectrum(&mut self, terms: &[(Complex32, f32, Ket<Sign1D>)], hbar: f32) {
+        self.spectral = Some(SpectralSamples {
+            frequencies: terms.iter().map(|(_, energy, _)| energy / hbar).collect(),
+            terms: terms
+                .iter()
+                .map(|(c, _, ket)| {
+                    self.sample_points
+                        .iter()
+                        .map(|&x| c * ket.f(x, 0.0))
+                        .collect()
+                })
+                .collect(),
+        });
     }
 
     /// Update the cache with values at time `t`.
     pub fn update(&mut self, wf: &Ket<Sign1D>, t: f32) {
-        let xs: Vec<_> = self.spline_re.into_iter().map(|k| k.t).collect();
+        let xs = &self.sample_points;
 
-        #[cfg(feature = "par_braket")]
-        let values: Vec<_> = xs.par_iter().map(|x| wf.f(*x, t)).collect();
-        #[cfg(not(feature = "par_braket"))]
-        let values: Vec<_> = xs.iter().map(|x| wf.f(*x, t)).collect();
+        let values: Vec<_> = if let Some(spectral) = &self.spectral {
+            let mut values = vec![Complex32::ZERO; xs.len()];
+            for (frequency, term) in spectral.frequencies.iter().zip(&spectral.terms) {
+                let phase = Complex32::cis(-frequency * t);
+                for (value, sample) in values.iter_mut().zip(term) {
+                    *value += phase * sample;
+                }
+            }
+            values
+        } else {
+            #[cfg(feature = "par_braket")]
+            let values = xs.par_iter().map(|x| wf.f(*x, t)).collect();
+            #[cfg(not(feature = "par_braket"))]
+            let values = xs.iter().map(|x| wf.f(*x, t)).collect();
+            values
+        };
 
         for (i, value) in values.iter().enumerate() {
             if let (Some(re), Some(im)) = (self.spline_re.get_mut(i), self.spline_im.get_mut(i)) {
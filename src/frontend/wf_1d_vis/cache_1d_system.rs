@@ -10,6 +10,7 @@ use crate::frontend::wf_component::WFComponent;
 
 /// Update the caches of wavefunctions currently being visualised.
 /// This is done to prevent repeat calculations for entities sharing the same wavefunction.
+/// `O` toggles drawing the wavefunctions in the rotating frame of their reference energy.
 pub fn update_cache_system(
     time: Res<Time>,
     mut query: Query<&mut WFComponent>,
@@ -19,9 +20,13 @@ pub fn update_cache_system(
         if key_input.just_pressed(KeyCode::Space) {
             wf_component.paused = !wf_component.paused;
         }
-        if !wf_component.paused {
-            wf_component.time += wf_component.time_scale * time.delta_secs();
+        if key_input.just_pressed(KeyCode::KeyD) {
+            wf_component.cycle_density_scale();
         }
+        if key_input.just_pressed(KeyCode::KeyO) {
+            wf_component.rotating_frame = !wf_component.rotating_frame;
+        }
+        wf_component.advance(time.delta_secs());
 
         wf_component.update_cache();
     }
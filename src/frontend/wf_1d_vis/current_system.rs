@@ -0,0 +1,27 @@
+//! Logic for showing the probability current of wavefunctions.
+
+use bevy::{
+    camera::visibility::Visibility,
+    ecs::system::{Query, Res},
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use crate::frontend::wf_component::WFType;
+
+/// Show or hide the probability current when `J` is pressed. It is drawn
+/// behind the momentum-space density, and vanishes for stationary states,
+/// while a moving packet carries a current along with its density.
+pub fn toggle_current_system(
+    mut type_query: Query<(&WFType, &mut Visibility)>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    if !key_input.just_pressed(KeyCode::KeyJ) {
+        return;
+    }
+
+    for (wf_type, mut visibility) in &mut type_query {
+        if matches!(wf_type, WFType::Current) {
+            visibility.toggle_inherited_hidden();
+        }
+    }
+}
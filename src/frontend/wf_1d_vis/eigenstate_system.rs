@@ -0,0 +1,98 @@
+//! Logic for stepping through the energy eigenstates of a system at runtime.
+
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        error::BevyError,
+        resource::Resource,
+        system::{Query, Res, ResMut},
+    },
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use crate::{
+    framework::wavefunction::signature::Sign1D, frontend::wf_component::WFComponent,
+    quantum_system::DiscreteSystem,
+};
+
+/// A resource holding the system whose energy eigenstates are being
+/// visualised, along with the index of the currently displayed eigenstate.
+#[derive(Resource)]
+pub(in crate::frontend) struct EigenstateSelector {
+    /// The system to take eigenstates from
+    system: Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>,
+    /// The index of the currently displayed eigenstate
+    n: i32,
+    /// The largest eigenstate index that can be displayed
+    max_n: i32,
+}
+
+impl EigenstateSelector {
+    /// Create a selector for the eigenstates `1..=max_n` of `system`, starting
+    /// from the ground state.
+    pub fn new(system: Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>, max_n: i32) -> Self {
+        Self {
+            system,
+            n: 1,
+            max_n: max_n.max(1),
+        }
+    }
+
+    /// The system eigenstates are taken from
+    pub fn system(&self) -> &(dyn DiscreteSystem<Sign1D> + Send + Sync) {
+        self.system.as_ref()
+    }
+
+    /// The index of the currently displayed eigenstate
+    pub fn n(&self) -> i32 {
+        self.n
+    }
+}
+
+/// Step up or down through the energy eigenstates with the arrow keys,
+/// replacing the displayed wavefunctions with the newly selected eigenstate.
+pub fn eigenstate_cycle_system(
+    mut selector: ResMut<EigenstateSelector>,
+    mut query: Query<&mut WFComponent>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) -> Result<(), BevyError> {
+    let n = if key_input.just_pressed(KeyCode::ArrowUp) {
+        (selector.n + 1).min(selector.max_n)
+    } else if key_input.just_pressed(KeyCode::ArrowDown) {
+        (selector.n - 1).max(1)
+    } else {
+        return Ok(());
+    };
+    if n == selector.n {
+        return Ok(());
+    }
+
+    selector.n = n;
+    for mut wf_component in &mut query {
+        wf_component.set_ket(selector.system.energy_eigenstate(n))?;
+    }
+
+    Ok(())
+}
+
+/// Draw the probability density of a classical particle with the energy of
+/// the displayed eigenstate whenever the selected eigenstate changes, for
+/// comparison with the quantum probability density. The same energy is taken
+/// as the reference energy of the rotating frame, in which the displayed
+/// eigenstate is static.
+pub fn classical_density_system(
+    selector: Res<EigenstateSelector>,
+    mut query: Query<&mut WFComponent>,
+) {
+    if !selector.is_changed() {
+        return;
+    }
+
+    let energy = selector.system().energy(selector.n());
+    for mut wf_component in &mut query {
+        wf_component.classical_energy = Some(energy);
+        wf_component.reference_energy = Some(energy);
+    }
+}
@@ -0,0 +1,124 @@
+//! Logic for rebuilding the expansion of an evolving wavefunction when the
+//! parameters it depends on change at runtime.
+
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        error::BevyError,
+        resource::Resource,
+        system::{Query, Res, ResMut},
+    },
+    input::{ButtonInput, keyboard::KeyCode},
+};
+use num_complex::Complex32;
+
+use crate::{
+    framework::{braket::Ket, wavefunction::signature::Sign1D},
+    frontend::wf_component::WFComponent,
+    quantum_system::DiscreteSystem,
+};
+
+/// A resource holding everything the expansion of an evolving wavefunction in
+/// energy eigenstates is computed from. Only these parameters affect the
+/// physics, so only changes to this resource cause the sampled eigenstates to
+/// be recomputed, while view settings held by the `WFComponent`, such as its
+/// time scale, are cheap to change every frame.
+#[derive(Resource)]
+pub(in crate::frontend) struct ExpansionSource {
+    /// The system whose energy eigenstates the initial state is expanded in
+    system: Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>,
+    /// The state at time zero
+    initial_state: Ket<Sign1D>,
+    /// The step size used to compute the coefficients of the expansion
+    step_size: f32,
+    /// The reduced Planck's constant used to evolve the expansion
+    hbar: f32,
+    /// The lowest eigenstate index in the expansion
+    min_n: i32,
+    /// The highest eigenstate index in the expansion
+    max_n: i32,
+}
+
+impl ExpansionSource {
+    /// Create a source expanding `initial_state` in the energy eigenstates
+    /// `min_n..=max_n` of `system`.
+    pub fn new(
+        system: Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>,
+        initial_state: Ket<Sign1D>,
+        step_size: f32,
+        hbar: f32,
+        min_n: i32,
+        max_n: i32,
+    ) -> Self {
+        Self {
+            system,
+            initial_state,
+            step_size,
+            hbar,
+            min_n,
+            max_n: max_n.max(min_n),
+        }
+    }
+
+    /// The system the initial state is expanded in
+    pub fn system(&self) -> Arc<dyn DiscreteSystem<Sign1D> + Send + Sync> {
+        self.system.clone()
+    }
+
+    /// The range of eigenstate indices in the expansion
+    pub fn range(&self) -> (i32, i32) {
+        (self.min_n, self.max_n)
+    }
+
+    /// The `(c_n, E_n, |n>)` terms of the expansion of the initial state
+    pub fn expansion(&self) -> Vec<(Complex32, f32, Ket<Sign1D>)> {
+        self.system.expansion(
+            &self.initial_state,
+            0.0,
+            self.step_size,
+            self.min_n,
+            self.max_n,
+        )
+    }
+}
+
+/// Grow or shrink the expansion by one eigenstate with `=` and `-`. The
+/// resource is only mutably borrowed when the basis size actually changes, so
+/// pressing the keys at the ends of the range does not trigger a rebuild.
+pub fn basis_size_system(
+    mut source: ResMut<ExpansionSource>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    let max_n = if key_input.just_pressed(KeyCode::Equal) {
+        source.max_n + 1
+    } else if key_input.just_pressed(KeyCode::Minus) {
+        (source.max_n - 1).max(source.min_n)
+    } else {
+        return;
+    };
+    if max_n != source.max_n {
+        source.max_n = max_n;
+    }
+}
+
+/// Recompute the expansion and rebuild the cached eigenstates of every
+/// wavefunction whenever the `ExpansionSource` changes, keeping the current
+/// time so the evolution carries on from the same frame. The first frame is
+/// skipped, since the wavefunctions are spawned from the same expansion.
+pub fn rebuild_expansion_system(
+    source: Res<ExpansionSource>,
+    mut query: Query<&mut WFComponent>,
+) -> Result<(), BevyError> {
+    if !source.is_changed() || source.is_added() {
+        return Ok(());
+    }
+
+    let terms = source.expansion();
+    for mut wf_component in &mut query {
+        wf_component.set_spectrum(&terms, source.hbar)?;
+    }
+
+    Ok(())
+}
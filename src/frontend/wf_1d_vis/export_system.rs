@@ -0,0 +1,49 @@
+//! Logic for exporting the currently rendered 1D wavefunctions to CSV files.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use bevy::{
+    ecs::{
+        entity::Entity,
+        error::BevyError,
+        system::{Query, Res},
+    },
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use crate::frontend::wf_component::WFComponent;
+
+/// Write the rendered samples of each wavefunction to a CSV file when `E` is pressed.
+/// Each file is named after the entity holding the `WFComponent`, and holds the columns
+/// `t, x, re, im, density`, sampled on the same grid as the rendered polylines.
+pub fn export_csv_system(
+    query: Query<(Entity, &WFComponent)>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) -> Result<(), BevyError> {
+    if !key_input.just_pressed(KeyCode::KeyE) {
+        return Ok(());
+    }
+
+    for (entity, wf) in &query {
+        let mut file = BufWriter::new(File::create(format!("wavefunction_{entity}.csv"))?);
+        writeln!(file, "t,x,re,im,density")?;
+        for x in wf.iter_render_points() {
+            let value = wf.cache_at(x);
+            writeln!(
+                file,
+                "{},{},{},{},{}",
+                wf.render_time(),
+                x,
+                value.re,
+                value.im,
+                value.norm_sqr()
+            )?;
+        }
+        file.flush()?;
+    }
+
+    Ok(())
+}
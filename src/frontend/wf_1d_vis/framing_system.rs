@@ -0,0 +1,65 @@
+//! Logic for framing the visualised wavefunctions with the camera.
+
+use std::f32::consts::PI;
+
+use bevy::{
+    ecs::system::{Local, Query, Res},
+    input::{ButtonInput, keyboard::KeyCode},
+    math::Vec3,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use super::EigenstateSelector;
+use crate::frontend::wf_component::WFComponent;
+
+/// The vertical field of view of the camera
+const FIELD_OF_VIEW: f32 = PI / 4.0;
+/// The fraction of the view left empty around the framed wavefunctions
+const MARGIN: f32 = 0.2;
+/// The offset along z of the probability density from the wavefunction
+const DENSITY_OFFSET: f32 = -2.0;
+
+/// Point the camera at the visualised wavefunctions, at a distance which fits
+/// their subdomains and peak amplitudes in view. When stepping through
+/// eigenstates, only the recommended subdomain of the displayed eigenstate is
+/// fitted. This is done once the caches are first filled, and again whenever
+/// `F` is pressed.
+pub fn fit_camera_system(
+    mut fitted: Local<bool>,
+    wf_query: Query<&WFComponent>,
+    mut camera_query: Query<&mut PanOrbitCamera>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    selector: Option<Res<EigenstateSelector>>,
+) {
+    if *fitted && !key_input.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    let Some((min, max)) = wf_query
+        .iter()
+        .map(|wf| {
+            let (mut lower, mut upper) = wf.bounds();
+            if let Some(selector) = &selector {
+                let recommended = selector.system().recommended_subdomain(selector.n());
+                lower = lower.max(recommended.lower);
+                upper = upper.min(recommended.upper);
+            }
+            let peak = wf.peak_amplitude().max(wf.peak_density());
+            (
+                Vec3::new(lower, -peak, DENSITY_OFFSET),
+                Vec3::new(upper, peak, peak),
+            )
+        })
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    else {
+        return;
+    };
+
+    let extent = (max - min).max_element();
+    let radius = 0.5 * extent * (1.0 + MARGIN) / (0.5 * FIELD_OF_VIEW).tan();
+    for mut camera in &mut camera_query {
+        camera.target_focus = 0.5 * (min + max);
+        camera.target_radius = radius;
+    }
+    *fitted = true;
+}
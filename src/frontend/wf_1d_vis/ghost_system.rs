@@ -0,0 +1,27 @@
+//! Logic for showing frozen snapshots of wavefunctions alongside the live ones.
+
+use bevy::{
+    camera::visibility::Visibility,
+    ecs::system::{Query, Res},
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use crate::frontend::wf_component::WFComponent;
+
+/// Show or hide the wavefunctions with a time override when `G` is pressed,
+/// such as the ghost of the initial state drawn for comparison with the
+/// evolving wavefunction.
+pub fn toggle_ghost_system(
+    mut query: Query<(&WFComponent, &mut Visibility)>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    if !key_input.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+
+    for (wf, mut visibility) in &mut query {
+        if wf.time_override.is_some() {
+            visibility.toggle_inherited_hidden();
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Logic for on-screen text describing the visualised wavefunctions.
+
+use bevy::{
+    color::Color,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::With,
+        system::{Commands, Query, Res},
+    },
+    text::{TextColor, TextFont},
+    ui::{Node, PositionType, Val, widget::Text},
+};
+
+use super::{EigenstateSelector, ExpansionSource};
+use crate::frontend::wf_component::WFComponent;
+
+/// Marker for the on-screen text describing the visualised wavefunction
+#[derive(Component, Default)]
+pub(in crate::frontend) struct InfoLabel;
+
+/// Spawn the on-screen text describing the visualised wavefunction in the
+/// bottom left corner of the screen.
+pub fn spawn_info_label(commands: &mut Commands) -> Entity {
+    commands
+        .spawn((
+            InfoLabel,
+            Text::default(),
+            TextFont {
+                font_size: 20.0,
+                ..Default::default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(12.0),
+                left: Val::Px(12.0),
+                ..Default::default()
+            },
+        ))
+        .id()
+}
+
+/// The fraction of a special time after each multiple of it during which the
+/// on-screen text flags that the time has been reached
+const SPECIAL_TIME_WINDOW: f32 = 0.02;
+
+/// Update the on-screen text with the time, domain, and norm of the
+/// visualised wavefunction, and the index and energy of the displayed
+/// eigenstate if stepping through eigenstates, the eigenstates in the
+/// expansion of an evolving wavefunction, and the reference energy if
+/// drawn in the rotating frame. The special times of the
+/// system are listed, and flagged just after each multiple is crossed.
+pub fn info_label_system(
+    mut labels: Query<&mut Text, With<InfoLabel>>,
+    wf_query: Query<&WFComponent>,
+    selector: Option<Res<EigenstateSelector>>,
+    source: Option<Res<ExpansionSource>>,
+) {
+    let Some(wf) = wf_query.iter().find(|wf| wf.time_override.is_none()) else {
+        return;
+    };
+
+    let (lower, upper) = wf.bounds();
+    let mut info = format!(
+        "t = {:.2}\nx in [{lower:.2}, {upper:.2}]\n|psi|^2 = {:.2}",
+        wf.time,
+        wf.norm_sqr()
+    );
+    if let Some(selector) = selector {
+        let n = selector.n();
+        info += &format!("\nn = {n}, E = {:.3}", selector.system().energy(n));
+    }
+    if let Some(source) = source {
+        let (min_n, max_n) = source.range();
+        info += &format!("\nexpanded in n = {min_n}..={max_n}");
+    }
+    if let (true, Some(energy)) = (wf.rotating_frame, wf.reference_energy) {
+        info += &format!("\nrotating frame, E_ref = {energy:.3}");
+    }
+    for (name, period) in wf.system().map(|s| s.special_times()).unwrap_or_default() {
+        info += &format!("\n{name} every t = {period:.2}");
+        if wf.time.abs() >= period && wf.time.rem_euclid(period) < SPECIAL_TIME_WINDOW * period {
+            info += &format!("  <-- {}", name.to_uppercase());
+        }
+    }
+
+    for mut text in &mut labels {
+        text.0.clone_from(&info);
+    }
+}
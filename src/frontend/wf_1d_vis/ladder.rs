@@ -0,0 +1,111 @@
+//! Static "ladder" visualisation of the energy eigenstates of a 1D system,
+//! with each eigenstate drawn at the height of its energy inside the potential.
+
+use bevy::{
+    asset::Assets,
+    color::LinearRgba,
+    ecs::system::{Commands, ResMut},
+    math::{Vec3, vec3},
+};
+use bevy_polyline::prelude::{
+    Polyline, PolylineBundle, PolylineHandle, PolylineMaterial, PolylineMaterialHandle,
+};
+
+use crate::{
+    framework::wavefunction::{Wavefunction, signature::Sign1D},
+    quantum_system::DiscreteSystem,
+};
+
+/// The height at which the highest eigenstate in the ladder is drawn
+const LADDER_HEIGHT: f32 = 4.0;
+/// The fraction of the spacing between neighbouring levels that each
+/// eigenstate may fill
+const LEVEL_FILL: f32 = 0.8;
+/// The fraction of the domain width by which the potential is drawn beyond
+/// each side of the domain
+const POTENTIAL_OVERHANG: f32 = 0.1;
+
+/// Spawn polylines drawing the real parts of the energy eigenstates
+/// `min_n..=max_n` of a system at `t = 0`, each offset vertically by its
+/// energy, along with the potential of the system if it has one. Energies are
+/// scaled so that the highest eigenstate is drawn at `LADDER_HEIGHT`.
+pub fn spawn_ladder(
+    system: &dyn DiscreteSystem<Sign1D>,
+    min_n: i32,
+    max_n: i32,
+    step_size: f32,
+    commands: &mut Commands,
+    polyline_materials: &mut ResMut<Assets<PolylineMaterial>>,
+    polylines: &mut ResMut<Assets<Polyline>>,
+) {
+    let spectrum = system.spectrum(min_n, max_n);
+    let max_energy = spectrum.iter().map(|(_, e, _)| *e).fold(0.0, f32::max);
+    let scale = if max_energy > 0.0 {
+        LADDER_HEIGHT / max_energy
+    } else {
+        1.0
+    };
+    let min_spacing = spectrum
+        .windows(2)
+        .map(|pair| (pair[1].1 - pair[0].1).abs() * scale)
+        .fold(LADDER_HEIGHT, f32::min);
+
+    let state_material = polyline_materials.add(PolylineMaterial {
+        width: 15.0,
+        color: LinearRgba::rgb(1.0, 2.0, 1.0),
+        perspective: true,
+        ..Default::default()
+    });
+    let mut bounds = (f32::INFINITY, f32::NEG_INFINITY);
+    for (_, energy, ket) in spectrum {
+        bounds = (
+            bounds.0.min(ket.subdomain.lower),
+            bounds.1.max(ket.subdomain.upper),
+        );
+        let peak = ket
+            .iter_with_step_size(step_size)
+            .map(|x| ket.f(x, 0.0).re.abs())
+            .fold(0.0, f32::max);
+        let amplitude = if peak > 0.0 {
+            0.5 * LEVEL_FILL * min_spacing / peak
+        } else {
+            0.0
+        };
+
+        let vertices = ket
+            .iter_with_step_size(step_size)
+            .map(|x| vec3(x, energy * scale + amplitude * ket.f(x, 0.0).re, 0.0))
+            .collect();
+        commands.spawn(PolylineBundle {
+            polyline: PolylineHandle(polylines.add(Polyline { vertices })),
+            material: PolylineMaterialHandle(state_material.clone()),
+            ..Default::default()
+        });
+    }
+
+    let overhang = POTENTIAL_OVERHANG * (bounds.1 - bounds.0);
+    let vertices: Vec<Vec3> = (0..)
+        .map(|i| bounds.0 - overhang + i as f32 * step_size)
+        .take_while(|&x| x <= bounds.1 + overhang)
+        .filter_map(|x| {
+            let potential = system.potential(x)?;
+            Some(vec3(
+                x,
+                (potential * scale).min(LADDER_HEIGHT + min_spacing),
+                0.0,
+            ))
+        })
+        .collect();
+    if !vertices.is_empty() {
+        commands.spawn(PolylineBundle {
+            polyline: PolylineHandle(polylines.add(Polyline { vertices })),
+            material: PolylineMaterialHandle(polyline_materials.add(PolylineMaterial {
+                width: 15.0,
+                color: LinearRgba::rgb(15.0, 2.0, 0.0),
+                perspective: true,
+                ..Default::default()
+            })),
+            ..Default::default()
+        });
+    }
+}
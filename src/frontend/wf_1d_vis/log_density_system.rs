@@ -0,0 +1,46 @@
+//! Logic for showing the logarithm of the probability density of wavefunctions.
+
+use bevy::{
+    camera::visibility::Visibility,
+    ecs::system::{Query, Res},
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use crate::frontend::wf_component::{WFComponent, WFType};
+
+/// The factor by which the log floor is lowered or raised with each key press
+const LOG_FLOOR_STEP: f32 = 10.0;
+
+/// Show or hide the logarithm of the probability density when `L` is
+/// pressed, which reveals the exponentially decaying tails of states in
+/// classically forbidden regions. `[` and `]` lower and raise the floor added
+/// to the density before taking its logarithm by a decade, showing more or
+/// less of the tails.
+pub fn toggle_log_density_system(
+    mut type_query: Query<(&WFType, &mut Visibility)>,
+    mut wf_query: Query<&mut WFComponent>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    let factor = if key_input.just_pressed(KeyCode::BracketLeft) {
+        1.0 / LOG_FLOOR_STEP
+    } else if key_input.just_pressed(KeyCode::BracketRight) {
+        LOG_FLOOR_STEP
+    } else {
+        1.0
+    };
+    if factor != 1.0 {
+        for mut wf in &mut wf_query {
+            wf.log_floor = (wf.log_floor * factor).max(f32::MIN_POSITIVE);
+        }
+    }
+
+    if !key_input.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+
+    for (wf_type, mut visibility) in &mut type_query {
+        if matches!(wf_type, WFType::LogDensity) {
+            visibility.toggle_inherited_hidden();
+        }
+    }
+}
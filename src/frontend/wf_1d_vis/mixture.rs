@@ -0,0 +1,130 @@
+//! Visualisation of the probability density of mixed states, which unlike
+//! superpositions show no interference between their pure states.
+
+use bevy::{
+    asset::Assets,
+    camera::visibility::Visibility,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        error::BevyError,
+        hierarchy::Children,
+        system::{Commands, Query, Res, ResMut},
+    },
+    input::{ButtonInput, keyboard::KeyCode},
+    math::vec3,
+    time::Time,
+    transform::components::Transform,
+};
+use bevy_polyline::prelude::{
+    Polyline, PolylineBundle, PolylineHandle, PolylineMaterial, PolylineMaterialHandle,
+};
+
+use super::{
+    super::{color_scheme::LinePalette, wf_component::WFType},
+    WFPolylineBundle,
+    animation_system::WFPolylineError,
+};
+use crate::framework::{
+    braket::DensityMatrix, core::domain::SubDomain, wavefunction::signature::Sign1D,
+};
+
+/// A component holding a mixed state being visualised by its probability
+/// density.
+#[derive(Component, Clone)]
+pub(in crate::frontend) struct MixtureComponent {
+    /// The mixed state
+    rho: DensityMatrix<Sign1D>,
+    /// The step size at which to render the probability density each frame
+    render_step: f32,
+    /// The time scale at which to render the mixed state. Lower values are
+    /// slower.
+    pub time_scale: f32,
+    /// Whether the evolution is paused. This may be mutated by bevy systems.
+    pub paused: bool,
+    /// The current time value associated with the mixed state. This may be
+    /// mutated by bevy systems.
+    pub time: f32,
+}
+
+impl MixtureComponent {
+    /// Create a component for a mixed state, rendered every `render_step`.
+    pub fn new(rho: DensityMatrix<Sign1D>, render_step: f32, time_scale: f32) -> Self {
+        Self {
+            rho,
+            render_step,
+            time_scale,
+            paused: false,
+            time: 0.0,
+        }
+    }
+
+    /// Get the points of the polyline drawing the probability density of the
+    /// mixture at the current time
+    fn vertices(&self) -> Vec<bevy::math::Vec3> {
+        self.rho
+            .subdomain()
+            .iter_with_step_size(self.render_step)
+            .map(|x| vec3(x, self.rho.density(x, self.time), 0.0))
+            .collect()
+    }
+}
+
+/// Spawn a visualiser for the probability density of a mixed state, returning
+/// the entity holding the `MixtureComponent`. The density is drawn by a
+/// `WFType::Density` polyline, so it is recoloured along with wavefunctions.
+pub fn spawn_mixture(
+    mixture: MixtureComponent,
+    commands: &mut Commands,
+    polyline_materials: &mut ResMut<Assets<PolylineMaterial>>,
+    polylines: &mut ResMut<Assets<Polyline>>,
+) -> Entity {
+    let line = WFPolylineBundle {
+        polyline: PolylineBundle {
+            polyline: PolylineHandle(polylines.add(Polyline {
+                vertices: mixture.vertices(),
+            })),
+            material: PolylineMaterialHandle(
+                polyline_materials.add(WFType::Density.polyline_mat(LinePalette::default())),
+            ),
+            ..Default::default()
+        },
+        wf_type: WFType::Density,
+    };
+    commands
+        .spawn((mixture, Transform::IDENTITY, Visibility::default()))
+        .with_children(|parent| {
+            parent.spawn(line);
+        })
+        .id()
+}
+
+/// Advance mixed states in time and redraw their probability densities.
+/// `Space` pauses and resumes the evolution.
+pub fn mixture_animation_system(
+    time: Res<Time>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut polylines: ResMut<Assets<Polyline>>,
+    poly_query: Query<&PolylineHandle>,
+    mut query: Query<(&mut MixtureComponent, &Children)>,
+) -> Result<(), BevyError> {
+    for (mut mixture, children) in &mut query {
+        if key_input.just_pressed(KeyCode::Space) {
+            mixture.paused = !mixture.paused;
+        }
+        if !mixture.paused {
+            mixture.time += mixture.time_scale * time.delta_secs();
+        }
+
+        for child in children {
+            if let Ok(PolylineHandle(handle)) = poly_query.get(*child) {
+                polylines
+                    .get_mut(handle)
+                    .ok_or(WFPolylineError::MissingPolyline)?
+                    .vertices = mixture.vertices();
+            }
+        }
+    }
+
+    Ok(())
+}
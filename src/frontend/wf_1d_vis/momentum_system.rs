@@ -0,0 +1,35 @@
+//! Logic for showing the momentum-space probability density of wavefunctions
+//! alongside their position-space probability density.
+
+use bevy::{
+    camera::visibility::Visibility,
+    ecs::system::{Query, Res},
+    input::{ButtonInput, keyboard::KeyCode},
+};
+
+use crate::frontend::wf_component::{WFComponent, WFType};
+
+/// Show or hide the momentum-space probability density when `K` is pressed.
+/// It is drawn against the wavenumber behind the position-space density, and
+/// is recomputed by FFT from the cache each frame while shown, so a moving
+/// packet shows a stationary momentum distribution while its position
+/// distribution translates.
+pub fn toggle_momentum_system(
+    mut type_query: Query<(&WFType, &mut Visibility)>,
+    mut wf_query: Query<&mut WFComponent>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    if !key_input.just_pressed(KeyCode::KeyK) {
+        return;
+    }
+
+    for mut wf in &mut wf_query {
+        wf.show_momentum = !wf.show_momentum;
+        wf.update_cache();
+    }
+    for (wf_type, mut visibility) in &mut type_query {
+        if matches!(wf_type, WFType::MomentumDensity) {
+            visibility.toggle_inherited_hidden();
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Logic for keeping moving wavefunctions centred in view.
+
+use bevy::{
+    ecs::system::{Local, Query, Res},
+    input::{ButtonInput, keyboard::KeyCode},
+    transform::components::Transform,
+};
+
+use crate::frontend::wf_component::WFComponent;
+
+/// Toggle recentring with `R`. While recentring, every wavefunction is
+/// shifted so that the expectation value of position `<x>` of the live
+/// wavefunction stays at the origin, so a moving packet appears stationary
+/// while the grid scrolls underneath it. Frozen wavefunctions are shifted
+/// along with the live one, so they stay comparable.
+pub fn recenter_system(
+    mut recentering: Local<bool>,
+    mut query: Query<(&WFComponent, &mut Transform)>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) {
+    if key_input.just_pressed(KeyCode::KeyR) {
+        *recentering = !*recentering;
+    }
+
+    let shift = if *recentering {
+        query
+            .iter()
+            .find(|(wf, _)| wf.time_override.is_none())
+            .map_or(0.0, |(wf, _)| -wf.expectation_x())
+    } else {
+        0.0
+    };
+    for (_, mut transform) in &mut query {
+        transform.translation.x = shift;
+    }
+}
@@ -0,0 +1,57 @@
+//! Logic for recolouring 1D wavefunction visualisations when the color scheme changes.
+
+use bevy::{
+    asset::Assets,
+    ecs::{
+        change_detection::DetectChanges,
+        error::BevyError,
+        query::With,
+        system::{Query, Res, ResMut},
+    },
+    pbr::{MeshMaterial3d, StandardMaterial},
+};
+use bevy_polyline::prelude::{PolylineMaterial, PolylineMaterialHandle};
+use thiserror::Error;
+
+use super::{
+    super::{color_scheme::ColorScheme, wf_component::WFType},
+    filled_wave::FilledWave,
+};
+
+#[derive(Error, Debug)]
+#[allow(clippy::missing_docs_in_private_items)]
+pub enum WFRecolorError {
+    #[error("Unable to find material using given handle.")]
+    MissingMaterial,
+}
+
+/// Recolour the polylines and fills of 1D wavefunctions with the line palette
+/// of the `ColorScheme` whenever it changes.
+pub fn recolor_wavefunction_system(
+    scheme: Res<ColorScheme>,
+    mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    poly_query: Query<(&PolylineMaterialHandle, &WFType)>,
+    fill_query: Query<(&MeshMaterial3d<StandardMaterial>, &WFType), With<FilledWave>>,
+) -> Result<(), BevyError> {
+    if !scheme.is_changed() {
+        return Ok(());
+    }
+
+    for (PolylineMaterialHandle(handle), wf_type) in &poly_query {
+        let material = polyline_materials
+            .get_mut(handle)
+            .ok_or(WFRecolorError::MissingMaterial)?;
+        material.color = scheme.lines.polyline_color(wf_type);
+    }
+    for (MeshMaterial3d(handle), wf_type) in &fill_query {
+        let material = standard_materials
+            .get_mut(handle)
+            .ok_or(WFRecolorError::MissingMaterial)?;
+        if let Some(color) = scheme.lines.fill_color(wf_type) {
+            material.base_color = color;
+        }
+    }
+
+    Ok(())
+}
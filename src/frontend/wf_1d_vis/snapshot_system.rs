@@ -0,0 +1,59 @@
+//! Logic for rendering a single frame of a wavefunction to an image file.
+
+use std::path::PathBuf;
+
+use bevy::{
+    app::AppExit,
+    ecs::{
+        message::MessageWriter,
+        observer::On,
+        resource::Resource,
+        system::{Commands, Local, Query, Res},
+    },
+    render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk},
+};
+
+use crate::frontend::wf_component::WFComponent;
+
+/// The number of frames rendered before the snapshot is taken, giving the
+/// camera time to settle and render pipelines time to compile.
+const SNAPSHOT_WARMUP_FRAMES: u32 = 120;
+
+/// A resource describing the snapshot to take of the visualised wavefunction.
+#[derive(Resource)]
+pub(in crate::frontend) struct SnapshotRequest {
+    /// The time at which to draw the wavefunction
+    pub time: f32,
+    /// The path of the image file to save, whose extension sets its format
+    pub path: PathBuf,
+}
+
+/// Freeze the live wavefunctions at the requested time, then save a
+/// screenshot of the window to the requested path once the scene has
+/// settled, and exit once it has been saved.
+pub fn snapshot_system(
+    mut frame: Local<u32>,
+    mut commands: Commands,
+    request: Res<SnapshotRequest>,
+    mut query: Query<&mut WFComponent>,
+) {
+    if *frame == 0 {
+        for mut wf in &mut query {
+            if wf.time_override.is_none() {
+                wf.time = request.time;
+                wf.paused = true;
+            }
+        }
+    }
+    if *frame == SNAPSHOT_WARMUP_FRAMES {
+        commands
+            .spawn(Screenshot::primary_window())
+            .observe(save_to_disk(request.path.clone()))
+            .observe(
+                |_: On<ScreenshotCaptured>, mut exit: MessageWriter<AppExit>| {
+                    exit.write(AppExit::Success);
+                },
+            );
+    }
+    *frame += 1;
+}
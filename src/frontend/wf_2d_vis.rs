@@ -0,0 +1,57 @@
+//! Frontend functionality for visualising 2D wavefunctions.
+
+mod colormap;
+mod heatmap;
+mod heatmap_system;
+mod wigner;
+
+pub use colormap::Colormap;
+pub(in crate::frontend) use heatmap::WFHeatmap;
+pub(in crate::frontend) use heatmap_system::update_heatmap_system;
+pub(in crate::frontend) use wigner::spawn_wigner;
+
+use bevy::{
+    asset::Assets,
+    ecs::{
+        entity::Entity,
+        system::{Commands, ResMut},
+    },
+    image::Image,
+    math::{Vec2, Vec3, primitives::Plane3d},
+    mesh::{Mesh, Mesh3d},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    transform::components::Transform,
+};
+
+/// Spawn a 2D wavefunction visualiser, returning the entity holding the
+/// `WFHeatmap`.
+/// The probability density is drawn as a heatmap on a quad in the xz-plane,
+/// with the wavefunction's y axis along -z.
+pub fn spawn_heatmap(
+    mut heatmap: WFHeatmap,
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+    images: &mut ResMut<Assets<Image>>,
+) -> Entity {
+    let image = heatmap.create_image(images);
+
+    let (x, y) = (&heatmap.subdomain().x, &heatmap.subdomain().y);
+    let half_size = Vec2::new(x.upper - x.lower, y.upper - y.lower) / 2.0;
+    let centre = Vec3::new((x.lower + x.upper) / 2.0, 0.0, -(y.lower + y.upper) / 2.0);
+    let quad = meshes.add(Plane3d::new(Vec3::Y, half_size));
+    let material = standard_materials.add(StandardMaterial {
+        base_color_texture: Some(image),
+        unlit: true,
+        ..Default::default()
+    });
+
+    commands
+        .spawn((
+            heatmap,
+            Mesh3d(quad),
+            MeshMaterial3d(material),
+            Transform::from_translation(centre),
+        ))
+        .id()
+}
@@ -0,0 +1,107 @@
+//! Colormaps for rendering scalar fields such as probability densities.
+
+/// A colormap taking values in `[0, 1]` to colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Colormap {
+    /// The perceptually uniform viridis colormap, from dark purple to yellow
+    #[default]
+    Viridis,
+    /// The turbo colormap, a smooth rainbow from dark blue to dark red
+    Turbo,
+    /// The perceptually uniform, cyclic twilight colormap, from white through blue to black
+    /// and back through red to white. Suited to periodic values such as phases.
+    Twilight,
+    /// The diverging cool-warm colormap, from blue through light grey at `0.5` to red. Suited
+    /// to signed values such as quasi-probabilities, with zero mapped to the middle.
+    CoolWarm,
+}
+
+/// Polynomial coefficients (constant term first) fitting the viridis colormap, from
+/// <https://www.shadertoy.com/view/WlfXRN>
+const VIRIDIS: [[f32; 3]; 7] = [
+    [0.277_727_33, 0.005_407_344_5, 0.334_099_8],
+    [0.105_093_04, 1.404_613_5, 1.384_590_2],
+    [-0.330_861_83, 0.214_847_56, 0.095_095_16],
+    [-4.634_230_6, -5.799_101, -19.332_441],
+    [6.228_27, 14.179_933, 56.690_55],
+    [4.776_385, -13.745_145, -65.353_03],
+    [-5.435_456, 4.645_852_6, 26.312_435],
+];
+
+/// Polynomial coefficients (constant term first) fitting the turbo colormap, from
+/// <https://gist.github.com/mikhailov-work/0d177465a8151eb6ede1768d51d476c7>
+const TURBO: [[f32; 3]; 6] = [
+    [0.135_721_38, 0.091_402_61, 0.106_673_3],
+    [4.615_392_6, 2.194_188_4, 12.641_946],
+    [-42.660_324, 4.842_966_6, -60.582_05],
+    [132.131_08, -14.185_033, 110.362_77],
+    [-152.942_4, 4.277_298_5, -89.903_11],
+    [59.286_38, 2.829_566, 27.348_25],
+];
+
+/// Approximate samples of matplotlib's twilight colormap at evenly spaced values, to be
+/// linearly interpolated. The first and last samples coincide, so the colormap is cyclic.
+const TWILIGHT: [[f32; 3]; 9] = [
+    [0.886, 0.850, 0.888],
+    [0.614, 0.694, 0.800],
+    [0.384, 0.500, 0.775],
+    [0.360, 0.268, 0.626],
+    [0.186, 0.074, 0.232],
+    [0.451, 0.143, 0.353],
+    [0.678, 0.314, 0.320],
+    [0.794, 0.583, 0.513],
+    [0.886, 0.850, 0.888],
+];
+
+/// Approximate samples of Moreland's diverging cool-warm colormap at evenly spaced values, to
+/// be linearly interpolated.
+const COOL_WARM: [[f32; 3]; 5] = [
+    [0.230, 0.299, 0.754],
+    [0.552, 0.690, 0.996],
+    [0.865, 0.865, 0.865],
+    [0.958, 0.604, 0.482],
+    [0.706, 0.016, 0.150],
+];
+
+/// Linearly interpolate between evenly spaced samples of a colormap
+fn interpolate_samples(samples: &[[f32; 3]], t: f32) -> [f32; 3] {
+    let pos = t * (samples.len() - 1) as f32;
+    let i = (pos.floor() as usize).min(samples.len() - 2);
+    let frac = pos - i as f32;
+    let (a, b) = (samples[i], samples[i + 1]);
+    [0, 1, 2].map(|channel| a[channel] * (1.0 - frac) + b[channel] * frac)
+}
+
+/// Evaluate the polynomial with the given coefficients (constant term first) for each channel
+fn eval_polynomial(coefficients: &[[f32; 3]], t: f32) -> [f32; 3] {
+    let mut color = [0.0; 3];
+    for coefficient in coefficients.iter().rev() {
+        for (channel, c) in color.iter_mut().zip(coefficient) {
+            *channel = *channel * t + c;
+        }
+    }
+    color
+}
+
+impl Colormap {
+    /// Get the sRGB color of the given value as channels in `[0, 1]`. Values are clamped to
+    /// `[0, 1]`.
+    pub fn rgb(self, value: f32) -> [f32; 3] {
+        let t = value.clamp(0.0, 1.0);
+        match self {
+            Colormap::Viridis => eval_polynomial(&VIRIDIS, t),
+            Colormap::Turbo => eval_polynomial(&TURBO, t),
+            Colormap::Twilight => interpolate_samples(&TWILIGHT, t),
+            Colormap::CoolWarm => interpolate_samples(&COOL_WARM, t),
+        }
+        .map(|channel| channel.clamp(0.0, 1.0))
+    }
+
+    /// Get the sRGB color of the given value as RGBA bytes. Values are clamped to `[0, 1]`.
+    pub fn rgba8(self, value: f32) -> [u8; 4] {
+        let [r, g, b] = self
+            .rgb(value)
+            .map(|channel| (channel * 255.0).round() as u8);
+        [r, g, b, 255]
+    }
+}
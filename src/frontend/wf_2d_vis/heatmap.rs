@@ -0,0 +1,120 @@
+//! A bevy component for rendering the probability density of a 2D wavefunction as a heatmap.
+
+use std::{f32::consts::TAU, sync::Arc};
+
+use bevy::{
+    asset::{Assets, Handle, RenderAssetUsages},
+    ecs::component::Component,
+    image::Image,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use num_complex::Complex32;
+
+use crate::framework::{
+    braket::Ket,
+    core::domain::{Point2D, SubDomain2D},
+    wavefunction::{Wavefunction, signature::Sign2D},
+};
+use crate::frontend::color_scheme::ColorScheme;
+
+/// A component holding information for one 2D wavefunction rendered as a heatmap of its
+/// probability density. The wavefunction is sampled on a grid, with one texel per sample.
+#[derive(Component, Clone)]
+pub(in crate::frontend) struct WFHeatmap {
+    /// A reference to the wavefunction
+    ket: Arc<Ket<Sign2D>>,
+    /// The step size of the sampling grid in each direction
+    step: Point2D,
+    /// The texture that the heatmap is drawn to. This is created when the
+    /// heatmap is spawned.
+    image: Handle<Image>,
+    /// Whether the phase of the wavefunction is drawn, shaded by the
+    /// probability density, rather than the probability density alone. This
+    /// may be mutated by bevy systems.
+    pub show_phase: bool,
+    /// The time scale at which to render the wavefunction. Lower values are
+    /// slower.
+    pub time_scale: f32,
+    /// Whether the wavefunction evolution is paused. This may be mutated by
+    /// bevy systems.
+    pub paused: bool,
+    /// The current time value associated with the wavefunction. This may be
+    /// mutated by bevy systems.
+    pub time: f32,
+}
+
+impl WFHeatmap {
+    /// Create a heatmap component for a wavefunction, sampled every `step`.
+    pub fn new(ket: Ket<Sign2D>, step: Point2D, time_scale: f32) -> Self {
+        Self {
+            ket: Arc::new(ket),
+            step,
+            image: Handle::default(),
+            show_phase: false,
+            time_scale,
+            paused: false,
+            time: 0.0,
+        }
+    }
+
+    /// Get the subdomain covered by the heatmap
+    pub fn subdomain(&self) -> &SubDomain2D {
+        &self.ket.subdomain
+    }
+
+    /// Create the heatmap texture, with one texel per sample, returning its handle
+    pub fn create_image(&mut self, images: &mut Assets<Image>) -> Handle<Image> {
+        let subdomain = &self.ket.subdomain;
+        self.image = images.add(Image::new_fill(
+            Extent3d {
+                width: subdomain.x.len(self.step.x) as u32,
+                height: subdomain.y.len(self.step.y) as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+        self.image.clone()
+    }
+
+    /// Get the handle of the heatmap texture
+    pub fn image(&self) -> &Handle<Image> {
+        &self.image
+    }
+
+    /// Sample the wavefunction grid and write it to the RGBA texture data of a
+    /// `width` by `height` image, with the colormaps of `scheme`. The density
+    /// is scaled so that its maximum is drawn with the top of the density
+    /// colormap. When showing the phase, the phase colormap is darkened where
+    /// the density is low.
+    pub fn draw(&self, data: &mut [u8], width: usize, height: usize, scheme: &ColorScheme) {
+        let values: Vec<Complex32> = self
+            .ket
+            .iter_with_step_size(self.step)
+            .map(|p| self.ket.f(p, self.time))
+            .collect();
+        let max = values.iter().map(Complex32::norm_sqr).fold(0.0, f32::max);
+        let scale = if max > 0.0 { 1.0 / max } else { 0.0 };
+
+        // Samples are ordered row by row with increasing y, while texture rows
+        // are ordered from the top down.
+        for (i, value) in values.into_iter().enumerate().take(width * height) {
+            let (col, row) = (i % width, height - 1 - i / width);
+            let texel = 4 * (row * width + col);
+            let density = value.norm_sqr() * scale;
+            let color = if self.show_phase {
+                let phase = value.arg().rem_euclid(TAU) / TAU;
+                let [r, g, b] = scheme
+                    .phase
+                    .rgb(phase)
+                    .map(|channel| (channel * density * 255.0).round() as u8);
+                [r, g, b, 255]
+            } else {
+                scheme.density.rgba8(density)
+            };
+            data[texel..texel + 4].copy_from_slice(&color);
+        }
+    }
+}
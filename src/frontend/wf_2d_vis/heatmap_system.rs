@@ -0,0 +1,59 @@
+//! Logic for updating 2D wavefunction heatmaps.
+
+use bevy::{
+    asset::Assets,
+    ecs::{
+        error::BevyError,
+        system::{Query, Res, ResMut},
+    },
+    image::Image,
+    input::{ButtonInput, keyboard::KeyCode},
+    time::Time,
+};
+use thiserror::Error;
+
+use super::{super::color_scheme::ColorScheme, heatmap::WFHeatmap};
+
+#[derive(Error, Debug)]
+#[allow(clippy::missing_docs_in_private_items)]
+pub enum WFHeatmapError {
+    #[error("Unable to find heatmap texture using given handle.")]
+    MissingImage,
+    #[error("Heatmap texture has no data.")]
+    MissingImageData,
+}
+
+/// Advance the time of 2D wavefunction heatmaps and redraw their textures with
+/// the current `ColorScheme`. `P` toggles between drawing the probability
+/// density and the phase.
+pub fn update_heatmap_system(
+    time: Res<Time>,
+    scheme: Res<ColorScheme>,
+    mut images: ResMut<Assets<Image>>,
+    mut query: Query<&mut WFHeatmap>,
+    key_input: Res<ButtonInput<KeyCode>>,
+) -> Result<(), BevyError> {
+    for mut heatmap in &mut query {
+        if key_input.just_pressed(KeyCode::Space) {
+            heatmap.paused = !heatmap.paused;
+        }
+        if key_input.just_pressed(KeyCode::KeyP) {
+            heatmap.show_phase = !heatmap.show_phase;
+        }
+        if !heatmap.paused {
+            heatmap.time += heatmap.time_scale * time.delta_secs();
+        }
+
+        let image = images
+            .get_mut(heatmap.image())
+            .ok_or(WFHeatmapError::MissingImage)?;
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        let data = image
+            .data
+            .as_mut()
+            .ok_or(WFHeatmapError::MissingImageData)?;
+        heatmap.draw(data, width, height, &scheme);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Rendering of the Wigner quasi-probability distribution of a 1D wavefunction as a heatmap
+//! over phase space.
+
+use bevy::{
+    asset::{Assets, RenderAssetUsages},
+    ecs::{
+        entity::Entity,
+        system::{Commands, ResMut},
+    },
+    image::Image,
+    math::{Vec2, Vec3, primitives::Plane3d},
+    mesh::{Mesh, Mesh3d},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    transform::components::Transform,
+};
+
+use super::Colormap;
+
+/// Spawn a heatmap of the Wigner function `wigner`, given as one row of
+/// `W(x, p)` over evenly spaced momenta in `p_range` for each of the evenly
+/// spaced positions in `x_range`, as returned by `Ket::wigner`. Phase space is
+/// drawn on a quad in the xz-plane, with momentum along -z. The diverging
+/// `CoolWarm` colormap is centred on zero and scaled so that the largest `|W|`
+/// reaches one of its ends, so negative regions are drawn in blue.
+pub fn spawn_wigner(
+    wigner: &[Vec<f32>],
+    x_range: (f32, f32),
+    p_range: (f32, f32),
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+    images: &mut ResMut<Assets<Image>>,
+) -> Entity {
+    let (width, height) = (wigner.len(), wigner.first().map_or(0, Vec::len));
+    let max = wigner.iter().flatten().map(|w| w.abs()).fold(0.0, f32::max);
+    let scale = if max > 0.0 { 0.5 / max } else { 0.0 };
+
+    // Texture rows are ordered from the top down, i.e. by decreasing momentum
+    let mut data = vec![0; 4 * width * height];
+    for (col, row) in wigner.iter().enumerate() {
+        for (j, w) in row.iter().enumerate() {
+            let texel = 4 * ((height - 1 - j) * width + col);
+            data[texel..texel + 4].copy_from_slice(&Colormap::CoolWarm.rgba8(0.5 + w * scale));
+        }
+    }
+    let image = images.add(Image::new(
+        Extent3d {
+            width: width as u32,
+            height: height as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ));
+
+    let half_size = Vec2::new(x_range.1 - x_range.0, p_range.1 - p_range.0) / 2.0;
+    let centre = Vec3::new(
+        (x_range.0 + x_range.1) / 2.0,
+        0.0,
+        -(p_range.0 + p_range.1) / 2.0,
+    );
+    let quad = meshes.add(Plane3d::new(Vec3::Y, half_size));
+    let material = standard_materials.add(StandardMaterial {
+        base_color_texture: Some(image),
+        unlit: true,
+        ..Default::default()
+    });
+
+    commands
+        .spawn((
+            Mesh3d(quad),
+            MeshMaterial3d(material),
+            Transform::from_translation(centre),
+        ))
+        .id()
+}
@@ -2,20 +2,27 @@
 
 use std::sync::Arc;
 
-use bevy::{
-    color::{Color, LinearRgba},
-    ecs::component::Component,
-    pbr::StandardMaterial,
-};
+use bevy::{ecs::component::Component, pbr::StandardMaterial};
 use bevy_polyline::prelude::PolylineMaterial;
 use num_complex::Complex32;
 use thiserror::Error;
 
 use super::super::{
-    framework::{braket::Ket, wavefunction::signature::Sign1D},
-    frontend::wf_1d_vis::{Cache1D, Cache1DError},
+    framework::{
+        braket::Ket, core::vectorspace::VectorSpace, transform::momentum_samples,
+        wavefunction::signature::Sign1D,
+    },
+    frontend::{
+        color_scheme::LinePalette,
+        wf_1d_vis::{Cache1D, Cache1DError, CacheInterpolation},
+    },
+    quantum_system::DiscreteSystem,
 };
 
+/// A map from the wall-clock seconds for which a wavefunction has evolved to
+/// the time at which it is drawn
+pub(in crate::frontend) type TimeFn = Arc<dyn Fn(f32) -> f32 + Send + Sync>;
+
 #[derive(Debug, Error)]
 #[allow(clippy::missing_docs_in_private_items)]
 pub enum WFComponentError {
@@ -33,40 +40,293 @@ pub(in crate::frontend) struct WFComponent {
     ket: Arc<Ket<Sign1D>>,
     /// The wavefunction cache. This may be mutated by bevy systems.
     cache: Cache1D,
+    /// The step size at which the wavefunction is sampled for the cache
+    cache_step: f32,
+    /// The interpolation used between cached samples
+    interpolation: CacheInterpolation,
     /// The step size at which to render the wavefunction each frame. This
-    /// should be lower than `eval_step_size`, as points between wavefunction
-    /// samples will be interpolated via Catmull-Rom.
+    /// may be lower than the cache step size, as points between wavefunction
+    /// samples will be interpolated.
     render_step: f32,
     /// The time scale at which to render the wavefunction. Lower values are
     /// slower.
     pub time_scale: f32,
+    /// The map from wall-clock seconds to the time at which to render the
+    /// wavefunction, if any, in place of scaling by `time_scale`. This allows
+    /// slowing down near interesting events, reversing, or looping over a
+    /// bounded interval. It may be mutated by bevy systems.
+    pub time_fn: Option<TimeFn>,
+    /// The wall-clock seconds for which the wavefunction has evolved while
+    /// unpaused, which are passed to `time_fn`
+    clock: f32,
     /// Whether the wavefunction evolution is paused. This may be mutated by
     /// bevy systems.
     pub paused: bool,
     /// The current time value associated with the wavefunction. This may be
     /// mutated by bevy systems.
     pub time: f32,
+    /// A fixed time at which to draw the wavefunction in place of `time`, for
+    /// static snapshots such as a ghost of the initial state.
+    pub time_override: Option<f32>,
+    /// How the probability density is scaled when rendered. This may be
+    /// mutated by bevy systems.
+    pub density_scale: DensityScale,
+    /// The largest probability density at a render point in the current
+    /// frame. This is updated along with the cache.
+    density_max: f32,
+    /// The system whose potential is drawn along with the wavefunction, if any
+    potential: Option<Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>>,
+    /// The largest finite potential at a render point, which is drawn with
+    /// height `POTENTIAL_HEIGHT`
+    potential_max: f32,
+    /// The energy of the classical particle whose probability density is
+    /// drawn along with the wavefunction, if any. This may be mutated by bevy
+    /// systems.
+    pub classical_energy: Option<f32>,
+    /// The floor added to the probability density before taking its
+    /// logarithm for `WFType::LogDensity`, below which tails are not drawn.
+    /// This may be mutated by bevy systems.
+    pub log_floor: f32,
+    /// The reference energy subtracted from every energy in the rotating
+    /// frame, such as the mean energy of the wavefunction, if known. This may
+    /// be mutated by bevy systems.
+    pub reference_energy: Option<f32>,
+    /// Whether the wavefunction is drawn in the frame rotating with the
+    /// phase `e^{-i E_ref t / hbar}` of the reference energy, so that an
+    /// eigenstate with the reference energy is drawn as static. This may be
+    /// mutated by bevy systems.
+    pub rotating_frame: bool,
+    /// The value of hbar used to find the rotating frame's angular frequency
+    hbar: f32,
+    /// The phase by which cached values are multiplied in the current frame,
+    /// which undoes the rotation of the reference energy in the rotating frame.
+    /// This is updated along with the cache.
+    frame_phase: Complex32,
+    /// Whether the momentum-space probability density is computed along
+    /// with the cache, to be drawn for `WFType::MomentumDensity`. This may be
+    /// mutated by bevy systems.
+    pub show_momentum: bool,
+    /// The `(k, |psi(k)|^2)` samples of the momentum-space probability
+    /// density in the current frame, trimmed to where it is significant. This
+    /// is updated along with the cache while `show_momentum` is set.
+    momentum_density: Vec<(f32, f32)>,
+}
+
+/// The largest classical probability density drawn, relative to the largest
+/// drawn probability density of the wavefunction. Classical densities diverge
+/// at turning points, so are clipped to this height.
+const CLASSICAL_CLIP: f32 = 2.0;
+
+/// The default floor added to the probability density before taking its
+/// logarithm, so that about eight decades of decaying tails are visible.
+const DEFAULT_LOG_FLOOR: f32 = 1e-8;
+
+/// The height at which each decade of the probability density above the log
+/// floor is drawn for `WFType::LogDensity`.
+const LOG_DENSITY_DECADE_HEIGHT: f32 = 0.25;
+
+/// The factor by which the rendered samples are padded with zeros before
+/// transforming to momentum space, which refines the spacing of wavenumbers
+/// by the same factor.
+const MOMENTUM_PADDING: usize = 8;
+
+/// The fraction of the largest momentum-space probability density below
+/// which its tails are trimmed, as the FFT spans far more wavenumbers than
+/// are occupied.
+const MOMENTUM_CUTOFF: f32 = 1e-3;
+
+/// The height at which the largest finite potential is drawn. Larger
+/// (e.g. infinite) potentials are clipped to this height.
+const POTENTIAL_HEIGHT: f32 = 2.0;
+
+/// Physical constants associated with a wavefunction, for systems computing
+/// physical quantities such as momentum or probability current.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct PhysicalParams {
+    /// The mass of the particle
+    pub mass: f32,
+    /// The value to use for the reduced Planck's constant
+    pub hbar: f32,
+}
+
+impl Default for PhysicalParams {
+    fn default() -> Self {
+        Self {
+            mass: 1.0,
+            hbar: 1.0,
+        }
+    }
+}
+
+/// How the probability density of a wavefunction is scaled when rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(in crate::frontend) enum DensityScale {
+    /// Render the probability density as-is
+    #[default]
+    Raw,
+    /// Scale the probability density so that the given value is rendered
+    /// with height 1
+    FixedMax(f32),
+    /// Scale the probability density each frame so that its maximum is
+    /// rendered with height 1
+    AutoPerFrame,
 }
 
 impl WFComponent {
-    /// Create a wavefunction component for a wavefunction
+    /// Create a wavefunction component for a wavefunction. The wavefunction is
+    /// sampled every `cache_step_size`, and rendered every `render_step_size`
+    /// by interpolating between samples with `interpolation`.
     pub fn new(
         ket: Ket<Sign1D>,
         cache_step_size: f32,
         render_step_size: f32,
+        interpolation: CacheInterpolation,
         time_scale: f32,
     ) -> Result<Self, WFComponentError> {
-        let cache = Cache1D::from_ket(&ket, cache_step_size)?;
+        let cache = Cache1D::from_ket(&ket, cache_step_size, interpolation)?;
         Ok(Self {
             ket: Arc::new(ket),
             cache,
+            cache_step: cache_step_size,
+            interpolation,
             render_step: render_step_size,
             time_scale,
+            time_fn: None,
+            clock: 0.0,
             paused: false,
             time: 0.0,
+            time_override: None,
+            density_scale: DensityScale::Raw,
+            density_max: 0.0,
+            potential: None,
+            potential_max: 0.0,
+            classical_energy: None,
+            log_floor: DEFAULT_LOG_FLOOR,
+            reference_energy: None,
+            rotating_frame: false,
+            hbar: 1.0,
+            frame_phase: Complex32::ONE,
+            show_momentum: false,
+            momentum_density: Vec::new(),
         })
     }
 
+    /// Draw the potential of `system` along with the wavefunction, if it is
+    /// described by a potential.
+    #[must_use]
+    pub fn with_potential(mut self, system: Arc<dyn DiscreteSystem<Sign1D> + Send + Sync>) -> Self {
+        self.potential_max = self
+            .iter_render_points()
+            .filter_map(|x| system.potential(x))
+            .filter(|v| v.is_finite())
+            .fold(0.0, f32::max);
+        self.potential = Some(system);
+        self
+    }
+
+    /// Update the cache from the `(c_n, E_n, |n>)` terms of the expansion of
+    /// the wavefunction in energy eigenstates, which are sampled once so that
+    /// each frame only recomputes the phase of each term. The terms should sum
+    /// to the wavefunction of this component. The mean energy of the terms is
+    /// taken as the reference energy of the rotating frame.
+    #[must_use]
+    pub fn with_spectrum(mut self, terms: &[(Complex32, f32, Ket<Sign1D>)], hbar: f32) -> Self {
+        self.apply_spectrum(terms, hbar);
+        self
+    }
+
+    /// Sample the terms of an expansion in energy eigenstates into the cache
+    /// and take their mean energy as the reference energy.
+    fn apply_spectrum(&mut self, terms: &[(Complex32, f32, Ket<Sign1D>)], hbar: f32) {
+        self.cache.set_spectrum(terms, hbar);
+        let (weight, energy) = terms
+            .iter()
+            .map(|(c, e, _)| (c.norm_sqr(), c.norm_sqr() * e))
+            .fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+        if weight > 0.0 {
+            self.reference_energy = Some(energy / weight);
+        }
+        self.hbar = hbar;
+        self.update_cache();
+    }
+
+    /// Use the given value of hbar to find the angular frequency of the
+    /// rotating frame.
+    #[must_use]
+    pub fn with_hbar(mut self, hbar: f32) -> Self {
+        self.hbar = hbar;
+        self
+    }
+
+    /// Map the wall-clock seconds for which the wavefunction has evolved to
+    /// the time at which it is drawn with `time_fn`, rather than scaling them
+    /// by the time scale.
+    #[must_use]
+    pub fn with_time_fn(mut self, time_fn: TimeFn) -> Self {
+        self.time = time_fn(self.clock);
+        self.time_fn = Some(time_fn);
+        self
+    }
+
+    /// Advance the wavefunction by `delta` seconds of wall-clock time, unless
+    /// it is paused. The time is found from `time_fn` if set, and otherwise
+    /// advances by `delta` scaled by the time scale.
+    pub fn advance(&mut self, delta: f32) {
+        if self.paused {
+            return;
+        }
+        self.clock += delta;
+        self.time = match &self.time_fn {
+            Some(time_fn) => time_fn(self.clock),
+            None => self.time + self.time_scale * delta,
+        };
+    }
+
+    /// Draw the wavefunction frozen at time `t`, rather than evolving it.
+    #[must_use]
+    pub fn frozen_at(mut self, t: f32) -> Self {
+        self.time_override = Some(t);
+        self
+    }
+
+    /// Return the time at which the wavefunction is drawn, which is the
+    /// override time if set, and the current time otherwise.
+    pub fn render_time(&self) -> f32 {
+        self.time_override.unwrap_or(self.time)
+    }
+
+    /// Return the system whose potential is drawn along with the wavefunction, if any
+    pub fn system(&self) -> Option<&(dyn DiscreteSystem<Sign1D> + Send + Sync)> {
+        self.potential.as_deref()
+    }
+
+    /// Replace the wavefunction with another, rebuilding the cache and
+    /// resetting the time to zero.
+    pub fn set_ket(&mut self, ket: Ket<Sign1D>) -> Result<(), WFComponentError> {
+        self.cache = Cache1D::from_ket(&ket, self.cache_step, self.interpolation)?;
+        self.ket = Arc::new(ket);
+        self.clock = 0.0;
+        self.time = self.time_fn.as_ref().map_or(0.0, |time_fn| time_fn(0.0));
+        self.update_cache();
+        Ok(())
+    }
+
+    /// Replace the wavefunction with the sum of the `(c_n, E_n, |n>)` terms of
+    /// an expansion in energy eigenstates, rebuilding the cache as in
+    /// `with_spectrum`. Unlike `set_ket`, the time is kept, so the new
+    /// expansion carries on evolving from the current frame.
+    pub fn set_spectrum(
+        &mut self,
+        terms: &[(Complex32, f32, Ket<Sign1D>)],
+        hbar: f32,
+    ) -> Result<(), WFComponentError> {
+        let ket = Ket::weighted_sum(terms.iter().map(|(c, _, ket)| (*c, ket.clone())).collect());
+        self.cache = Cache1D::from_ket(&ket, self.cache_step, self.interpolation)?;
+        self.ket = Arc::new(ket);
+        self.apply_spectrum(terms, hbar);
+        Ok(())
+    }
+
     /// Iterate over the wavefunction domain with rendering step size
     pub fn iter_render_points(&self) -> impl Iterator<Item = f32> {
         self.ket.iter_with_step_size(self.render_step)
@@ -74,13 +334,185 @@ impl WFComponent {
 
     /// Update the wavefunction value cache
     pub fn update_cache(&mut self) {
-        self.cache.update(&self.ket, self.time);
+        self.cache.update(&self.ket, self.render_time());
+        self.frame_phase = match self.reference_energy {
+            Some(energy) if self.rotating_frame => {
+                Complex32::cis(energy * self.render_time() / self.hbar)
+            }
+            _ => Complex32::ONE,
+        };
+        self.density_max = self
+            .iter_render_points()
+            .map(|x| self.cache_at(x).norm_sqr())
+            .fold(0.0, f32::max);
+        if self.show_momentum {
+            self.update_momentum_density();
+        }
+    }
+
+    /// Transform the cached values at evenly spaced render points to momentum space,
+    /// keeping the span of wavenumbers where the probability density exceeds
+    /// `MOMENTUM_CUTOFF` of its maximum.
+    fn update_momentum_density(&mut self) {
+        let mut samples: Vec<Complex32> = self
+            .ket
+            .subdomain
+            .uniform_points(self.render_step)
+            .map(|x| self.cache.at(x))
+            .collect();
+        if samples.is_empty() {
+            self.momentum_density.clear();
+            return;
+        }
+        samples.resize(samples.len() * MOMENTUM_PADDING, Complex32::ZERO);
+
+        let (k_min, dk, values) =
+            momentum_samples(samples, self.ket.subdomain.lower, self.render_step);
+        let densities: Vec<f32> = values.iter().map(Complex32::norm_sqr).collect();
+        let cutoff = MOMENTUM_CUTOFF * densities.iter().copied().fold(0.0, f32::max);
+        let first = densities.iter().position(|&d| d > cutoff).unwrap_or(0);
+        let last = densities.iter().rposition(|&d| d > cutoff).unwrap_or(0);
+        self.momentum_density = (first..=last)
+            .map(|j| (k_min + j as f32 * dk, densities[j]))
+            .collect();
+    }
+
+    /// Iterate over the points `(k, height)` at which the momentum-space
+    /// probability density is drawn, scaled according to the current
+    /// `DensityScale` relative to its own maximum.
+    pub fn momentum_points(&self) -> impl Iterator<Item = (f32, f32)> {
+        let max = match self.density_scale {
+            DensityScale::Raw => 1.0,
+            _ => self
+                .momentum_density
+                .iter()
+                .map(|&(_, d)| d)
+                .fold(0.0, f32::max),
+        };
+        let scale = if max > 0.0 { 1.0 / max } else { 0.0 };
+        self.momentum_density
+            .iter()
+            .map(move |&(k, d)| (k, d * scale))
+    }
+
+    /// Switch to the next `DensityScale`, cycling from `Raw` to `AutoPerFrame`
+    /// to `FixedMax`, and back to `Raw`. The fixed maximum is taken from the
+    /// current frame, so the density is frozen at its current scale.
+    pub fn cycle_density_scale(&mut self) {
+        self.density_scale = match self.density_scale {
+            DensityScale::Raw => DensityScale::AutoPerFrame,
+            DensityScale::AutoPerFrame => DensityScale::FixedMax(self.density_max),
+            DensityScale::FixedMax(_) => DensityScale::Raw,
+        };
+    }
+
+    /// Get the probability density at the given point, scaled according to
+    /// the current `DensityScale`.
+    pub fn density_at(&self, x: f32) -> f32 {
+        self.scale_density(self.cache_at(x).norm_sqr())
+    }
+
+    /// Scale a probability density according to the current `DensityScale`
+    fn scale_density(&self, density: f32) -> f32 {
+        let max = match self.density_scale {
+            DensityScale::Raw => return density,
+            DensityScale::FixedMax(max) => max,
+            DensityScale::AutoPerFrame => self.density_max,
+        };
+        if max > 0.0 { density / max } else { 0.0 }
+    }
+
+    /// Get the height at which the logarithm of the probability density
+    /// `log10(|psi|^2 + eps)` is drawn at the given point, where `eps` is the
+    /// log floor. The floor is drawn at height 0, so exponentially decaying
+    /// tails in classically forbidden regions remain visible above it.
+    pub fn log_density_at(&self, x: f32) -> f32 {
+        let floor = self.log_floor;
+        LOG_DENSITY_DECADE_HEIGHT * ((self.cache_at(x).norm_sqr() + floor) / floor).log10()
     }
 
-    /// Get the value at the given point. This is interpolated from sampled
-    /// points via Catmull-Rom.
+    /// Get the height at which the probability current
+    /// `(hbar / m) Im(psi* d psi / dx)` of a particle of the given mass is
+    /// drawn at the given point, taken by central differences of the cache
+    /// over one render step. It is scaled along with the probability density,
+    /// so a packet moving with speed `v` has current `v` times its density.
+    pub fn current_at(&self, x: f32, mass: f32) -> f32 {
+        let h = self.render_step;
+        let derivative = (self.cache_at(x + h) - self.cache_at(x - h)) / (2.0 * h);
+        let current = self.hbar / mass * (self.cache_at(x).conj() * derivative).im;
+        self.scale_density(current)
+    }
+
+    /// Get the height at which the probability density of a classical
+    /// particle with energy `classical_energy` is drawn at the given point,
+    /// scaled along with the probability density of the wavefunction, or
+    /// `None` if no classical density is drawn there.
+    pub fn classical_height(&self, x: f32) -> Option<f32> {
+        let energy = self.classical_energy?;
+        let density = self.potential.as_ref()?.classical_density(energy, x)?;
+        let clip = CLASSICAL_CLIP * self.scale_density(self.density_max);
+        Some(self.scale_density(density).min(clip))
+    }
+
+    /// Get the lower and upper bounds of the wavefunction domain
+    pub fn bounds(&self) -> (f32, f32) {
+        (self.ket.subdomain.lower, self.ket.subdomain.upper)
+    }
+
+    /// Get the squared norm of the wavefunction, integrated over the render
+    /// points from the cache.
+    pub fn norm_sqr(&self) -> f32 {
+        self.iter_render_points()
+            .map(|x| self.cache_at(x).norm_sqr())
+            .sum::<f32>()
+            * self.render_step
+    }
+
+    /// Get the expectation value of position `<x>`, integrated over the
+    /// render points from the cache.
+    pub fn expectation_x(&self) -> f32 {
+        let (norm, x) = self
+            .iter_render_points()
+            .map(|x| {
+                let density = self.cache_at(x).norm_sqr();
+                (density, x * density)
+            })
+            .fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+        if norm > 0.0 { x / norm } else { 0.0 }
+    }
+
+    /// Get the largest modulus of the wavefunction at a render point in the
+    /// current frame
+    pub fn peak_amplitude(&self) -> f32 {
+        self.iter_render_points()
+            .map(|x| self.cache_at(x).norm())
+            .fold(0.0, f32::max)
+    }
+
+    /// Get the largest rendered probability density in the current frame
+    pub fn peak_density(&self) -> f32 {
+        self.iter_render_points()
+            .map(|x| self.density_at(x))
+            .fold(0.0, f32::max)
+    }
+
+    /// Get the height at which the potential is drawn at the given point, or
+    /// `None` if no potential is drawn there.
+    pub fn potential_height(&self, x: f32) -> Option<f32> {
+        let v = self.potential.as_ref()?.potential(x)?;
+        if v.is_infinite() {
+            Some(POTENTIAL_HEIGHT)
+        } else if self.potential_max > 0.0 {
+            Some((v / self.potential_max * POTENTIAL_HEIGHT).min(POTENTIAL_HEIGHT))
+        } else {
+            Some(0.0)
+        }
+    }
+
+    /// Get the value at the given point, in the rotating frame if enabled.
+    /// This is interpolated from sampled points.
     pub fn cache_at(&self, x: f32) -> Complex32 {
-        self.cache.at(x)
+        self.frame_phase * self.cache.at(x)
     }
 }
 
@@ -99,6 +531,20 @@ pub(in crate::frontend) enum WFType {
     Imag,
     /// For the probability density of the wavefunction
     Density,
+    /// For the logarithm of the probability density of the wavefunction,
+    /// which shows the decaying tails of tunnelling states
+    LogDensity,
+    /// For the probability density of the wavefunction in momentum space,
+    /// drawn against the wavenumber `k`
+    MomentumDensity,
+    /// For the probability current of the wavefunction, which is positive
+    /// where probability flows towards larger `x`
+    Current,
+    /// For the potential the wavefunction evolves in
+    Potential,
+    /// For the probability density of a classical particle in the same
+    /// potential
+    Classical,
 }
 
 #[derive(Debug, Error)]
@@ -106,61 +552,54 @@ pub(in crate::frontend) enum WFType {
 pub enum FilledWaveMatError {
     #[error("FilledWave cannot be applied to WFType::Full wavefunctions")]
     AppliedToFullWF,
+    #[error("FilledWave cannot be applied to WFType::Potential")]
+    AppliedToPotential,
+    #[error("FilledWave cannot be applied to WFType::Classical")]
+    AppliedToClassical,
+    #[error("FilledWave cannot be applied to WFType::LogDensity")]
+    AppliedToLogDensity,
+    #[error("FilledWave cannot be applied to WFType::MomentumDensity")]
+    AppliedToMomentumDensity,
+    #[error("FilledWave cannot be applied to WFType::Current")]
+    AppliedToCurrent,
 }
 
 impl WFType {
-    /// Get the standard polyline material for a particular `WFType`
-    pub fn polyline_mat(&self) -> PolylineMaterial {
-        match self {
-            WFType::Full => PolylineMaterial {
-                width: 50.0,
-                color: LinearRgba::rgb(1.0, 2.0, 1.0),
-                perspective: true,
-                ..Default::default()
-            },
-            WFType::Real => PolylineMaterial {
-                width: 15.0,
-                color: LinearRgba::rgb(15.0, 0.0, 0.0),
-                perspective: true,
-                ..Default::default()
-            },
-            WFType::Imag => PolylineMaterial {
-                width: 15.0,
-                color: LinearRgba::rgb(0.0, 0.0, 15.0),
-                perspective: true,
-                ..Default::default()
-            },
-            WFType::Density => PolylineMaterial {
-                width: 50.0,
-                color: LinearRgba::rgb(10.0, 10.0, 10.0),
-                perspective: true,
-                ..Default::default()
-            },
+    /// Get the polyline material for a particular `WFType` in the given palette
+    pub fn polyline_mat(&self, palette: LinePalette) -> PolylineMaterial {
+        let width = match self {
+            WFType::Full | WFType::Density | WFType::MomentumDensity => 50.0,
+            WFType::Real
+            | WFType::Imag
+            | WFType::Potential
+            | WFType::Classical
+            | WFType::LogDensity
+            | WFType::Current => 15.0,
+        };
+        PolylineMaterial {
+            width,
+            color: palette.polyline_color(self),
+            perspective: true,
+            ..Default::default()
         }
     }
 
-    /// Get the standard `FilledWave` material for a particular `WFType`
-    pub fn filled_mat(&self) -> Result<StandardMaterial, FilledWaveMatError> {
-        match self {
-            WFType::Full => Err(FilledWaveMatError::AppliedToFullWF),
-            WFType::Real => Ok(StandardMaterial {
-                base_color: Color::srgba(1.0, 0.2, 0.2, 0.7),
-                cull_mode: None,
-                alpha_mode: bevy::render::alpha::AlphaMode::Add,
-                ..Default::default()
-            }),
-            WFType::Imag => Ok(StandardMaterial {
-                base_color: Color::srgba(0.3, 0.3, 1.0, 0.7),
-                cull_mode: None,
-                alpha_mode: bevy::render::alpha::AlphaMode::Add,
-                ..Default::default()
-            }),
-            WFType::Density => Ok(StandardMaterial {
-                base_color: Color::srgba(1.0, 1.0, 1.0, 0.7),
-                cull_mode: None,
-                alpha_mode: bevy::render::alpha::AlphaMode::Add,
-                ..Default::default()
-            }),
-        }
+    /// Get the `FilledWave` material for a particular `WFType` in the given palette
+    pub fn filled_mat(&self, palette: LinePalette) -> Result<StandardMaterial, FilledWaveMatError> {
+        let base_color = match self {
+            WFType::Full => return Err(FilledWaveMatError::AppliedToFullWF),
+            WFType::Potential => return Err(FilledWaveMatError::AppliedToPotential),
+            WFType::Classical => return Err(FilledWaveMatError::AppliedToClassical),
+            WFType::LogDensity => return Err(FilledWaveMatError::AppliedToLogDensity),
+            WFType::MomentumDensity => return Err(FilledWaveMatError::AppliedToMomentumDensity),
+            WFType::Current => return Err(FilledWaveMatError::AppliedToCurrent),
+            _ => palette.fill_color(self).unwrap_or_default(),
+        };
+        Ok(StandardMaterial {
+            base_color,
+            cull_mode: None,
+            alpha_mode: bevy::render::alpha::AlphaMode::Add,
+            ..Default::default()
+        })
     }
 }
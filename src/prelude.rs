@@ -2,15 +2,34 @@
 
 pub use crate::{
     framework::{
-        braket::{AbstractBra, AbstractKet, Bra, Ket},
-        core::domain::{SubDomain, SubDomain1D, SubDomain1DIter},
+        braket::{
+            AbstractBra, AbstractKet, Bra, DensityMatrix, EnergyBreakdown, IntegrationMethod,
+            Interp, Ket, MultiplyByPosition, Operator, tensor,
+        },
+        core::domain::{
+            Point2D, SubDomain, SubDomain1D, SubDomain1DIter, SubDomain2D,
+            tensor_domains::TensorPoint,
+        },
+        evolution::{CrankNicolsonEvolver, RK4Evolver},
+        transform::Edges,
         wavefunction::{
             Wavefunction,
-            signature::{SigFinite, Sign1D},
+            signature::{
+                SigFinite, SigRing, Sign1D, Sign1DF64, Sign1DReal, Sign2D, SignRadial,
+                TensorSignature,
+            },
         },
     },
-    frontend::run_viz_1d,
-    quantum_system::{DiscreteSystem, HarmonicWell, InfiniteSquareWell, TwoState},
+    frontend::{
+        CacheInterpolation, ColorScheme, Colormap, LinePalette, PhysicalParams, Qwaviz,
+        render_snapshot, run_viz_1d, run_viz_1d_in, run_viz_2d, run_viz_eigenstates,
+        run_viz_evolution, run_viz_ladder, run_viz_mixture, run_viz_wigner,
+    },
+    quantum_system::{
+        DeltaWell, DiscreteSystem, FiniteSquareWell, HarmonicWell, HarmonicWell2D, HydrogenRadial,
+        InfiniteSquareWell, KronigPenney, OffsetPotential, ParticleOnRing, PerturbedPotential,
+        SpinHalf, TwoState,
+    },
 };
 pub use num_complex::Complex32;
 pub use std::sync::Arc;
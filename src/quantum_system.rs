@@ -1,17 +1,40 @@
 //! For representing solvable, confined time-independent potentials or other systems with discrete states.
 
+mod delta_well;
+mod finite_square_well;
 mod harmonic_well;
+mod harmonic_well_2d;
+mod hydrogen_radial;
 mod infinite_square_well;
+mod kronig_penney;
+mod offset_potential;
+mod particle_on_ring;
+mod perturbed_potential;
+mod spin_half;
 mod two_state;
 
+pub use delta_well::DeltaWell;
+pub use finite_square_well::FiniteSquareWell;
 pub use harmonic_well::HarmonicWell;
+pub use harmonic_well_2d::HarmonicWell2D;
+pub use hydrogen_radial::HydrogenRadial;
 pub use infinite_square_well::InfiniteSquareWell;
+pub use kronig_penney::KronigPenney;
+pub use offset_potential::OffsetPotential;
+pub use particle_on_ring::ParticleOnRing;
+pub use perturbed_potential::PerturbedPotential;
+pub use spin_half::SpinHalf;
 pub use two_state::TwoState;
 
+use rand::{Rng, RngCore};
+
 use super::framework::{
-    braket::{AbstractBra, AbstractKet, Ket},
-    core::vectorspace::VectorSpace,
-    wavefunction::signature::WFSignature,
+    braket::{AbstractBra, AbstractKet, DensityMatrix, Ket, survival_probability},
+    core::{
+        field::{Field, RealField},
+        vectorspace::VectorSpace,
+    },
+    wavefunction::{Wavefunction, signature::WFSignature},
 };
 
 /// A time-independent potential for which the Schroedinger equation can be solved.
@@ -20,6 +43,143 @@ pub trait DiscreteSystem<S: WFSignature> {
     /// Return the `n`th energy eigenstate of the specified ISW, in the basis specified by `S::Space`
     fn energy_eigenstate(&self, n: i32) -> Ket<S>;
 
+    /// Return the energy of the `n`th energy eigenstate
+    fn energy(&self, n: i32) -> f32;
+
+    /// Return the potential energy at `x`, if the system is described by a potential
+    fn potential(&self, _x: S::Space) -> Option<f32> {
+        None
+    }
+
+    /// Return a subdomain which comfortably contains the `n`th energy eigenstate, for framing
+    /// views of it without guessing its extent. By default, this is the subdomain of the
+    /// eigenstate itself.
+    fn recommended_subdomain(&self, n: i32) -> S::SubDom {
+        self.energy_eigenstate(n).subdomain
+    }
+
+    /// Return the probability density at `x` of a classical particle with the given energy, as the
+    /// fraction of time it spends near `x`, if known. This is the classical limit of the
+    /// probability density of highly excited eigenstates.
+    fn classical_density(&self, _energy: f32, _x: S::Space) -> Option<f32> {
+        None
+    }
+
+    /// Return the index, energy and energy eigenstate of each of the levels `min_n..=max_n`, for
+    /// tabulating the spectrum of the system
+    fn spectrum(&self, min_n: i32, max_n: i32) -> Vec<(i32, f32, Ket<S>)> {
+        (min_n..=max_n)
+            .map(|n| (n, self.energy(n), self.energy_eigenstate(n)))
+            .collect()
+    }
+
+    /// Return the named times at which notable behaviour recurs, such as the revival time after
+    /// which every state returns to its initial value
+    fn special_times(&self) -> Vec<(&'static str, f32)> {
+        Vec::new()
+    }
+
+    /// Check that the energy eigenstates `min_n..=max_n` are orthonormal at time `t`, with each
+    /// inner product `<m|n>` within `tol` of `1` for `m = n` and of `0` otherwise.
+    fn is_orthonormal(
+        &self,
+        min_n: i32,
+        max_n: i32,
+        t: S::Time,
+        step_size: S::Space,
+        tol: <S::Out as Field>::Real,
+    ) -> bool {
+        let eigenstates: Vec<Ket<S>> = (min_n..=max_n).map(|n| self.energy_eigenstate(n)).collect();
+        eigenstates.iter().enumerate().all(|(i, bra)| {
+            let bra = Ket::<S>::adjoint(bra);
+            eigenstates.iter().enumerate().all(|(j, ket)| {
+                let expected = if i == j {
+                    S::Out::one()
+                } else {
+                    S::Out::zero()
+                };
+                (bra.apply(ket, t, step_size) - expected).modulus() <= tol
+            })
+        })
+    }
+
+    /// Return this system with the constant `v0` added to its potential, shifting every energy by
+    /// `v0`. The value of `hbar` should match the one used by this system.
+    fn with_offset(self, v0: f32, hbar: f32) -> OffsetPotential<Self>
+    where
+        Self: Sized,
+    {
+        OffsetPotential::new(self, v0, hbar)
+    }
+
+    /// Return the thermal state `e^{-H / kT} / Z` at temperature `kt` (in units of energy) over
+    /// the energy eigenstates `min_n..=max_n`, as an incoherent mixture of eigenstates with
+    /// Boltzmann probabilities.
+    fn thermal_state(&self, kt: f32, min_n: i32, max_n: i32) -> DensityMatrix<S> {
+        let spectrum = self.spectrum(min_n, max_n);
+        // Measure energies from the lowest level, so the weights cannot all underflow
+        let ground = spectrum
+            .iter()
+            .map(|(_, e, _)| *e)
+            .fold(f32::INFINITY, f32::min);
+        DensityMatrix::new(
+            spectrum
+                .into_iter()
+                .map(|(_, e, ket)| ((-(e - ground) / kt).exp(), ket))
+                .collect(),
+        )
+    }
+
+    /// Return the superposition of energy eigenstates with the given `(coefficient, n)` terms
+    fn superposition(&self, terms: &[(S::Out, i32)]) -> Ket<S> {
+        Ket::<S>::weighted_sum(
+            terms
+                .iter()
+                .map(|&(c, n)| (c, self.energy_eigenstate(n)))
+                .collect(),
+        )
+    }
+
+    /// Return the coefficients `c_n = <n|psi(t0)>` of `state(t=t0)` in the energy eigenstates
+    /// `min_n..=max_n`, in order of `n`. The spectrum of the state is given by `|c_n|^2`.
+    fn decompose(
+        &self,
+        state: &Ket<S>,
+        t0: S::Time,
+        step_size: S::Space,
+        min_n: i32,
+        max_n: i32,
+    ) -> Vec<S::Out> {
+        project_onto_eigenstates(self, state, t0, step_size, min_n, max_n)
+            .into_iter()
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Return the terms `(c_n, E_n, |n>)` of the expansion of `initial_state(t=t0)` in the energy
+    /// eigenstates `min_n..=max_n`. Each eigenstate only changes by the phase `e^{-i E_n t / hbar}`
+    /// in time, so this allows the evolved state to be sampled once and then cheaply advanced.
+    fn expansion(
+        &self,
+        initial_state: &Ket<S>,
+        t0: S::Time,
+        step_size: S::Space,
+        min_n: i32,
+        max_n: i32,
+    ) -> Vec<(S::Out, f32, Ket<S>)> {
+        (min_n..=max_n)
+            .zip(project_onto_eigenstates(
+                self,
+                initial_state,
+                t0,
+                step_size,
+                min_n,
+                max_n,
+            ))
+            .map(|(n, (c, ket))| (c, self.energy(n), ket))
+            .collect()
+    }
+
     /// Return a state which evolves from `initial_state(t=0)` according to the Schrodinger equation
     fn evolution(
         &self,
@@ -29,16 +189,147 @@ pub trait DiscreteSystem<S: WFSignature> {
         min_n: i32,
         max_n: i32,
     ) -> Ket<S> {
-        let coef_eigenkets: Vec<(S::Out, Ket<S>)> = (min_n..=max_n)
-            .map(|i| {
-                let basis_state = self.energy_eigenstate(i);
-                (
-                    Ket::<S>::adjoint(&basis_state).apply(initial_state, t0, step_size),
-                    basis_state,
-                )
+        Ket::<S>::weighted_sum(project_onto_eigenstates(
+            self,
+            initial_state,
+            t0,
+            step_size,
+            min_n,
+            max_n,
+        ))
+    }
+
+    /// Return a state which evolves from `initial_state(t=0)` according to the Schrodinger equation,
+    /// along with the fraction `sum |c_n|^2 / <psi|psi>` of the norm of the initial state captured
+    /// by the eigenstates `min_n..=max_n`. A fraction well below 1 indicates that the initial state
+    /// has significant components outside of this range, so `max_n` should be increased.
+    fn evolution_checked(
+        &self,
+        initial_state: &Ket<S>,
+        t0: S::Time,
+        step_size: S::Space,
+        min_n: i32,
+        max_n: i32,
+    ) -> (Ket<S>, f32) {
+        let coef_eigenkets =
+            project_onto_eigenstates(self, initial_state, t0, step_size, min_n, max_n);
+        let captured: f32 = coef_eigenkets
+            .iter()
+            .map(|(c, _)| c.modulus().to_f32().powi(2))
+            .sum();
+        let initial = initial_state.norm_sqr(t0, step_size).modulus().to_f32();
+
+        (Ket::<S>::weighted_sum(coef_eigenkets), captured / initial)
+    }
+
+    /// Return the autocorrelation `C(t) = <psi(t0)|psi(t)>` of a state evolving from
+    /// `initial_state(t=t0)`, sampled at each of the given `times`. The Fourier transform of
+    /// the autocorrelation reveals the energy spectrum of the state.
+    fn autocorrelation(
+        &self,
+        initial_state: &Ket<S>,
+        t0: S::Time,
+        step_size: S::Space,
+        min_n: i32,
+        max_n: i32,
+        times: &[S::Time],
+    ) -> Vec<S::Out> {
+        let evolved = self.evolution(initial_state, t0, step_size, min_n, max_n);
+        times
+            .iter()
+            .map(|&t| {
+                Ket::<S>::adjoint(&initial_state.clone().translate_time(t - t0))
+                    .apply(&evolved, t, step_size)
             })
+            .collect()
+    }
+
+    /// Return the probability that a state evolving from `initial_state(t=t0)` is still found in
+    /// its initial state after a projective measurement onto the initial state at each of the
+    /// given `measurement_times`, in increasing order. Each measurement which finds the initial
+    /// state collapses the state back onto it, so more frequent measurements slow the decay of
+    /// the initial state, illustrating the quantum Zeno effect.
+    fn zeno_survival(
+        &self,
+        initial_state: &Ket<S>,
+        t0: S::Time,
+        step_size: S::Space,
+        min_n: i32,
+        max_n: i32,
+        measurement_times: &[S::Time],
+    ) -> f32 {
+        // Measure against the evolved state at `t0` rather than `initial_state` itself, so that
+        // the part of `initial_state` outside the truncated basis is not lost at every measurement.
+        let evolved = self.evolution(initial_state, t0, step_size, min_n, max_n);
+        let mut previous = t0;
+        measurement_times
+            .iter()
+            .map(|&t| {
+                let elapsed = t - previous;
+                previous = t;
+                survival_probability(&evolved, &evolved, t0, t0 + elapsed, step_size)
+            })
+            .product()
+    }
+
+    /// Simulate a measurement of the energy of `state(t=t0)`, returning the index of the observed
+    /// eigenstate along with the eigenstate the state collapses to. The outcome `n` is sampled
+    /// with probability `|c_n|^2`, renormalised over the eigenstates `min_n..=max_n`.
+    ///
+    /// All randomness is drawn from `rng`, so passing a seeded generator (e.g.
+    /// `StdRng::seed_from_u64`) reproduces the same sequence of outcomes.
+    ///
+    /// # Panics
+    /// Panics if `state` has no component in any of the eigenstates `min_n..=max_n`.
+    fn measure_energy(
+        &self,
+        state: &Ket<S>,
+        t0: S::Time,
+        step_size: S::Space,
+        min_n: i32,
+        max_n: i32,
+        rng: &mut dyn RngCore,
+    ) -> (i32, Ket<S>) {
+        let probabilities: Vec<f32> = self
+            .decompose(state, t0, step_size, min_n, max_n)
+            .iter()
+            .map(|c| c.modulus().to_f32().powi(2))
             .collect();
+        let total: f32 = probabilities.iter().sum();
+        assert!(
+            total > 0.0,
+            "State has no component in the eigenstates being measured."
+        );
 
-        Ket::<S>::weighted_sum(coef_eigenkets)
+        let mut sample = rng.random::<f32>() * total;
+        let n = (min_n..=max_n)
+            .zip(&probabilities)
+            .find(|&(_, &p)| {
+                sample -= p;
+                sample < 0.0
+            })
+            .map_or(max_n, |(n, _)| n);
+        (n, self.energy_eigenstate(n))
     }
 }
+
+/// Return the energy eigenstates `min_n..=max_n` of a system, each paired with the coefficient
+/// of `initial_state(t=t0)` in that eigenstate
+fn project_onto_eigenstates<S: WFSignature, D: DiscreteSystem<S> + ?Sized>(
+    system: &D,
+    initial_state: &Ket<S>,
+    t0: S::Time,
+    step_size: S::Space,
+    min_n: i32,
+    max_n: i32,
+) -> Vec<(S::Out, Ket<S>)> {
+    (min_n..=max_n)
+        .map(|i| {
+            let basis_state = system.energy_eigenstate(i);
+            (
+                Ket::<S>::adjoint(&basis_state).apply(initial_state, t0, step_size),
+                basis_state,
+            )
+        })
+        .collect()
+}
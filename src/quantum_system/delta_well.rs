@@ -0,0 +1,90 @@
+//! Functionality for working with the bound state of an attractive delta-function well
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket, core::domain::SubDomain1D, wavefunction::signature::Sign1D,
+};
+use super::DiscreteSystem;
+
+/// The number of decay lengths `1 / kappa` either side of the well in which the bound state is
+/// taken to have a non-negligible tail
+const TAIL_DECAY_LENGTHS: f32 = 5.0;
+
+#[derive(Clone)]
+/// A struct representing an attractive delta-function well `V(x) = -alpha delta(x)`, which has
+/// exactly one bound state.
+pub struct DeltaWell {
+    /// The strength `alpha` of the well
+    alpha: f32,
+    /// The mass of the particle
+    mass: f32,
+    /// The value of hbar to use
+    hbar: f32,
+    /// The half-width of the subdomain the bound state is defined on
+    half_width: f32,
+}
+
+impl DeltaWell {
+    /// Create a delta-function well of strength `alpha`, whose bound state is defined on
+    /// `[-half_width, half_width]`
+    #[must_use]
+    pub fn new(alpha: f32, mass: f32, hbar: f32, half_width: f32) -> DeltaWell {
+        DeltaWell {
+            alpha,
+            mass,
+            hbar,
+            half_width,
+        }
+    }
+
+    /// Return the inverse decay length `kappa = m alpha / hbar^2` of the bound state
+    #[must_use]
+    pub fn kappa(&self) -> f32 {
+        self.mass * self.alpha / self.hbar.powi(2)
+    }
+}
+
+impl DiscreteSystem<Sign1D> for DeltaWell {
+    /// Return the bound state `sqrt(kappa) e^(-kappa |x|)`, which is the only eigenstate.
+    ///
+    /// # Panics
+    /// Panics if `n != 1`.
+    fn energy_eigenstate(&self, n: i32) -> Ket<Sign1D> {
+        assert!(
+            n == 1,
+            "Index of DeltaWell eigenstate invalid. Only state 1 allowed."
+        );
+
+        let (kappa, energy, hbar) = (self.kappa(), self.energy(n), self.hbar);
+        Ket::<Sign1D>::new(
+            move |x: f32, t| {
+                kappa.sqrt() * (-kappa * x.abs()).exp() * Complex32::cis(-energy * t / hbar)
+            },
+            SubDomain1D {
+                lower: -self.half_width,
+                upper: self.half_width,
+            },
+        )
+    }
+
+    /// Return the energy `-m alpha^2 / (2 hbar^2)` of the bound state, whatever `n`
+    fn energy(&self, _n: i32) -> f32 {
+        -self.mass * self.alpha.powi(2) / (2.0 * self.hbar.powi(2))
+    }
+
+    /// The potential vanishes away from the origin, and the delta function itself cannot be
+    /// sampled, so it is zero everywhere.
+    fn potential(&self, _x: f32) -> Option<f32> {
+        Some(0.0)
+    }
+
+    /// A few decay lengths either side of the well, clipped to the subdomain of the bound state.
+    fn recommended_subdomain(&self, _n: i32) -> SubDomain1D<f32> {
+        let half_width = (TAIL_DECAY_LENGTHS / self.kappa()).min(self.half_width);
+        SubDomain1D {
+            lower: -half_width,
+            upper: half_width,
+        }
+    }
+}
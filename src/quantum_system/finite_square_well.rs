@@ -0,0 +1,189 @@
+//! Functionality for working with the bound states of finite square wells
+
+use std::f32::consts::FRAC_PI_2;
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket, core::domain::SubDomain1D, wavefunction::signature::Sign1D,
+};
+use super::DiscreteSystem;
+
+/// The number of bisection steps used to solve for the wavenumber of each bound state
+const BISECTION_STEPS: u32 = 48;
+/// The number of decay lengths `1 / kappa` the subdomain of each bound state extends beyond
+/// the walls, so that its exponential tails are visible
+const TAIL_DECAY_LENGTHS: f32 = 5.0;
+/// The distance from the asymptotes of `tan` and `cot` at which the bisection for each bound
+/// state starts, in units of `z`. A state is only counted as bound if the strength `z0` of
+/// the well exceeds the start of its interval by at least this much.
+const ASYMPTOTE_MARGIN: f32 = 1e-4;
+/// The largest extent of the tails of each bound state beyond the walls, in widths of the well.
+/// This bounds the subdomains of barely bound states, whose decay lengths diverge.
+const MAX_TAIL_WIDTHS: f32 = 10.0;
+
+#[derive(Clone)]
+/// A struct representing a finite square well with a particle inside. The potential is zero
+/// on `[-width / 2, width / 2]` and `depth` elsewhere, so the well only supports finitely many
+/// bound states, with energies between `0` and `depth`.
+pub struct FiniteSquareWell {
+    /// The height of the potential outside the well
+    depth: f32,
+    /// The width of the well
+    width: f32,
+    /// The mass of the particle
+    mass: f32,
+    /// The value of hbar to use
+    hbar: f32,
+}
+
+impl FiniteSquareWell {
+    /// Create a finite square well of the given depth and width, centred on the origin
+    #[must_use]
+    pub fn new(depth: f32, width: f32, mass: f32, hbar: f32) -> FiniteSquareWell {
+        FiniteSquareWell {
+            depth,
+            width,
+            mass,
+            hbar,
+        }
+    }
+
+    /// Return the dimensionless strength `z0 = (w / 2) sqrt(2 m V0) / hbar` of the well, which
+    /// bounds `z = k w / 2` for every bound state
+    fn strength(&self) -> f32 {
+        0.5 * self.width * (2.0 * self.mass * self.depth).sqrt() / self.hbar
+    }
+
+    /// Return the number of bound states the well supports, `ceil(2 z0 / pi)`, less any state
+    /// bound by less than `ASYMPTOTE_MARGIN`, which could not be resolved. Every well supports
+    /// at least the (even) ground state.
+    #[must_use]
+    pub fn bound_states(&self) -> i32 {
+        (((self.strength() - ASYMPTOTE_MARGIN) / FRAC_PI_2).floor() as i32 + 1).max(1)
+    }
+
+    /// Return `z = k w / 2` of the `n`th bound state, with `n` clamped to
+    /// `1..=bound_states()`. This solves `z tan z = sqrt(z0^2 - z^2)` for even states (odd `n`)
+    /// and `-z cot z = sqrt(z0^2 - z^2)` for odd states (even `n`), which match the
+    /// wavefunction and its derivative at the walls. The left side rises from `0` to infinity
+    /// on `((n - 1) pi / 2, n pi / 2)` while the right side falls to `0` at `z0`, so there is
+    /// exactly one root there, found by bisection.
+    fn z(&self, n: i32) -> f32 {
+        let n = n.clamp(1, self.bound_states());
+        let z0 = self.strength();
+        let matching = |z: f32| {
+            let inside = if n % 2 == 1 {
+                z * z.tan()
+            } else {
+                -z / z.tan()
+            };
+            inside - (z0 * z0 - z * z).max(0.0).sqrt()
+        };
+        // The left side vanishes rather than diverging at the start of the interval
+        let mut lower = (n - 1) as f32 * FRAC_PI_2;
+        let mut upper = (n as f32 * FRAC_PI_2 - ASYMPTOTE_MARGIN).min(z0);
+        assert!(
+            lower < upper,
+            "No bound state {n} of a finite square well of strength {z0}."
+        );
+        for _ in 0..BISECTION_STEPS {
+            let mid = 0.5 * (lower + upper);
+            if matching(mid) < 0.0 {
+                lower = mid;
+            } else {
+                upper = mid;
+            }
+        }
+        0.5 * (lower + upper)
+    }
+
+    /// Return the wavenumber `k` inside the well and decay constant `kappa` outside it of the
+    /// `n`th bound state
+    fn wavenumbers(&self, n: i32) -> (f32, f32) {
+        let half_width = 0.5 * self.width;
+        let z = self.z(n);
+        let z0 = self.strength();
+        (
+            z / half_width,
+            (z0 * z0 - z * z).max(0.0).sqrt() / half_width,
+        )
+    }
+}
+
+/// Get the value of the bound state with wavenumber `k` and decay constant `kappa` at `x`, `t`,
+/// which is even in `x` if `even`, and odd otherwise. Inside the well it is `A cos(k x)` or
+/// `A sin(k x)`, continued outside by exponential tails matching its value at the walls.
+fn eigenfunction(
+    x: f32,
+    t: f32,
+    well: &FiniteSquareWell,
+    k: f32,
+    kappa: f32,
+    even: bool,
+) -> Complex32 {
+    let a = 0.5 * well.width;
+    let energy = (well.hbar * k).powi(2) / (2.0 * well.mass);
+    // Normalise analytically, as the integral of |psi|^2 inside plus that of both tails
+    let (inside, wall) = if even {
+        (a + (2.0 * k * a).sin() / (2.0 * k), (k * a).cos())
+    } else {
+        (a - (2.0 * k * a).sin() / (2.0 * k), (k * a).sin())
+    };
+    let coef = 1.0 / (inside + wall * wall / kappa).sqrt();
+    let value = if x.abs() <= a {
+        if even { (k * x).cos() } else { (k * x).sin() }
+    } else {
+        let tail = wall * (-kappa * (x.abs() - a)).exp();
+        if even || x > 0.0 { tail } else { -tail }
+    };
+    coef * value * Complex32::cis(-energy * t / well.hbar)
+}
+
+impl DiscreteSystem<Sign1D> for FiniteSquareWell {
+    /// Return the `n`th bound state, counting from the ground state at `n = 1`. As the well only
+    /// supports `bound_states()` bound states, larger `n` are clamped to the highest bound
+    /// state, and smaller `n` to the ground state. The subdomain extends
+    /// `TAIL_DECAY_LENGTHS` decay lengths beyond each wall, up to `MAX_TAIL_WIDTHS` widths of
+    /// the well.
+    fn energy_eigenstate(&self, n: i32) -> Ket<Sign1D> {
+        let (k, kappa) = self.wavenumbers(n);
+        let even = n.clamp(1, self.bound_states()) % 2 == 1;
+        let extent =
+            0.5 * self.width + (TAIL_DECAY_LENGTHS / kappa).min(MAX_TAIL_WIDTHS * self.width);
+        let well = self.clone();
+        Ket::<Sign1D>::new(
+            move |x, t| eigenfunction(x, t, &well, k, kappa, even),
+            SubDomain1D {
+                lower: -extent,
+                upper: extent,
+            },
+        )
+    }
+
+    /// Return the energy of the `n`th bound state, with `n` clamped as in `energy_eigenstate`
+    fn energy(&self, n: i32) -> f32 {
+        let (k, _) = self.wavenumbers(n);
+        (self.hbar * k).powi(2) / (2.0 * self.mass)
+    }
+
+    fn potential(&self, x: f32) -> Option<f32> {
+        if x.abs() <= 0.5 * self.width {
+            Some(0.0)
+        } else {
+            Some(self.depth)
+        }
+    }
+
+    /// A classical particle bound in the well bounces between the walls at constant speed, so
+    /// its density is uniform across the well for every bound energy.
+    fn classical_density(&self, energy: f32, x: f32) -> Option<f32> {
+        if energy >= self.depth {
+            None
+        } else if x.abs() <= 0.5 * self.width {
+            Some(1.0 / self.width)
+        } else {
+            Some(0.0)
+        }
+    }
+}
@@ -1,14 +1,14 @@
 //! 1D Harmonic well potential
 
-use std::{
-    f32::consts::{PI, SQRT_2},
-    sync::LazyLock,
-};
+use std::f32::consts::PI;
 
-use num_complex::Complex32;
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
 
 use super::super::framework::{
-    braket::Ket, core::domain::SubDomain1D, wavefunction::signature::Sign1D,
+    braket::Ket,
+    core::domain::SubDomain1D,
+    wavefunction::signature::{Sign1D, Sign1DF64},
 };
 
 use super::DiscreteSystem;
@@ -25,19 +25,22 @@ pub struct HarmonicWell {
     half_width: f32,
 }
 
-/// Inverse fourth root of pi
-static PI_FTH_RT: LazyLock<f32> = LazyLock::new(|| 1.0 / PI.sqrt().sqrt());
+/// Convert a small constant to the float type `F`
+fn float<F: Float>(x: f32) -> F {
+    F::from(x).unwrap_or_else(F::nan)
+}
 
 /// Courtesy of `ChatGPT`!
 /// Seriously, how is it so hard to find good implementations of
 /// normalised hermite polynomials?
-fn norm_hermite(n: i32, x: f32) -> f32 {
-    let psi0 = *PI_FTH_RT;
+fn norm_hermite<F: Float + FloatConst>(n: i32, x: F) -> F {
+    // Inverse fourth root of pi
+    let psi0 = F::PI().sqrt().sqrt().recip();
     if n == 0 {
         return psi0;
     }
 
-    let psi1 = SQRT_2 * x * psi0;
+    let psi1 = F::SQRT_2() * x * psi0;
     if n == 1 {
         return psi1;
     }
@@ -46,8 +49,9 @@ fn norm_hermite(n: i32, x: f32) -> f32 {
     let mut psi_n = psi1;
 
     for k in 1..n {
-        let kf = k as f32;
-        let psi_np1 = (2.0 / (kf + 1.0)).sqrt() * x * psi_n - (kf / (kf + 1.0)).sqrt() * psi_nm1;
+        let kf: F = float(k as f32);
+        let kp1 = kf + F::one();
+        let psi_np1 = (float::<F>(2.0) / kp1).sqrt() * x * psi_n - (kf / kp1).sqrt() * psi_nm1;
 
         psi_nm1 = psi_n;
         psi_n = psi_np1;
@@ -56,15 +60,23 @@ fn norm_hermite(n: i32, x: f32) -> f32 {
     psi_n
 }
 
-/// Get the value of the `n`th energy eigenfunction at `x`, `t` with given parameters
-fn eigenfunction(x: f32, t: f32, omega: f32, mass: f32, hbar: f32, n: i32) -> Complex32 {
+/// Get the value of the `n`th energy eigenfunction at `x`, `t` with given parameters, in the
+/// precision of `F`
+pub(super) fn eigenfunction<F: Float + FloatConst>(
+    x: F,
+    t: F,
+    omega: F,
+    mass: F,
+    hbar: F,
+    n: i32,
+) -> Complex<F> {
     let scale = (mass * omega / hbar).sqrt();
     let y = scale * x;
     let psi = norm_hermite(n, y);
     let prefactor = scale.sqrt();
-    let exp = (-0.5 * y * y).exp();
-    let energy = hbar * omega * (n as f32 + 0.5);
-    prefactor * exp * psi * Complex32::cis(-energy * t / hbar)
+    let exp = (float::<F>(-0.5) * y * y).exp();
+    let energy = hbar * omega * (float::<F>(n as f32) + float(0.5));
+    Complex::cis(-energy * t / hbar) * (prefactor * exp * psi)
 }
 
 impl HarmonicWell {
@@ -78,8 +90,50 @@ impl HarmonicWell {
             half_width,
         }
     }
+
+    /// Create a harmonic well in natural units, with `mass = omega = hbar = 1`, so that the `n`th
+    /// eigenstate has energy `n - 1/2`. The well is wide enough to hold the eigenstates
+    /// `1..=max_n`, and is returned along with the step size which samples it at `points`
+    /// evenly spaced points.
+    ///
+    /// # Panics
+    /// Panics if `points < 2`.
+    #[must_use]
+    pub fn natural(max_n: i32, points: usize) -> (HarmonicWell, f32) {
+        // The classical turning point of the highest state, plus a margin for its decaying tail
+        let half_width = (2.0 * max_n as f32 - 1.0).max(0.0).sqrt() + NATURAL_TAIL;
+        let (_, step_size) = SubDomain1D::from_points(-half_width, half_width, points);
+        (HarmonicWell::new(1.0, 1.0, 1.0, half_width), step_size)
+    }
+
+    /// Return the `n`th energy eigenstate in double precision, for accuracy-sensitive work such
+    /// as checking orthonormality to tight tolerances. The parameters of the well are widened
+    /// to `f64`, and `n` counts from 1 as in `energy_eigenstate`.
+    #[must_use]
+    pub fn energy_eigenstate_f64(&self, n: i32) -> Ket<Sign1DF64> {
+        let (omega, mass, hbar) = (
+            f64::from(self.omega),
+            f64::from(self.mass),
+            f64::from(self.hbar),
+        );
+        Ket::<Sign1DF64>::new(
+            move |x, t| eigenfunction(x, t, omega, mass, hbar, n - 1),
+            SubDomain1D {
+                lower: -f64::from(self.half_width),
+                upper: f64::from(self.half_width),
+            },
+        )
+    }
 }
 
+/// The margin beyond the outermost classical turning point given to harmonic wells in natural
+/// units, in which eigenstates have decayed to a negligible tail
+const NATURAL_TAIL: f32 = 4.0;
+
+/// The number of decay lengths beyond the classical turning points in which eigenstates are
+/// taken to have a non-negligible tail
+const TAIL_DECAY_LENGTHS: f32 = 3.0;
+
 impl DiscreteSystem<Sign1D> for HarmonicWell {
     fn energy_eigenstate(&self, n: i32) -> Ket<Sign1D> {
         let (omega, mass, hbar) = (self.omega, self.mass, self.hbar);
@@ -91,4 +145,42 @@ impl DiscreteSystem<Sign1D> for HarmonicWell {
             },
         )
     }
+
+    fn energy(&self, n: i32) -> f32 {
+        self.hbar * self.omega * (n as f32 - 0.5)
+    }
+
+    fn potential(&self, x: f32) -> Option<f32> {
+        Some(0.5 * self.mass * (self.omega * x).powi(2))
+    }
+
+    /// The classical turning points of the `n`th eigenstate, widened by a few decay lengths
+    /// `sqrt(hbar / m omega)` to hold its tails, and clipped to the well.
+    fn recommended_subdomain(&self, n: i32) -> SubDomain1D<f32> {
+        let length = (self.hbar / (self.mass * self.omega)).sqrt();
+        let turning_point = (2.0 * n as f32 - 1.0).max(0.0).sqrt() * length;
+        let half_width = (turning_point + TAIL_DECAY_LENGTHS * length).min(self.half_width);
+        SubDomain1D {
+            lower: -half_width,
+            upper: half_width,
+        }
+    }
+
+    /// A classical particle oscillating with amplitude `A = sqrt(2 E / m omega^2)` has density
+    /// `1 / (pi sqrt(A^2 - x^2))` between its turning points, which diverges at the turning
+    /// points themselves.
+    fn classical_density(&self, energy: f32, x: f32) -> Option<f32> {
+        let amplitude_sqr = 2.0 * energy / (self.mass * self.omega * self.omega);
+        if x * x < amplitude_sqr {
+            Some(1.0 / (PI * (amplitude_sqr - x * x).sqrt()))
+        } else {
+            Some(0.0)
+        }
+    }
+
+    /// Every state returns to its initial value, up to a global phase, after one classical
+    /// period `2 pi / omega`.
+    fn special_times(&self) -> Vec<(&'static str, f32)> {
+        vec![("period", 2.0 * PI / self.omega)]
+    }
 }
@@ -0,0 +1,172 @@
+//! 2D harmonic well potential, with independent frequencies along each axis
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use super::super::framework::{
+    braket::Ket,
+    core::domain::{Point2D, SubDomain1D, SubDomain2D},
+    wavefunction::signature::Sign2D,
+};
+use super::{DiscreteSystem, harmonic_well::eigenfunction};
+
+/// A struct representing a harmonic well potential in two dimensions, with frequencies
+/// `omega_x` and `omega_y` along its axes, which may be rotated from the coordinate axes.
+/// The energy eigenstates are products `psi_nx(x; omega_x) psi_ny(y; omega_y)` of 1D harmonic
+/// well eigenstates, with energy `hbar (omega_x (nx + 1/2) + omega_y (ny + 1/2))`. Only the
+/// isotropic well, with `omega_x = omega_y`, has degenerate energy levels of every size.
+pub struct HarmonicWell2D {
+    /// The standard `omega` value of the harmonic well potential along its x axis
+    omega_x: f32,
+    /// The standard `omega` value of the harmonic well potential along its y axis
+    omega_y: f32,
+    /// The anticlockwise angle of the axes of the well from the coordinate axes
+    angle: f32,
+    /// The mass of the particle
+    mass: f32,
+    /// The value to use for the reduced planck's constant
+    hbar: f32,
+    /// The half-width of the well in each direction, beyond which the wavefunction will be set
+    /// to zero.
+    half_width: f32,
+}
+
+/// The quantum numbers of an energy eigenstate, ordered as the eigenstates are indexed: by
+/// energy, and by decreasing `nx` within each degenerate level
+struct OrderedState {
+    /// The energy of the state
+    energy: f32,
+    /// The quantum number along the x axis of the well
+    nx: i32,
+    /// The quantum number along the y axis of the well
+    ny: i32,
+}
+
+impl Ord for OrderedState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.energy
+            .total_cmp(&other.energy)
+            .then(other.nx.cmp(&self.nx))
+    }
+}
+
+impl PartialOrd for OrderedState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for OrderedState {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedState {}
+
+impl HarmonicWell2D {
+    /// Create a 2D harmonic well, which is isotropic if `omega_x = omega_y`
+    #[must_use]
+    pub fn new(
+        omega_x: f32,
+        omega_y: f32,
+        mass: f32,
+        hbar: f32,
+        half_width: f32,
+    ) -> HarmonicWell2D {
+        HarmonicWell2D {
+            omega_x,
+            omega_y,
+            angle: 0.0,
+            mass,
+            hbar,
+            half_width,
+        }
+    }
+
+    /// Rotate the axes of the well anticlockwise by `angle` from the coordinate axes
+    #[must_use]
+    pub fn with_rotation(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Return the energy eigenstate with quantum numbers `nx` and `ny`, starting from 0
+    #[must_use]
+    pub fn eigenstate_xy(&self, nx: i32, ny: i32) -> Ket<Sign2D> {
+        let (omega_x, omega_y, mass, hbar) = (self.omega_x, self.omega_y, self.mass, self.hbar);
+        let (sin, cos) = self.angle.sin_cos();
+        let bounds = SubDomain1D {
+            lower: -self.half_width,
+            upper: self.half_width,
+        };
+        Ket::<Sign2D>::new(
+            move |p: Point2D, t| {
+                // Coordinates along the axes of the well
+                let (u, v) = (cos * p.x + sin * p.y, cos * p.y - sin * p.x);
+                eigenfunction(u, t, omega_x, mass, hbar, nx)
+                    * eigenfunction(v, t, omega_y, mass, hbar, ny)
+            },
+            SubDomain2D {
+                x: bounds.clone(),
+                y: bounds,
+            },
+        )
+    }
+
+    /// Return the energy of the eigenstate with quantum numbers `nx` and `ny`
+    #[must_use]
+    pub fn energy_xy(&self, nx: i32, ny: i32) -> f32 {
+        self.hbar * (self.omega_x * (nx as f32 + 0.5) + self.omega_y * (ny as f32 + 0.5))
+    }
+
+    /// Return the quantum numbers `(nx, ny)` of the `n`th energy eigenstate. States are ordered
+    /// by energy, and by decreasing `nx` within each degenerate level.
+    ///
+    /// # Panics
+    /// Panics if `n < 1`.
+    #[must_use]
+    pub fn quantum_numbers(&self, n: i32) -> (i32, i32) {
+        assert!(
+            n >= 1,
+            "Index of HarmonicWell2D eigenstate must be positive."
+        );
+        // Raising either quantum number raises the energy, so the states can be enumerated in
+        // order from the ground state: each state visited is followed by raising its `ny`, and
+        // those with `ny = 0` also by raising their `nx`.
+        let state = |nx, ny| {
+            Reverse(OrderedState {
+                energy: self.energy_xy(nx, ny),
+                nx,
+                ny,
+            })
+        };
+        let mut frontier = BinaryHeap::from([state(0, 0)]);
+        let mut index = 1;
+        while let Some(Reverse(OrderedState { nx, ny, .. })) = frontier.pop() {
+            if index == n {
+                return (nx, ny);
+            }
+            index += 1;
+            frontier.push(state(nx, ny + 1));
+            if ny == 0 {
+                frontier.push(state(nx + 1, 0));
+            }
+        }
+        unreachable!("Every state visited adds a higher state to the frontier.")
+    }
+}
+
+impl DiscreteSystem<Sign2D> for HarmonicWell2D {
+    fn energy_eigenstate(&self, n: i32) -> Ket<Sign2D> {
+        let (nx, ny) = self.quantum_numbers(n);
+        self.eigenstate_xy(nx, ny)
+    }
+
+    fn energy(&self, n: i32) -> f32 {
+        let (nx, ny) = self.quantum_numbers(n);
+        self.energy_xy(nx, ny)
+    }
+}
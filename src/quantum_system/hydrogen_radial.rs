@@ -0,0 +1,125 @@
+//! The radial part of the hydrogen-like atom.
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket, core::domain::SubDomain1D, wavefunction::signature::SignRadial,
+};
+use super::DiscreteSystem;
+
+/// The margin beyond the outermost classical turning point `2 n^2 a` of the `n`th eigenstate,
+/// in units of its decay length `n a`, in which the eigenstate has a non-negligible tail
+const TAIL_DECAY_LENGTHS: f32 = 15.0;
+
+/// A struct representing the radial part of a hydrogen-like atom with nuclear charge `z`, in units
+/// where the Coulomb constant and the elementary charge are 1, with angular momentum quantum
+/// number `l`. With `mass = hbar = 1`, these are atomic units.
+pub struct HydrogenRadial {
+    /// The charge of the nucleus, in units of the elementary charge
+    z: f32,
+    /// The mass of the electron
+    mass: f32,
+    /// The value to use for the reduced planck's constant
+    hbar: f32,
+    /// The angular momentum quantum number shared by all eigenstates
+    l: i32,
+}
+
+/// Get the value of the associated Laguerre polynomial `L_k^alpha(x)` by its three-term recurrence
+fn assoc_laguerre(k: i32, alpha: f32, x: f32) -> f32 {
+    let mut l_km1 = 1.0;
+    if k == 0 {
+        return l_km1;
+    }
+    let mut l_k = 1.0 + alpha - x;
+    for j in 1..k {
+        let jf = j as f32;
+        let l_kp1 = ((2.0 * jf + 1.0 + alpha - x) * l_k - (jf + alpha) * l_km1) / (jf + 1.0);
+        l_km1 = l_k;
+        l_k = l_kp1;
+    }
+    l_k
+}
+
+/// Get the value of the normalised radial eigenfunction `R_nl` at `r`, `t` with the scaled Bohr
+/// radius `a` and energy `energy`
+fn eigenfunction(r: f32, t: f32, a: f32, energy: f32, hbar: f32, n: i32, l: i32) -> Complex32 {
+    let nf = n as f32;
+    let rho = 2.0 * r / (nf * a);
+    // `(n - l - 1)! / (n + l)!`, accumulated in `f64` to avoid underflow for large `n`
+    let factorial_ratio: f64 = (n - l..=n + l).map(|k| 1.0 / f64::from(k)).product();
+    let prefactor = ((2.0 / (nf * a)).powi(3) * factorial_ratio as f32 / (2.0 * nf)).sqrt();
+    let radial = prefactor
+        * (-0.5 * rho).exp()
+        * rho.powi(l)
+        * assoc_laguerre(n - l - 1, (2 * l + 1) as f32, rho);
+    radial * Complex32::cis(-energy * t / hbar)
+}
+
+impl HydrogenRadial {
+    /// Create the radial part of a hydrogen-like atom with the s orbitals (`l = 0`)
+    #[must_use]
+    pub fn new(z: f32, mass: f32, hbar: f32) -> HydrogenRadial {
+        HydrogenRadial {
+            z,
+            mass,
+            hbar,
+            l: 0,
+        }
+    }
+
+    /// Return this system with angular momentum quantum number `l`, so that its eigenstates are
+    /// those with principal quantum numbers `n > l`
+    ///
+    /// # Panics
+    /// Panics if `l` is negative.
+    #[must_use]
+    pub fn with_l(self, l: i32) -> HydrogenRadial {
+        assert!(
+            l >= 0,
+            "Angular momentum quantum number must be non-negative."
+        );
+        HydrogenRadial { l, ..self }
+    }
+
+    /// Return the Bohr radius `hbar^2 / (m z)` scaled by the nuclear charge
+    fn bohr_radius(&self) -> f32 {
+        self.hbar * self.hbar / (self.mass * self.z)
+    }
+}
+
+impl DiscreteSystem<SignRadial> for HydrogenRadial {
+    /// Return the radial eigenstate `R_nl` with principal quantum number `n`, built from the
+    /// associated Laguerre polynomial `L_{n-l-1}^{2l+1}`.
+    ///
+    /// # Panics
+    /// Panics if `n <= l`.
+    fn energy_eigenstate(&self, n: i32) -> Ket<SignRadial> {
+        assert!(
+            n > self.l,
+            "Index of HydrogenRadial eigenstate invalid. Only states n > l allowed."
+        );
+
+        let (a, energy, hbar, l) = (self.bohr_radius(), self.energy(n), self.hbar, self.l);
+        Ket::<SignRadial>::new(
+            move |r, t| eigenfunction(r, t, a, energy, hbar, n, l),
+            SubDomain1D {
+                lower: 0.0,
+                upper: n as f32 * (2.0 * n as f32 + TAIL_DECAY_LENGTHS) * a,
+            },
+        )
+    }
+
+    /// The Bohr energies `-m z^2 / (2 hbar^2 n^2)`, which are `-z^2 / (2 n^2)` in atomic units
+    fn energy(&self, n: i32) -> f32 {
+        -self.mass * self.z * self.z / (2.0 * (self.hbar * n as f32).powi(2))
+    }
+
+    /// The Coulomb potential of the nucleus, along with the centrifugal barrier
+    /// `hbar^2 l (l + 1) / (2 m r^2)` of the angular momentum
+    fn potential(&self, r: f32) -> Option<f32> {
+        let centrifugal =
+            (self.hbar * self.hbar * (self.l * (self.l + 1)) as f32) / (2.0 * self.mass * r * r);
+        Some(-self.z / r + centrifugal)
+    }
+}
@@ -41,6 +41,32 @@ impl DiscreteSystem<Sign1D> for InfiniteSquareWell {
             },
         )
     }
+
+    fn energy(&self, n: i32) -> f32 {
+        (n as f32 * PI * self.hbar / self.width).powi(2) / (2.0 * self.mass)
+    }
+
+    fn potential(&self, x: f32) -> Option<f32> {
+        if (0.0..=self.width).contains(&x) {
+            Some(0.0)
+        } else {
+            Some(f32::INFINITY)
+        }
+    }
+
+    /// A classical particle bounces between the walls at constant speed, so its density is
+    /// uniform across the well for every energy.
+    fn classical_density(&self, _energy: f32, x: f32) -> Option<f32> {
+        if (0.0..=self.width).contains(&x) {
+            Some(1.0 / self.width)
+        } else {
+            Some(0.0)
+        }
+    }
+
+    fn special_times(&self) -> Vec<(&'static str, f32)> {
+        vec![("revival", self.revival_time())]
+    }
 }
 
 impl InfiniteSquareWell {
@@ -50,6 +76,18 @@ impl InfiniteSquareWell {
         InfiniteSquareWell { width, mass, hbar }
     }
 
+    /// Create an infinite square well of the given width in natural units, with
+    /// `mass = hbar = 1`, returned along with the step size which samples it at `points` evenly
+    /// spaced points.
+    ///
+    /// # Panics
+    /// Panics if `points < 2` or `width` is not positive.
+    #[must_use]
+    pub fn natural(width: f32, points: usize) -> (InfiniteSquareWell, f32) {
+        let (_, step_size) = SubDomain1D::from_points(0.0, width, points);
+        (InfiniteSquareWell::new(width, 1.0, 1.0), step_size)
+    }
+
     /// Return a the state resulting from suddenly expanding an ISW from width `initial_width` to `final_width`
     #[must_use]
     pub fn expansion_state(&self, initial_width: f32, n: i32) -> Ket<Sign1D> {
@@ -63,4 +101,34 @@ impl InfiniteSquareWell {
             },
         )
     }
+
+    /// Return the state evolving in this ISW after it is suddenly expanded from width
+    /// `initial_width`, starting from the `initial_n`th eigenstate of the narrower well at
+    /// `t = 0`. The initial state only overlaps the new eigenstates on `[0, initial_width]`, so
+    /// it is expanded in the eigenstates `1..=max_n` of this well, sampled with `step_size`.
+    #[must_use]
+    pub fn sudden_expansion(
+        &self,
+        initial_width: f32,
+        initial_n: i32,
+        step_size: f32,
+        max_n: i32,
+    ) -> Ket<Sign1D> {
+        let initial_state = self.expansion_state(initial_width, initial_n);
+        self.evolution(&initial_state, 0.0, step_size, 1, max_n)
+    }
+
+    /// Return the period `2 w / v` of a classical particle with the given energy bouncing between
+    /// the walls, where `v = sqrt(2 E / m)`
+    #[must_use]
+    pub fn classical_period(&self, energy: f32) -> f32 {
+        2.0 * self.width / (2.0 * energy / self.mass).sqrt()
+    }
+
+    /// Return the revival time `4 m w^2 / (pi hbar)` after which every state of this ISW
+    /// returns to its initial value.
+    #[must_use]
+    pub fn revival_time(&self) -> f32 {
+        4.0 * self.mass * self.width.powi(2) / (PI * self.hbar)
+    }
 }
@@ -0,0 +1,236 @@
+//! Band structure of the Kronig-Penney model, a 1D crystal of square wells separated by
+//! square barriers.
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket, core::domain::periodic_domains::PeriodicSubDomain1D,
+    wavefunction::signature::SigRing,
+};
+
+/// The number of energy samples per characteristic energy `hbar^2 pi^2 / 2 m L^2` used when
+/// scanning for band edges
+const SCAN_RESOLUTION: f32 = 200.0;
+/// The number of bisection steps used to refine band edges and Bloch state energies
+const BISECTION_STEPS: u32 = 48;
+/// The number of samples used to normalise Bloch states over a unit cell
+const NORMALISATION_SAMPLES: usize = 512;
+
+/// A periodic potential of wells of width `well_width` at zero potential, separated by barriers
+/// of width `barrier_width` and height `barrier_height`. Each unit cell `[0, L)` holds a well on
+/// `[0, well_width)` followed by a barrier, where `L = well_width + barrier_width`.
+pub struct KronigPenney {
+    /// The potential of the barriers
+    barrier_height: f32,
+    /// The width of the barriers
+    barrier_width: f32,
+    /// The width of the wells
+    well_width: f32,
+    /// The mass of the particle
+    mass: f32,
+    /// The value of hbar to use
+    hbar: f32,
+}
+
+/// A 2x2 real transfer matrix acting on `(psi, psi')`
+type Transfer = [[f32; 2]; 2];
+
+/// Return `(cos(q x), sin(q x) / q)` for `q = sqrt(q_sqr)`, continued analytically to negative
+/// `q_sqr` (where they become `cosh` and `sinh`) and to `q_sqr = 0`.
+fn cos_sinc(q_sqr: f32, x: f32) -> (f32, f32) {
+    if q_sqr > 0.0 {
+        let q = q_sqr.sqrt();
+        ((q * x).cos(), (q * x).sin() / q)
+    } else if q_sqr < 0.0 {
+        let kappa = (-q_sqr).sqrt();
+        ((kappa * x).cosh(), (kappa * x).sinh() / kappa)
+    } else {
+        (1.0, x)
+    }
+}
+
+/// Return the transfer matrix taking `(psi, psi')` across a region of length `x`, where
+/// `psi'' = -q_sqr psi`
+fn transfer(q_sqr: f32, x: f32) -> Transfer {
+    let (c, s) = cos_sinc(q_sqr, x);
+    [[c, s], [-q_sqr * s, c]]
+}
+
+/// Multiply two transfer matrices
+fn compose(a: Transfer, b: Transfer) -> Transfer {
+    [
+        [
+            a[0][0] * b[0][0] + a[0][1] * b[1][0],
+            a[0][0] * b[0][1] + a[0][1] * b[1][1],
+        ],
+        [
+            a[1][0] * b[0][0] + a[1][1] * b[1][0],
+            a[1][0] * b[0][1] + a[1][1] * b[1][1],
+        ],
+    ]
+}
+
+/// Find a root of `f` in `[lower, upper]` by bisection, given that `f` changes sign there
+fn bisect(f: impl Fn(f32) -> f32, mut lower: f32, mut upper: f32) -> f32 {
+    let lower_sign = f(lower).signum();
+    for _ in 0..BISECTION_STEPS {
+        let mid = 0.5 * (lower + upper);
+        if f(mid).signum() == lower_sign {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+    }
+    0.5 * (lower + upper)
+}
+
+impl KronigPenney {
+    /// Create a Kronig-Penney potential
+    #[must_use]
+    pub fn new(
+        barrier_height: f32,
+        barrier_width: f32,
+        well_width: f32,
+        mass: f32,
+        hbar: f32,
+    ) -> KronigPenney {
+        KronigPenney {
+            barrier_height,
+            barrier_width,
+            well_width,
+            mass,
+            hbar,
+        }
+    }
+
+    /// The period `L` of the potential
+    #[must_use]
+    pub fn period(&self) -> f32 {
+        self.well_width + self.barrier_width
+    }
+
+    /// Return `2m (E - V) / hbar^2`, the squared wavenumber at energy `E` in a region of
+    /// potential `V`
+    fn wavenumber_sqr(&self, energy: f32, potential: f32) -> f32 {
+        2.0 * self.mass * (energy - potential) / (self.hbar * self.hbar)
+    }
+
+    /// Return the transfer matrices across the well and across the barrier at energy `E`
+    fn cell_transfers(&self, energy: f32) -> (Transfer, Transfer) {
+        (
+            transfer(self.wavenumber_sqr(energy, 0.0), self.well_width),
+            transfer(
+                self.wavenumber_sqr(energy, self.barrier_height),
+                self.barrier_width,
+            ),
+        )
+    }
+
+    /// Return the right hand side `f(E)` of the dispersion relation `cos(kL) = f(E)`.
+    /// Energies with `|f(E)| <= 1` lie in an allowed band.
+    #[must_use]
+    pub fn dispersion(&self, energy: f32) -> f32 {
+        let (well, barrier) = self.cell_transfers(energy);
+        let cell = compose(barrier, well);
+        0.5 * (cell[0][0] + cell[1][1])
+    }
+
+    /// Return the lowest `n_bands` allowed energy bands as `(E_min, E_max)` ranges. Bands
+    /// separated by gaps narrower than the scanning resolution, such as for vanishing barriers,
+    /// are merged.
+    #[must_use]
+    pub fn bands(&self, n_bands: usize) -> Vec<(f32, f32)> {
+        let scale = (std::f32::consts::PI * self.hbar / self.period()).powi(2) / (2.0 * self.mass);
+        let step = scale / SCAN_RESOLUTION;
+        // Each band lies below the corresponding level of an isolated well of width
+        // `well_width`, or just above the barriers.
+        let max_energy = self.barrier_height
+            + ((n_bands + 1) as f32 * self.period() / self.well_width).powi(2) * scale;
+
+        let excess = |energy: f32| self.dispersion(energy).abs() - 1.0;
+        let mut bands = Vec::with_capacity(n_bands);
+        let mut band_start = if excess(0.0) <= 0.0 { Some(0.0) } else { None };
+        let mut energy = 0.0;
+        while bands.len() < n_bands && energy < max_energy {
+            let next = energy + step;
+            match (band_start, excess(next) <= 0.0) {
+                (None, true) => band_start = Some(bisect(excess, energy, next)),
+                (Some(start), false) => {
+                    bands.push((start, bisect(excess, energy, next)));
+                    band_start = None;
+                }
+                _ => {}
+            }
+            energy = next;
+        }
+        bands
+    }
+
+    /// Return the energy of the Bloch state with crystal momentum `k` in the given band,
+    /// counting from 0
+    ///
+    /// # Panics
+    /// Panics if the band could not be found.
+    #[must_use]
+    pub fn band_energy(&self, k: f32, band: usize) -> f32 {
+        let (lower, upper) = *self
+            .bands(band + 1)
+            .get(band)
+            .expect("Kronig-Penney band not found.");
+        let target = (k * self.period()).cos();
+        bisect(|energy| self.dispersion(energy) - target, lower, upper)
+    }
+
+    /// Return the Bloch state `psi_k(x) = e^{ikx} u_k(x)` with crystal momentum `k` in the given
+    /// band (counting from 0), as its periodic part `u_k` on a ring of one unit cell. The state is
+    /// normalised over the unit cell.
+    ///
+    /// # Panics
+    /// Panics if the band could not be found.
+    #[must_use]
+    pub fn bloch_state(&self, k: f32, band: usize) -> Ket<SigRing> {
+        let energy = self.band_energy(k, band);
+        let (well, barrier) = self.cell_transfers(energy);
+        let cell = compose(barrier, well);
+
+        // The initial (psi, psi') is an eigenvector of the cell transfer matrix with
+        // eigenvalue e^{ikL}, by Bloch's theorem.
+        let bloch_phase = Complex32::cis(k * self.period());
+        let initial = if cell[0][1].abs() > f32::EPSILON {
+            (Complex32::new(cell[0][1], 0.0), bloch_phase - cell[0][0])
+        } else {
+            (bloch_phase - cell[1][1], Complex32::new(cell[1][0], 0.0))
+        };
+
+        let (well_sqr, barrier_sqr) = (
+            self.wavenumber_sqr(energy, 0.0),
+            self.wavenumber_sqr(energy, self.barrier_height),
+        );
+        let well_width = self.well_width;
+        let periodic_part = move |x: f32| {
+            let m = if x < well_width {
+                transfer(well_sqr, x)
+            } else {
+                compose(transfer(barrier_sqr, x - well_width), well)
+            };
+            Complex32::cis(-k * x) * (m[0][0] * initial.0 + m[0][1] * initial.1)
+        };
+
+        let period = self.period();
+        let dx = period / NORMALISATION_SAMPLES as f32;
+        let norm_sqr: f32 = (0..NORMALISATION_SAMPLES)
+            .map(|i| periodic_part(i as f32 * dx).norm_sqr() * dx)
+            .sum();
+        let scale = 1.0 / norm_sqr.sqrt();
+        let hbar = self.hbar;
+
+        Ket::new(
+            move |x: f32, t: f32| {
+                scale * periodic_part(x.rem_euclid(period)) * Complex32::cis(-energy * t / hbar)
+            },
+            PeriodicSubDomain1D {
+                circumference: period,
+            },
+        )
+    }
+}
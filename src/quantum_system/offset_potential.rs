@@ -0,0 +1,71 @@
+//! Functionality for shifting the energies of a system by a constant potential offset.
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket,
+    wavefunction::{Wavefunction, signature::WFSignature},
+};
+use super::DiscreteSystem;
+
+/// A system with a constant offset `v0` added to its potential. Every energy is shifted by `v0`,
+/// so each eigenstate picks up the extra global phase `e^{-i v0 t / hbar}` as it evolves, while
+/// probability densities are unchanged.
+pub struct OffsetPotential<D> {
+    /// The system being offset
+    system: D,
+    /// The constant added to the potential
+    v0: f32,
+    /// The value of hbar to use.
+    hbar: f32,
+}
+
+impl<D> OffsetPotential<D> {
+    /// Return a new `OffsetPotential` adding `v0` to the potential of `system`. The value of
+    /// `hbar` should match the one used by `system`.
+    #[must_use]
+    pub fn new(system: D, v0: f32, hbar: f32) -> Self {
+        Self { system, v0, hbar }
+    }
+
+    /// Return the system being offset
+    #[must_use]
+    pub fn inner(&self) -> &D {
+        &self.system
+    }
+}
+
+impl<S, D> DiscreteSystem<S> for OffsetPotential<D>
+where
+    S: WFSignature<Time = f32, Out = Complex32> + 'static,
+    D: DiscreteSystem<S>,
+{
+    fn energy_eigenstate(&self, n: i32) -> Ket<S> {
+        let eigenstate = self.system.energy_eigenstate(n);
+        let subdomain = eigenstate.subdomain.clone();
+        let (v0, hbar) = (self.v0, self.hbar);
+        Ket::new(
+            move |x, t: f32| Complex32::cis(-v0 * t / hbar) * eigenstate.f(x, t),
+            subdomain,
+        )
+    }
+
+    fn energy(&self, n: i32) -> f32 {
+        self.system.energy(n) + self.v0
+    }
+
+    fn potential(&self, x: S::Space) -> Option<f32> {
+        self.system.potential(x).map(|v| v + self.v0)
+    }
+
+    /// The offset raises the potential along with the energy, so the classical motion at an
+    /// energy `E` is that of the inner system at `E - v0`.
+    fn classical_density(&self, energy: f32, x: S::Space) -> Option<f32> {
+        self.system.classical_density(energy - self.v0, x)
+    }
+
+    /// The offset only changes the global phase, so the special times are unchanged.
+    fn special_times(&self) -> Vec<(&'static str, f32)> {
+        self.system.special_times()
+    }
+}
@@ -0,0 +1,55 @@
+//! Functionality for working with a free particle confined to a ring.
+
+use std::f32::consts::PI;
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket, core::domain::periodic_domains::PeriodicSubDomain1D,
+    wavefunction::signature::SigRing,
+};
+use super::DiscreteSystem;
+
+#[derive(Clone)]
+/// A struct representing a free particle on a ring, with position measured as arc length.
+pub struct ParticleOnRing {
+    /// The radius of the ring
+    radius: f32,
+    /// The mass of the particle
+    mass: f32,
+    /// The value of hbar to use
+    hbar: f32,
+}
+
+/// Get the value of the `n`th energy eigenfunction at `x`, `t` with given parameters
+fn eigenfunction(x: f32, t: f32, radius: f32, mass: f32, hbar: f32, n: i32) -> Complex32 {
+    let energy = (hbar * n as f32 / radius).powi(2) / (2.0 * mass);
+    let coef = 1.0 / (2.0 * PI * radius).sqrt();
+    coef * Complex32::cis(n as f32 * x / radius) * Complex32::cis(-energy * t / hbar)
+}
+
+impl ParticleOnRing {
+    /// Create a particle on a ring
+    #[must_use]
+    pub fn new(radius: f32, mass: f32, hbar: f32) -> ParticleOnRing {
+        ParticleOnRing { radius, mass, hbar }
+    }
+}
+
+impl DiscreteSystem<SigRing> for ParticleOnRing {
+    /// Return the eigenstate with angular momentum `n * hbar`. Note that `n` may be negative,
+    /// and that the states `n` and `-n` are degenerate.
+    fn energy_eigenstate(&self, n: i32) -> Ket<SigRing> {
+        let (radius, mass, hbar) = (self.radius, self.mass, self.hbar);
+        Ket::<SigRing>::new(
+            move |x, t| eigenfunction(x, t, radius, mass, hbar, n),
+            PeriodicSubDomain1D {
+                circumference: 2.0 * PI * radius,
+            },
+        )
+    }
+
+    fn energy(&self, n: i32) -> f32 {
+        (self.hbar * n as f32 / self.radius).powi(2) / (2.0 * self.mass)
+    }
+}
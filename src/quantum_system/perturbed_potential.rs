@@ -0,0 +1,196 @@
+//! Functionality for perturbing a system by diagonalising its Hamiltonian in a truncated basis.
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::{Ket, WFOperation, operator_matrix},
+    wavefunction::{Wavefunction, signature::WFSignature},
+};
+use super::DiscreteSystem;
+
+/// Largest number of Jacobi sweeps used to diagonalise the Hamiltonian
+const MAX_SWEEPS: usize = 64;
+
+/// Off-diagonal magnitude below which the Hamiltonian is considered diagonal
+const OFF_DIAGONAL_EPSILON: f32 = 1e-9;
+
+/// A system with a multiplicative perturbation `V` added to the Hamiltonian `H0` of a base
+/// system. The Hamiltonian `<m|H0 + V|n> = E_m delta_mn + <m|V|n>` is built in the lowest
+/// `basis_size` eigenstates of the base system and diagonalised, so the eigenstates are
+/// superpositions of the base eigenstates. The result is only accurate for levels well inside
+/// the truncated basis.
+pub struct PerturbedPotential<S: WFSignature, D> {
+    /// The unperturbed system
+    base: D,
+    /// The perturbation added to the potential
+    perturbation: WFOperation<S>,
+    /// The index of the lowest eigenstate of the base system, shared by the perturbed system
+    min_n: i32,
+    /// The eigenstates of the base system spanning the truncated basis, at `t = 0`
+    basis: Vec<Ket<S>>,
+    /// The perturbed energies, in increasing order
+    energies: Vec<f32>,
+    /// The coefficients of each perturbed eigenstate in the truncated basis
+    coefficients: Vec<Vec<Complex32>>,
+    /// The value of hbar to use.
+    hbar: f32,
+}
+
+impl<S, D> PerturbedPotential<S, D>
+where
+    S: WFSignature<Time = f32, Out = Complex32> + 'static,
+    D: DiscreteSystem<S>,
+{
+    /// Return `base` perturbed by the multiplicative operator `perturbation`, diagonalised in the
+    /// base eigenstates `min_n..min_n + basis_size`. The eigenstates of the perturbed system are
+    /// indexed from `min_n` like those of `base`. The value of `hbar` should match the one used by
+    /// `base`, and the perturbation should be independent of time.
+    ///
+    /// # Panics
+    /// Panics if `basis_size` is zero.
+    #[must_use]
+    pub fn new(
+        base: D,
+        perturbation: WFOperation<S>,
+        min_n: i32,
+        basis_size: usize,
+        step_size: S::Space,
+        hbar: f32,
+    ) -> Self {
+        assert!(
+            basis_size > 0,
+            "PerturbedPotential needs a non-empty basis."
+        );
+
+        let max_n = min_n + basis_size as i32 - 1;
+        let basis: Vec<Ket<S>> = (min_n..=max_n).map(|n| base.energy_eigenstate(n)).collect();
+        let mut hamiltonian = operator_matrix(&basis, &perturbation, 0.0, step_size);
+        for (i, n) in (min_n..=max_n).enumerate() {
+            hamiltonian[i][i] += base.energy(n);
+        }
+        let (energies, coefficients) = diagonalise_hermitian(hamiltonian);
+
+        Self {
+            base,
+            perturbation,
+            min_n,
+            basis,
+            energies,
+            coefficients,
+            hbar,
+        }
+    }
+
+    /// Return the unperturbed system
+    #[must_use]
+    pub fn base(&self) -> &D {
+        &self.base
+    }
+
+    /// Return the index into the truncated basis of the `n`th eigenstate
+    ///
+    /// # Panics
+    /// Panics if the `n`th eigenstate lies outside the truncated basis.
+    fn index(&self, n: i32) -> usize {
+        usize::try_from(n - self.min_n)
+            .ok()
+            .filter(|&i| i < self.energies.len())
+            .expect("Index of PerturbedPotential eigenstate outside the truncated basis.")
+    }
+}
+
+impl<S, D> DiscreteSystem<S> for PerturbedPotential<S, D>
+where
+    S: WFSignature<Time = f32, Out = Complex32> + 'static,
+    D: DiscreteSystem<S>,
+{
+    fn energy_eigenstate(&self, n: i32) -> Ket<S> {
+        let i = self.index(n);
+        let terms: Vec<(Complex32, Ket<S>)> = self.coefficients[i]
+            .iter()
+            .copied()
+            .zip(self.basis.iter().cloned())
+            .collect();
+        let subdomain = self.basis[0].subdomain.clone();
+        let (energy, hbar) = (self.energies[i], self.hbar);
+        Ket::new(
+            move |x, t: f32| {
+                let psi: Complex32 = terms.iter().map(|(c, ket)| c * ket.f(x, 0.0)).sum();
+                Complex32::cis(-energy * t / hbar) * psi
+            },
+            subdomain,
+        )
+    }
+
+    fn energy(&self, n: i32) -> f32 {
+        self.energies[self.index(n)]
+    }
+
+    fn potential(&self, x: S::Space) -> Option<f32> {
+        self.base
+            .potential(x)
+            .map(|v| v + self.perturbation.eval(x, 0.0).re)
+    }
+}
+
+/// Diagonalise the Hermitian matrix `h` by the cyclic Jacobi method, returning its eigenvalues
+/// in increasing order, each with the components of its normalised eigenvector.
+fn diagonalise_hermitian(mut h: Vec<Vec<Complex32>>) -> (Vec<f32>, Vec<Vec<Complex32>>) {
+    let size = h.len();
+    let mut vectors: Vec<Vec<Complex32>> = (0..size)
+        .map(|i| {
+            (0..size)
+                .map(|j| {
+                    if i == j {
+                        Complex32::ONE
+                    } else {
+                        Complex32::ZERO
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f32 = (0..size)
+            .flat_map(|p| (p + 1..size).map(move |q| (p, q)))
+            .map(|(p, q)| h[p][q].norm_sqr())
+            .sum();
+        if off_diagonal < OFF_DIAGONAL_EPSILON {
+            break;
+        }
+        for p in 0..size {
+            for q in p + 1..size {
+                let magnitude = h[p][q].norm();
+                if magnitude == 0.0 {
+                    continue;
+                }
+                // Rotate in the `(p, q)` plane, with the phase of `h_pq` absorbed into the
+                // rotation so that the new `h_pq` vanishes.
+                let phase = h[p][q] / magnitude;
+                let theta = 0.5 * (2.0 * magnitude).atan2(h[p][p].re - h[q][q].re);
+                let (s, c) = theta.sin_cos();
+                let (s_p, s_q) = (s * phase.conj(), s * phase);
+
+                for row in h.iter_mut().chain(vectors.iter_mut()) {
+                    let (a_p, a_q) = (row[p], row[q]);
+                    row[p] = c * a_p + s_p * a_q;
+                    row[q] = c * a_q - s_q * a_p;
+                }
+                let (upper, lower) = h.split_at_mut(q);
+                for (a_p, a_q) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    (*a_p, *a_q) = (c * *a_p + s_q * *a_q, c * *a_q - s_p * *a_p);
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..size).collect();
+    order.sort_by(|&i, &j| h[i][i].re.total_cmp(&h[j][j].re));
+    let energies = order.iter().map(|&i| h[i][i].re).collect();
+    let coefficients = order
+        .iter()
+        .map(|&i| vectors.iter().map(|row| row[i]).collect())
+        .collect();
+    (energies, coefficients)
+}
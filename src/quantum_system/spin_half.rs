@@ -0,0 +1,124 @@
+//! Functionality for simulating spin-1/2 particles in a uniform magnetic field.
+
+use num_complex::Complex32;
+
+use super::super::framework::{
+    braket::Ket,
+    core::domain::finite_domains::FiniteSubDomain,
+    wavefunction::{Wavefunction, signature::SigFinite},
+};
+use super::DiscreteSystem;
+
+/// A spin-1/2 particle in a uniform magnetic field, with Hamiltonian
+/// `H = -gamma B.S = -(gamma hbar / 2) B.sigma`. The spin precesses about the field
+/// with the Larmor frequency `gamma |B|`.
+pub struct SpinHalf {
+    /// The gyromagnetic ratio of the particle
+    gamma: f32,
+    /// The magnetic field vector
+    b_field: [f32; 3],
+    /// The value of hbar to use.
+    hbar: f32,
+}
+
+/// Return the spinor pointing along the direction with polar angle `theta` and azimuth `phi`
+fn spinor(theta: f32, phi: f32) -> (Complex32, Complex32) {
+    (
+        Complex32::new((theta / 2.0).cos(), 0.0),
+        Complex32::cis(phi) * (theta / 2.0).sin(),
+    )
+}
+
+/// Return the polar and azimuthal angles of a direction. The zero vector points along z.
+fn angles(direction: [f32; 3]) -> (f32, f32) {
+    let [x, y, z] = direction;
+    let r = (x * x + y * y + z * z).sqrt();
+    if r == 0.0 {
+        (0.0, 0.0)
+    } else {
+        ((z / r).acos(), y.atan2(x))
+    }
+}
+
+impl SpinHalf {
+    /// Return a new `SpinHalf` system with given parameters.
+    #[must_use]
+    pub fn new(gamma: f32, b_field: [f32; 3], hbar: f32) -> Self {
+        Self {
+            gamma,
+            b_field,
+            hbar,
+        }
+    }
+
+    /// Return the (time-independent) spin state pointing along the given direction
+    #[must_use]
+    pub fn spin_state(direction: [f32; 3]) -> Ket<SigFinite> {
+        let (theta, phi) = angles(direction);
+        let (up, down) = spinor(theta, phi);
+        Ket::new(
+            move |x: i32, _| match x {
+                0 => up,
+                _ => down,
+            },
+            FiniteSubDomain {
+                min_idx: 0,
+                max_idx: 1,
+            },
+        )
+    }
+
+    /// Return the Bloch vector `(<sigma_x>, <sigma_y>, <sigma_z>)` of a normalised spin state
+    /// at time `t`.
+    #[must_use]
+    pub fn bloch_vector(ket: &Ket<SigFinite>, t: f32) -> [f32; 3] {
+        let (up, down) = (ket.f(0, t), ket.f(1, t));
+        let coherence = 2.0 * up.conj() * down;
+        [coherence.re, coherence.im, up.norm_sqr() - down.norm_sqr()]
+    }
+}
+
+impl DiscreteSystem<SigFinite> for SpinHalf {
+    /// Return the state with spin aligned (`n = 0`) or anti-aligned (`n = 1`) with the field.
+    /// For positive `gamma`, the aligned state has the lower energy.
+    fn energy_eigenstate(&self, n: i32) -> Ket<SigFinite> {
+        assert!(
+            (0..=1).contains(&n),
+            "Index of SpinHalf eigenstate invalid. Only states 0,1 allowed."
+        );
+
+        let [x, y, z] = self.b_field;
+        let b = (x * x + y * y + z * z).sqrt();
+        let (theta, phi) = angles(self.b_field);
+        let (eigenstate, energy) = match n {
+            0 => (spinor(theta, phi), -0.5 * self.gamma * self.hbar * b),
+            _ => (
+                spinor(std::f32::consts::PI - theta, phi + std::f32::consts::PI),
+                0.5 * self.gamma * self.hbar * b,
+            ),
+        };
+        let hbar = self.hbar;
+        Ket::new(
+            move |x: i32, t: f32| {
+                Complex32::cis(-energy * t / hbar)
+                    * match x {
+                        0 => eigenstate.0,
+                        _ => eigenstate.1,
+                    }
+            },
+            FiniteSubDomain {
+                min_idx: 0,
+                max_idx: 1,
+            },
+        )
+    }
+
+    fn energy(&self, n: i32) -> f32 {
+        let [x, y, z] = self.b_field;
+        let b = (x * x + y * y + z * z).sqrt();
+        match n {
+            0 => -0.5 * self.gamma * self.hbar * b,
+            _ => 0.5 * self.gamma * self.hbar * b,
+        }
+    }
+}
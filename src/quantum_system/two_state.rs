@@ -3,7 +3,9 @@
 use num_complex::Complex32;
 
 use super::super::framework::{
-    braket::Ket, core::domain::finite_domains::FiniteSubDomain, wavefunction::signature::SigFinite,
+    braket::{AbstractBra, AbstractKet, Ket},
+    core::domain::finite_domains::FiniteSubDomain,
+    wavefunction::signature::SigFinite,
 };
 use super::DiscreteSystem;
 
@@ -31,9 +33,44 @@ impl TwoState {
             hbar,
         }
     }
+
+    /// Return the geometric (Berry) phase acquired by the `n`th eigenstate as the Hamiltonian is
+    /// carried adiabatically around a closed loop of parameters. `path` maps `s` in `[0, 1]` to
+    /// the bare level splitting `delta = level_1 - level_2` and the coupling, with
+    /// `path(1) = path(0)`, and is sampled at `steps` points around the loop.
+    ///
+    /// The eigenstate is followed by diagonalising at each point, and the phase is
+    /// `-arg(prod_k <n_k|n_{k+1}>)`. This is independent of the arbitrary phases of the
+    /// eigenstates at each point, and excludes the dynamical phase, so it is purely geometric.
+    /// For the lower eigenstate it is half the solid angle the loop subtends on the Bloch sphere
+    /// of the Hamiltonian, and minus this for the upper eigenstate, returned in `(-pi, pi]`. A
+    /// loop of the coupling phase around degenerate bare levels gives a phase of `pi`.
+    pub fn berry_phase<P: Fn(f32) -> (f32, Complex32)>(path: P, steps: usize, n: i32) -> f32 {
+        let eigenstate = |s: f32| {
+            let (delta, coupling) = path(s);
+            TwoState::new(0.5 * delta, -0.5 * delta, coupling, 1.0).energy_eigenstate(n)
+        };
+        let first = eigenstate(0.0);
+        let mut previous = first.clone();
+        let mut product = Complex32::ONE;
+        for k in 1..=steps {
+            // Close the loop on the very first eigenstate, so that its phase cancels
+            let next = if k == steps {
+                first.clone()
+            } else {
+                eigenstate(k as f32 / steps as f32)
+            };
+            product *= Ket::adjoint(&previous).apply(&next, 0.0, 1);
+            previous = next;
+        }
+        -product.arg()
+    }
 }
 
 impl DiscreteSystem<SigFinite> for TwoState {
+    /// Return the lower (`n = 0`) or upper (`n = 1`) energy eigenstate. The amplitudes are
+    /// `cos` and `sin` of a mixing angle (up to a phase), so the eigenstates are orthonormal by
+    /// construction.
     fn energy_eigenstate(&self, n: i32) -> Ket<SigFinite> {
         assert!(
             (0..=1).contains(&n),
@@ -45,7 +82,9 @@ impl DiscreteSystem<SigFinite> for TwoState {
 
         let delta = level_1 - level_2;
         let v = coupling.norm();
-        let theta = 0.5 * (2.0 * v / delta).atan();
+        // `atan2` picks the mixing angle in `[0, pi / 2]`, so the eigenstates are correct
+        // whichever bare level is lower, and when the levels are degenerate and uncoupled.
+        let theta = 0.5 * (2.0 * v).atan2(delta);
         let phase = if v == 0.0 {
             Complex32::ONE
         } else {
@@ -76,4 +115,14 @@ impl DiscreteSystem<SigFinite> for TwoState {
             },
         )
     }
+
+    fn energy(&self, n: i32) -> f32 {
+        let split =
+            ((0.5 * (self.level_1 - self.level_2)).powi(2) + self.coupling.norm_sqr()).sqrt();
+        let mean_level = 0.5 * (self.level_1 + self.level_2);
+        match n {
+            0 => mean_level - split,
+            _ => mean_level + split,
+        }
+    }
 }
@@ -0,0 +1,48 @@
+//! Checks the band structure and Bloch states of periodic potentials.
+
+use std::f32::consts::PI;
+
+use qwaviz::{
+    framework::braket::{AbstractBra, AbstractKet, Ket},
+    quantum_system::KronigPenney,
+};
+
+/// Return the Kronig-Penney potential with barriers of the given height, and all other
+/// parameters fixed
+fn lattice(barrier_height: f32) -> KronigPenney {
+    KronigPenney::new(barrier_height, 0.2, 0.8, 1.0, 1.0)
+}
+
+#[test]
+fn gaps_open_and_widen_with_barrier_height() {
+    let mut previous_gaps = [0.0; 2];
+    for barrier_height in [2.0, 8.0, 32.0] {
+        let bands = lattice(barrier_height).bands(3);
+        assert_eq!(bands.len(), 3, "barrier height {barrier_height}");
+        for (i, pair) in bands.windows(2).enumerate() {
+            let gap = pair[1].0 - pair[0].1;
+            assert!(
+                gap > previous_gaps[i],
+                "barrier height {barrier_height}: gap {i} of {gap} from {}",
+                previous_gaps[i]
+            );
+            previous_gaps[i] = gap;
+        }
+    }
+}
+
+#[test]
+fn bloch_states_are_normalised_over_a_cell() {
+    let kp = lattice(8.0);
+    let zone_edge = PI / kp.period();
+    for band in 0..3 {
+        for k in [0.0, 0.3 * zone_edge, 0.9 * zone_edge] {
+            let state = kp.bloch_state(k, band);
+            let norm_sqr = Ket::adjoint(&state).apply(&state, 0.4, 0.001).re;
+            assert!(
+                (norm_sqr - 1.0).abs() < 1e-3,
+                "band {band}, k = {k}: norm {norm_sqr}"
+            );
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Checks convolutions of sampled kets against the impulse responses of their kernels.
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::{Interp, Ket},
+    core::domain::SubDomain1D,
+    transform::Edges,
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+
+/// The number of samples of each ket
+const LEN: usize = 6;
+
+/// Return a ket sampled at `0, 1, ..., LEN - 1` which is one at `index` and zero elsewhere
+fn impulse(index: usize) -> Ket<Sign1D> {
+    let mut samples = vec![Complex32::ZERO; LEN];
+    samples[index] = Complex32::ONE;
+    Ket::from_samples(0.0, 1.0, samples, Interp::Linear)
+}
+
+/// Return the samples of the convolution of `ket` with `kernel`
+fn convolved(ket: &Ket<Sign1D>, kernel: &[Complex32], edges: Edges) -> Vec<Complex32> {
+    let result = ket.convolve(kernel, 0.0, 1.0, edges);
+    (0..LEN).map(|i| result.f(i as f32, 0.0)).collect()
+}
+
+/// Assert that two sequences of samples agree to within rounding
+fn assert_samples_eq(actual: &[Complex32], expected: &[Complex32]) {
+    assert!(
+        actual
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| (a - b).norm() < 1e-5),
+        "{actual:?}, expected {expected:?}"
+    );
+}
+
+#[test]
+fn identity_kernels_leave_kets_unchanged() {
+    let ket = impulse(2);
+    let expected: Vec<Complex32> = (0..LEN).map(|i| ket.f(i as f32, 0.0)).collect();
+    for edges in [Edges::ZeroPad, Edges::Periodic] {
+        let identity = [Complex32::ZERO, Complex32::ONE, Complex32::ZERO];
+        assert_samples_eq(&convolved(&ket, &identity, edges), &expected);
+        assert_samples_eq(&convolved(&ket, &[Complex32::ONE], edges), &expected);
+    }
+}
+
+#[test]
+fn impulses_reproduce_the_kernel_about_their_position() {
+    let kernel = [
+        Complex32::new(0.25, 0.0),
+        Complex32::new(0.5, 0.5),
+        Complex32::new(0.0, -1.0),
+    ];
+    let zero = Complex32::ZERO;
+
+    // Away from the edges, both treatments centre the kernel on the impulse
+    let expected = [zero, kernel[0], kernel[1], kernel[2], zero, zero];
+    for edges in [Edges::ZeroPad, Edges::Periodic] {
+        assert_samples_eq(&convolved(&impulse(2), &kernel, edges), &expected);
+    }
+
+    // At an edge, the part of the kernel beyond the grid is lost, or wraps around
+    let lost = [kernel[1], kernel[2], zero, zero, zero, zero];
+    assert_samples_eq(&convolved(&impulse(0), &kernel, Edges::ZeroPad), &lost);
+    let wrapped = [kernel[1], kernel[2], zero, zero, zero, kernel[0]];
+    assert_samples_eq(&convolved(&impulse(0), &kernel, Edges::Periodic), &wrapped);
+}
+
+#[test]
+fn steps_not_dividing_the_subdomain_keep_samples_in_place() {
+    // The step does not divide the subdomain, and the ket is largest at its edges
+    let subdomain = SubDomain1D {
+        lower: 0.0,
+        upper: 1.03,
+    };
+    let ket = Ket::<Sign1D>::new(|x: f32, _| Complex32::cis(4.0 * x), subdomain.clone());
+    for edges in [Edges::ZeroPad, Edges::Periodic] {
+        let result = ket.convolve(&[Complex32::ONE], 0.0, 0.1, edges);
+        for x in subdomain.uniform_points(0.1) {
+            assert!(
+                (result.f(x, 0.0) - ket.f(x, 0.0)).norm() < 1e-5,
+                "{edges:?}: psi({x}) = {}, expected {}",
+                result.f(x, 0.0),
+                ket.f(x, 0.0)
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Checks Crank-Nicolson evolution against the exactly known evolution of harmonic well states.
+
+use std::f32::consts::PI;
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, Ket},
+        core::domain::SubDomain1D,
+        evolution::CrankNicolsonEvolver,
+        wavefunction::{Wavefunction, signature::Sign1D},
+    },
+    quantum_system::{DiscreteSystem, HarmonicWell},
+};
+
+/// The spacing of the grid
+const STEP: f32 = 0.02;
+
+/// The grid the wavefunctions are evolved on
+fn grid() -> SubDomain1D<f32> {
+    SubDomain1D {
+        lower: -8.0,
+        upper: 8.0,
+    }
+}
+
+#[test]
+fn ground_state_is_stationary_and_norm_is_preserved() {
+    // A time step far beyond the stability limit of explicit methods
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let ground = hw.energy_eigenstate(1);
+    let mut evolver =
+        CrankNicolsonEvolver::new(&ground, 0.0, grid(), STEP, |x, _| 0.5 * x * x, 1.0, 1.0);
+    let state = evolver.evolve(0.05, 200);
+
+    let norm = state.norm_sqr(0.0, STEP).re;
+    assert!((norm - 1.0).abs() < 1e-3, "norm {norm}");
+    let overlap = Ket::adjoint(&ground).apply(&state, 0.0, STEP).norm_sqr();
+    assert!((overlap - 1.0).abs() < 1e-3, "|<0|psi>|^2 = {overlap}");
+}
+
+#[test]
+fn displaced_packet_oscillates() {
+    // A displaced ground state is a coherent state, whose centre follows x0 cos t
+    let packet = Ket::<Sign1D>::gaussian(2.0, 0.0, 1.0 / 2.0f32.sqrt(), Some(grid()));
+    let mut evolver =
+        CrankNicolsonEvolver::new(&packet, 0.0, grid(), STEP, |x, _| 0.5 * x * x, 1.0, 1.0);
+    let half_period = evolver.evolve(PI / 400.0, 400);
+    let x = half_period.expectation_x(0.0, STEP);
+    assert!((x + 2.0).abs() < 1e-2, "<x> = {x}, expected -2");
+}
+
+#[test]
+fn grids_not_divided_by_the_step_stay_evenly_spaced() {
+    // The step does not divide the width of the grid, whose evenly spaced points run from 0 to
+    // 1. The wavefunction vanishes one step beyond either end, so the standing wave
+    // sin(k (x + dx)) with k = pi / (1 + 2 dx) evolves as a free eigenstate with the discrete
+    // energy (1 - cos(k dx)) / dx^2 of the second difference
+    let (dx, width) = (0.05, 1.0);
+    let grid = SubDomain1D {
+        lower: 0.0,
+        upper: width + 0.4 * dx,
+    };
+    let k = PI / (width + 2.0 * dx);
+    let mode = Ket::<Sign1D>::new(
+        move |x: f32, _| Complex32::from((k * (x + dx)).sin()),
+        grid.clone(),
+    );
+    let mut evolver = CrankNicolsonEvolver::new(&mode, 0.0, grid.clone(), dx, |_, _| 0.0, 1.0, 1.0);
+    let state = evolver.evolve(0.001, 100);
+    let energy = (1.0 - (k * dx).cos()) / (dx * dx);
+    let phase = Complex32::cis(-energy * 0.1);
+    for x in grid.uniform_points(dx) {
+        let expected = mode.f(x, 0.0) * phase;
+        assert!(
+            (state.f(x, 0.0) - expected).norm() < 1e-3,
+            "psi({x}) = {}, expected {expected}",
+            state.f(x, 0.0)
+        );
+    }
+}
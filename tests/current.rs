@@ -0,0 +1,45 @@
+//! Checks the probability current of stationary and moving states.
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::Ket,
+        core::domain::SubDomain1D,
+        wavefunction::{Wavefunction, signature::Sign1D},
+    },
+    quantum_system::{DiscreteSystem, HarmonicWell},
+};
+
+/// The spacing of the central differences
+const H: f32 = 1e-3;
+
+#[test]
+fn stationary_state_carries_no_current() {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let eigenstate = hw.energy_eigenstate(3);
+    for x in [-2.0, -0.7, 0.0, 0.4, 1.5] {
+        let j = eigenstate.current(x, 0.9, 1.0, 1.0, H);
+        assert!(j.abs() < 1e-4, "j({x}) = {j}");
+    }
+}
+
+#[test]
+fn moving_packet_carries_current_along_its_velocity() {
+    // A packet with wavenumber k moves at v = hbar k / m, carrying the current j = v |psi|^2
+    let (k, hbar, mass) = (3.0, 1.0, 2.0);
+    let packet = Ket::<Sign1D>::new(
+        move |x: f32, _| (-0.5 * x * x).exp() * Complex32::cis(k * x),
+        SubDomain1D {
+            lower: -8.0,
+            upper: 8.0,
+        },
+    );
+    for x in [-1.0, 0.0, 0.5] {
+        let j = packet.current(x, 0.0, hbar, mass, H);
+        let expected = hbar * k / mass * packet.f(x, 0.0).norm_sqr();
+        assert!(
+            (j - expected).abs() < 1e-3 * expected,
+            "j({x}) = {j}, expected {expected}"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Checks the finite difference derivatives of kets against analytic derivatives.
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::Ket,
+    core::domain::SubDomain1D,
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+
+#[test]
+fn laplacian_of_sine_is_scaled_sine() {
+    // (sin kx)'' = -k^2 sin kx, up to the truncation error k^4 dx^2 / 12 of central differences
+    let k = 3.0;
+    let wave = Ket::<Sign1D>::new(
+        move |x: f32, _| Complex32::from((k * x).sin()),
+        SubDomain1D {
+            lower: -2.0,
+            upper: 2.0,
+        },
+    );
+    let laplacian = wave.laplacian(0.01);
+    for x in [-1.7, -0.5, 0.1, 0.45, 1.2] {
+        let expected = -k * k * (k * x).sin();
+        let value = laplacian.f(x, 0.0);
+        assert!(
+            (value.re - expected).abs() < 1e-2 && value.im.abs() < 1e-6,
+            "psi''({x}) = {value}, expected {expected}"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Pins the points visited when iterating over subdomains, on which integration weights rely.
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::Ket,
+    core::domain::{
+        Domain, Point2D, SubDomain, SubDomain1D, SubDomain2D,
+        finite_domains::FiniteSubDomain,
+        periodic_domains::PeriodicSubDomain1D,
+        tensor_domains::{TensorPoint, TensorSubDomain},
+    },
+    wavefunction::{
+        Wavefunction,
+        signature::{SigRing, Sign1D},
+    },
+};
+
+/// Return the points visited when iterating over `[lower, upper]` with the given step size
+fn points(lower: f32, upper: f32, step_size: f32) -> Vec<f32> {
+    SubDomain1D { lower, upper }
+        .iter_with_step_size(step_size)
+        .collect()
+}
+
+#[test]
+fn iteration_includes_both_bounds() {
+    let xs = points(0.0, 1.0, 0.1);
+    assert_eq!(xs.len(), 11);
+    assert_eq!(xs[0], 0.0);
+    assert_eq!(xs[10], 1.0);
+
+    let (subdomain, step_size) = SubDomain1D::from_points(-2.0, 3.0, 7);
+    assert_eq!(subdomain.len(step_size), 7);
+}
+
+#[test]
+fn from_points_visits_exactly_n_points() {
+    let (subdomain, step_size) = SubDomain1D::from_points(0.0, 1.0, 11);
+    assert_eq!(subdomain.len(step_size), 11);
+    let xs: Vec<f32> = subdomain.iter_with_step_size(step_size).collect();
+    assert_eq!((xs[0], xs[10]), (0.0, 1.0));
+
+    // Accumulated rounding in the step size never adds or drops a point
+    for n in [2, 3, 101, 1000, 4097] {
+        let (subdomain, step_size) = SubDomain1D::from_points(-0.3, 2.9, n);
+        assert_eq!(subdomain.len(step_size), n, "n = {n}");
+    }
+}
+
+#[test]
+fn invalid_steps_are_rejected_and_visit_no_points() {
+    for step_size in [0.0, -0.1, f32::NAN] {
+        assert!(
+            SubDomain1D::try_from_step(0.0, 1.0, step_size).is_err(),
+            "step {step_size}"
+        );
+        // Iterating regardless terminates without visiting any points
+        assert_eq!(
+            points(0.0, 1.0, step_size),
+            Vec::<f32>::new(),
+            "step {step_size}"
+        );
+    }
+    assert!(SubDomain1D::try_from_points(0.0, 1.0, 1).is_err());
+    assert!(SubDomain1D::try_from_points(1.0, 0.0, 5).is_err());
+}
+
+#[test]
+fn uneven_steps_round_to_the_nearest_point() {
+    // 1 / 0.35 rounds up to 3 steps, the last of which is clamped to the upper bound
+    let xs = points(0.0, 1.0, 0.35);
+    assert_eq!(xs.len(), 4);
+    assert_eq!(xs[3], 1.0);
+
+    // 1 / 0.3 rounds down to 3 steps, the last of which is stretched to the upper bound
+    let xs = points(0.0, 1.0, 0.3);
+    assert_eq!(xs.len(), 4);
+    assert!((xs[2] - 0.6).abs() < 1e-6);
+    assert_eq!(xs[3], 1.0);
+}
+
+#[test]
+fn weights_span_the_subdomain_for_uneven_steps() {
+    // The weights of the samples integrate 1 to the length of the subdomain, however the
+    // final panel is shortened or stretched. Steps landing half a step short of the upper bound
+    // are decided by rounding, but either way the upper bound is visited.
+    for (upper, step_size) in [
+        (1.0, 0.1),
+        (1.0, 0.3),
+        (1.0, 0.35),
+        (1.0, 0.4),
+        (1.0, 0.45),
+        (1.0, 0.7),
+        (1.0, 2.0),
+        (3.0, 0.4),
+        (0.5, 0.04),
+    ] {
+        let subdomain = SubDomain1D { lower: 0.0, upper };
+        let xs: Vec<f32> = subdomain.iter_with_step_size(step_size).collect();
+        assert_eq!(xs.last(), Some(&upper), "step {step_size}");
+        let length: f32 = xs
+            .iter()
+            .map(|&x| subdomain.quadrature_weight::<f32>(x, step_size) * step_size)
+            .sum();
+        assert!(
+            (length - upper).abs() < 1e-5,
+            "step {step_size}: {length}, expected {upper}"
+        );
+    }
+}
+
+/// Check that weighted iteration visits the points of the subdomain in order, each with its
+/// quadrature weight
+fn assert_weighted_iteration_matches<D, S>(subdomain: &S, step_size: D)
+where
+    D: Domain + std::fmt::Debug,
+    S: SubDomain<D>,
+{
+    let weighted: Vec<(D, f64)> = subdomain.iter_weighted(step_size).collect();
+    let points: Vec<D> = subdomain.iter_with_step_size(step_size).collect();
+    assert_eq!(weighted.len(), points.len());
+    for ((x, weight), &point) in weighted.into_iter().zip(&points) {
+        assert_eq!(x, point);
+        assert_eq!(
+            weight,
+            subdomain.quadrature_weight::<f64>(x, step_size),
+            "at {x:?}"
+        );
+    }
+}
+
+#[test]
+fn weighted_iteration_pairs_points_with_their_weights() {
+    for (upper, step_size) in [(1.0, 0.1), (1.0, 0.35), (1.0, 2.0), (0.5, 0.04)] {
+        assert_weighted_iteration_matches(&SubDomain1D { lower: 0.0, upper }, step_size);
+    }
+    let plane = SubDomain2D {
+        x: SubDomain1D {
+            lower: -1.0,
+            upper: 1.0,
+        },
+        y: SubDomain1D {
+            lower: 0.0,
+            upper: 0.7,
+        },
+    };
+    assert_weighted_iteration_matches(&plane, Point2D::new(0.3, 0.2));
+    let spin_and_position = TensorSubDomain(
+        SubDomain1D {
+            lower: 0.0,
+            upper: 1.0,
+        },
+        FiniteSubDomain {
+            min_idx: 0,
+            max_idx: 1,
+        },
+    );
+    assert_weighted_iteration_matches(&spin_and_position, TensorPoint(0.35, 1));
+}
+
+#[test]
+fn sampled_grids_visit_every_point_of_the_subdomain() {
+    let ket = Ket::<Sign1D>::gaussian(0.3, 1.0, 0.5, None);
+    for (lower, upper, step_size) in [(-2.0, 3.0, 0.01), (0.0, 1.0, 0.35), (-1.0, 1.0, 0.3)] {
+        let subdomain = SubDomain1D { lower, upper };
+        let (points, values) = ket.sample_grid(&subdomain, 0.0, step_size);
+        assert_eq!(points.len(), subdomain.len(step_size), "step {step_size}");
+        assert_eq!(values.len(), points.len());
+        assert_eq!((points[0], points[points.len() - 1]), (lower, upper));
+        for (&x, &value) in points.iter().zip(&values) {
+            assert_eq!(value, ket.f(x, 0.0));
+        }
+    }
+}
+
+#[test]
+fn uniform_points_are_evenly_spaced_for_uneven_steps() {
+    // A step of 0.3 does not divide [-5, 5], so iteration ends on 4.6 and then 5, while the
+    // uniform grid ends on 4.9, with the same number of points
+    let subdomain = SubDomain1D {
+        lower: -5.0,
+        upper: 5.0,
+    };
+    let uniform: Vec<f32> = subdomain.uniform_points(0.3).collect();
+    assert_eq!(uniform.len(), subdomain.len(0.3));
+    assert!((uniform[uniform.len() - 1] - 4.9).abs() < 1e-5);
+    for (i, x) in uniform.iter().enumerate() {
+        assert!(
+            (x - (-5.0 + 0.3 * i as f32)).abs() < 1e-5,
+            "point {i} at {x}"
+        );
+    }
+
+    // When the step divides the subdomain, the grids agree
+    let (subdomain, step_size) = SubDomain1D::from_points(-2.0, 3.0, 11);
+    let uniform: Vec<f32> = subdomain.uniform_points(step_size).collect();
+    let iterated: Vec<f32> = subdomain.iter_with_step_size(step_size).collect();
+    assert_eq!(uniform.len(), iterated.len());
+    for (u, x) in uniform.iter().zip(&iterated) {
+        assert!((u - x).abs() < 1e-5, "{u} and {x}");
+    }
+}
+
+#[test]
+fn periodic_points_wrap_into_one_period() {
+    let ring = PeriodicSubDomain1D { circumference: 4.0 };
+    assert!(ring.contains(-7.0) && ring.contains(9.0));
+    assert_eq!(SubDomain::wrap(&ring, 5.0), 1.0);
+    assert_eq!(SubDomain::wrap(&ring, -1.0), 3.0);
+    assert_eq!(
+        SubDomain::wrap(
+            &SubDomain1D {
+                lower: 0.0,
+                upper: 1.0
+            },
+            5.0
+        ),
+        5.0
+    );
+}
+
+#[test]
+fn translated_ring_states_reenter_from_the_other_side() {
+    // A bump on [0, 1) of a ring of length 4, pushed past the end of the period
+    let bump = Ket::<SigRing>::new(
+        |x, _| Complex32::from(if x < 1.0 { 1.0 } else { 0.0 }),
+        PeriodicSubDomain1D { circumference: 4.0 },
+    );
+    let moved = bump.clone().translate_space(3.5);
+    for x in [0.25, 3.75] {
+        assert_eq!(moved.f(x, 0.0), Complex32::from(1.0), "x = {x}");
+    }
+    for x in [1.0, 2.0, 3.0] {
+        assert_eq!(moved.f(x, 0.0), Complex32::from(0.0), "x = {x}");
+    }
+
+    // Translating by whole periods, or reflecting twice, leaves the state unchanged
+    let lapped = bump.clone().translate_space(-8.0);
+    let reflected = bump.clone().reflect_space().reflect_space();
+    for x in [0.0, 0.5, 1.5, 3.5] {
+        assert_eq!(lapped.f(x, 0.0), bump.f(x, 0.0), "x = {x}");
+        assert_eq!(reflected.f(x, 0.0), bump.f(x, 0.0), "x = {x}");
+    }
+}
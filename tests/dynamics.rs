@@ -0,0 +1,188 @@
+//! Checks the evolution of superpositions of energy eigenstates against known revivals and
+//! measurement statistics, and the geometric phases of adiabatically transported eigenstates.
+
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, Ket},
+        wavefunction::Wavefunction,
+    },
+    quantum_system::{DiscreteSystem, InfiniteSquareWell, SpinHalf, TwoState},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+/// The step size of every integral
+const STEP: f32 = 0.001;
+
+/// The `(c_n, n)` terms of a superposition of the lowest three eigenstates of a system
+const TERMS: [(Complex32, i32); 3] = [
+    (Complex32::new(0.6, 0.0), 1),
+    (Complex32::new(0.0, 0.64), 2),
+    (Complex32::new(0.48, 0.0), 3),
+];
+
+#[test]
+fn square_well_autocorrelation_revives() {
+    let isw = InfiniteSquareWell::new(1.0, 1.0, 1.0);
+    let initial = isw.superposition(&TERMS);
+    let revival = isw.revival_time();
+    let c = isw.autocorrelation(&initial, 0.0, STEP, 1, 4, &[0.0, 0.5 * revival, revival]);
+
+    assert!((c[0].norm() - 1.0).abs() < 1e-3, "|C(0)| = {}", c[0].norm());
+    assert!(c[1].norm() < 0.9, "|C(T / 2)| = {}", c[1].norm());
+    assert!((c[2].norm() - 1.0).abs() < 1e-3, "|C(T)| = {}", c[2].norm());
+}
+
+#[test]
+fn energy_measurement_frequencies_match_born_rule() {
+    let isw = InfiniteSquareWell::new(1.0, 1.0, 1.0);
+    let state = isw.superposition(&TERMS);
+    let mut rng = StdRng::seed_from_u64(3);
+    let trials = 4000;
+    let mut counts = [0; 3];
+    for _ in 0..trials {
+        let (n, _) = isw.measure_energy(&state, 0.0, 0.01, 1, 3, &mut rng);
+        counts[(n - 1) as usize] += 1;
+    }
+
+    // Each frequency lies within about four standard deviations of |c_n|^2
+    for ((c, n), count) in TERMS.iter().zip(counts) {
+        let frequency = count as f32 / trials as f32;
+        assert!(
+            (frequency - c.norm_sqr()).abs() < 0.03,
+            "state {n}: frequency {frequency}, expected {}",
+            c.norm_sqr()
+        );
+    }
+}
+
+#[test]
+fn frequent_measurements_slow_decay() {
+    let isw = InfiniteSquareWell::new(1.0, 1.0, 1.0);
+    let initial = isw.superposition(&TERMS);
+    // The survival probability only decays quadratically over times short compared with
+    // hbar / dE, which is where frequent measurements freeze the state
+    let duration = 0.03 * isw.revival_time();
+    let survival = |measurements: usize| {
+        let times: Vec<f32> = (1..=measurements)
+            .map(|i| duration * i as f32 / measurements as f32)
+            .collect();
+        isw.zeno_survival(&initial, 0.0, STEP, 1, 3, &times)
+    };
+
+    let mut previous = survival(1);
+    assert!(previous < 0.9, "survival {previous} after one measurement");
+    for measurements in [4, 16, 64] {
+        let current = survival(measurements);
+        assert!(
+            current > previous,
+            "survival {current} after {measurements} measurements, from {previous}"
+        );
+        previous = current;
+    }
+    assert!(previous > 0.95, "survival {previous} after 64 measurements");
+}
+
+#[test]
+fn sudden_expansion_preserves_norm_and_revives() {
+    // The ground state of a well of width 1, suddenly released into a well of width 2
+    let isw = InfiniteSquareWell::new(2.0, 1.0, 1.0);
+    let expanded = isw.sudden_expansion(1.0, 1, STEP, 60);
+    let initial = Ket::adjoint(&expanded);
+    let revival = isw.revival_time();
+    for t in [0.0, 0.3 * revival, 0.5 * revival, revival] {
+        let norm = expanded.norm_sqr(t, STEP).re;
+        assert!((norm - 1.0).abs() < 1e-2, "norm {norm} at t = {t}");
+    }
+
+    // The state spreads over the wider well, then returns to the narrower half at the revival
+    let overlap = |t: f32| {
+        initial
+            .apply(&expanded.clone().translate_time(t), 0.0, STEP)
+            .norm()
+    };
+    assert!(
+        overlap(0.3 * revival) < 0.9,
+        "|C(0.3 T)| = {}",
+        overlap(0.3 * revival)
+    );
+    assert!(
+        (overlap(revival) - 1.0).abs() < 1e-2,
+        "|C(T)| = {}",
+        overlap(revival)
+    );
+}
+
+#[test]
+fn seeded_measurements_are_reproducible() {
+    let isw = InfiniteSquareWell::new(1.0, 1.0, 1.0);
+    let state = isw.superposition(&TERMS);
+    let outcomes = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..20)
+            .map(|_| isw.measure_energy(&state, 0.0, 0.01, 1, 3, &mut rng).0)
+            .collect::<Vec<i32>>()
+    };
+    assert_eq!(outcomes(7), outcomes(7));
+    assert_ne!(outcomes(7), outcomes(8));
+}
+
+#[test]
+fn loop_around_degeneracy_gives_berry_phase_pi() {
+    // Winding the coupling phase around degenerate bare levels traces a great circle on the
+    // Bloch sphere, which subtends a solid angle of 2 pi
+    let around = |s: f32| (0.0, Complex32::from_polar(0.7, 2.0 * PI * s));
+    for n in [0, 1] {
+        let phase = TwoState::berry_phase(around, 200, n);
+        assert!((phase.abs() - PI).abs() < 1e-2, "state {n}: phase {phase}");
+    }
+
+    // Away from the degeneracy the loop is a cone of half-angle theta, and subtends a solid
+    // angle of 2 pi (1 - cos theta), which the lower and upper eigenstates acquire with opposite
+    // signs
+    let (delta, coupling) = (1.5, 0.7);
+    let cone = move |s: f32| (delta, Complex32::from_polar(coupling, 2.0 * PI * s));
+    let cos_theta = 0.5 * delta / (0.25 * delta * delta + coupling * coupling).sqrt();
+    let expected = PI * (1.0 - cos_theta);
+    let (lower, upper) = (
+        TwoState::berry_phase(cone, 200, 0),
+        TwoState::berry_phase(cone, 200, 1),
+    );
+    assert!(
+        (lower.abs() - expected).abs() < 1e-2,
+        "phase {lower}, expected {expected}"
+    );
+    assert!((lower + upper).abs() < 1e-3, "phases {lower} and {upper}");
+}
+
+#[test]
+fn spin_in_z_field_undergoes_larmor_precession() {
+    let (gamma, bz) = (1.3, 2.0);
+    let spin = SpinHalf::new(gamma, [0.0, 0.0, bz], 1.0);
+    let initial = SpinHalf::spin_state([1.0, 0.0, 0.0]);
+    let evolved = spin.evolution(&initial, 0.0, 1, 0, 1);
+
+    // The spin precesses in the xy-plane at the Larmor frequency gamma Bz, about -z for a
+    // positive gamma, while its component along the field is conserved
+    let omega = gamma * bz;
+    for t in [0.0, 0.3, 1.1, 2.0, 4.7] {
+        let [x, y, z] = SpinHalf::bloch_vector(&evolved, t);
+        assert!((x - (omega * t).cos()).abs() < 1e-4, "<sigma_x>({t}) = {x}");
+        assert!((y + (omega * t).sin()).abs() < 1e-4, "<sigma_y>({t}) = {y}");
+        assert!(z.abs() < 1e-4, "<sigma_z>({t}) = {z}");
+    }
+
+    // A spin tilted out of the xy-plane precesses about the field on a cone of fixed <sigma_z>
+    let tilted = spin.evolution(&SpinHalf::spin_state([1.0, 0.0, 1.0]), 0.0, 1, 0, 1);
+    let radius = FRAC_1_SQRT_2;
+    for t in [0.0, 0.3, 1.1, 2.0, 4.7] {
+        let [x, _, z] = SpinHalf::bloch_vector(&tilted, t);
+        assert!(
+            (x - radius * (omega * t).cos()).abs() < 1e-4,
+            "<sigma_x>({t}) = {x}"
+        );
+        assert!((z - radius).abs() < 1e-4, "<sigma_z>({t}) = {z}");
+    }
+}
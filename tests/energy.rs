@@ -0,0 +1,271 @@
+//! Checks the energies reported by each system: that they match the phase `e^(-iEt/hbar)` with
+//! which each eigenfunction evolves, and the energy expectations of states built from them.
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractKet, EnergyBreakdown},
+        wavefunction::{Wavefunction, signature::WFSignature},
+    },
+    quantum_system::{
+        DiscreteSystem, FiniteSquareWell, HarmonicWell, HarmonicWell2D, InfiniteSquareWell,
+        ParticleOnRing, TwoState,
+    },
+};
+
+/// The largest deviation of the evolved phase of an eigenstate from `e^(-iEt/hbar)`
+const TOL: f32 = 1e-4;
+
+/// Assert that each of the eigenstates `min_n..=max_n` evolves from `t = 0` to `t` by the phase
+/// `e^(-iEt/hbar)` of its reported energy, at every point in `xs` where it is nonzero
+fn assert_phases<S, D>(system: &D, min_n: i32, max_n: i32, hbar: f32, t: f32, xs: &[S::Space])
+where
+    S: WFSignature<Time = f32, Out = Complex32>,
+    D: DiscreteSystem<S>,
+{
+    for n in min_n..=max_n {
+        let eigenstate = system.energy_eigenstate(n);
+        let expected = Complex32::cis(-system.energy(n) * t / hbar);
+        for &x in xs {
+            let initial = eigenstate.f(x, 0.0);
+            if initial.norm() < 1e-3 {
+                continue;
+            }
+            let phase = eigenstate.f(x, t) / initial;
+            assert!(
+                (phase - expected).norm() < TOL,
+                "state {n}: phase {phase}, expected {expected}"
+            );
+        }
+    }
+}
+
+/// Return the number of states in each energy level among the lowest `count` eigenstates
+fn level_sizes<S, D: DiscreteSystem<S>>(system: &D, count: i32) -> Vec<usize>
+where
+    S: WFSignature,
+{
+    let mut sizes: Vec<(f32, usize)> = Vec::new();
+    for n in 1..=count {
+        let energy = system.energy(n);
+        match sizes.last_mut() {
+            Some((level, size)) if (energy - *level).abs() < 1e-4 => *size += 1,
+            _ => sizes.push((energy, 1)),
+        }
+    }
+    sizes.into_iter().map(|(_, size)| size).collect()
+}
+
+/// Assert that the spectrum of the levels `1..=max_n` is indexed in order, with strictly
+/// increasing energies matching `energy`
+fn assert_increasing_spectrum<S: WFSignature, D: DiscreteSystem<S>>(system: &D, max_n: i32) {
+    let spectrum = system.spectrum(1, max_n);
+    assert_eq!(spectrum.len(), max_n as usize);
+    for (i, pair) in spectrum.windows(2).enumerate() {
+        let ((n, lower, _), (m, upper, _)) = (&pair[0], &pair[1]);
+        assert_eq!((*n, *m), (i as i32 + 1, i as i32 + 2));
+        assert!(upper > lower, "E_{m} = {upper} <= E_{n} = {lower}");
+        assert_eq!(*lower, system.energy(*n));
+    }
+}
+
+#[test]
+fn harmonic_well_energies_match_phases() {
+    // E_n = hbar omega (n - 1/2), counting from the ground state at n = 1
+    let hw = HarmonicWell::new(1.5, 0.8, 1.2, 8.0);
+    assert!((hw.energy(1) - 0.5 * 1.2 * 1.5).abs() < 1e-6);
+    assert_phases(&hw, 1, 6, 1.2, 0.7, &[-1.3, -0.2, 0.4, 1.1]);
+}
+
+#[test]
+fn infinite_square_well_energies_match_phases() {
+    // E_n = n^2 pi^2 hbar^2 / (2 m L^2)
+    let isw = InfiniteSquareWell::new(2.0, 1.0, 1.0);
+    let ground = PI.powi(2) / 8.0;
+    assert!((isw.energy(3) - 9.0 * ground).abs() < 1e-5);
+    assert_phases(&isw, 1, 6, 1.0, 0.3, &[0.15, 0.7, 1.3, 1.9]);
+}
+
+#[test]
+fn finite_square_well_energies_match_phases() {
+    let fsw = FiniteSquareWell::new(20.0, 2.0, 1.0, 1.0);
+    assert_phases(
+        &fsw,
+        1,
+        fsw.bound_states(),
+        1.0,
+        0.4,
+        &[-1.7, -0.3, 0.6, 1.2],
+    );
+}
+
+#[test]
+fn particle_on_ring_energies_match_phases() {
+    let ring = ParticleOnRing::new(1.5, 1.0, 1.0);
+    assert_phases(&ring, -3, 3, 1.0, 0.9, &[0.0, 2.0, 5.0]);
+}
+
+#[test]
+fn two_state_energies_match_phases() {
+    let system = TwoState::new(0.5, -1.0, Complex32::new(0.3, 0.4), 0.8);
+    assert!(system.energy(0) < system.energy(1));
+    assert_phases(&system, 0, 1, 0.8, 1.3, &[0, 1]);
+}
+
+#[test]
+fn spectra_increase_monotonically() {
+    assert_increasing_spectrum(&HarmonicWell::new(1.5, 0.8, 1.2, 8.0), 20);
+    assert_increasing_spectrum(&InfiniteSquareWell::new(2.0, 1.0, 1.0), 20);
+}
+
+#[test]
+fn offset_shifts_energies_but_not_densities() {
+    let (v0, hbar) = (2.5, 1.2);
+    let hw = HarmonicWell::new(1.5, 0.8, hbar, 8.0);
+    let offset = HarmonicWell::new(1.5, 0.8, hbar, 8.0).with_offset(v0, hbar);
+    for n in 1..=4 {
+        assert!(
+            (offset.energy(n) - hw.energy(n) - v0).abs() < 1e-5,
+            "state {n}"
+        );
+        let (shifted, unshifted) = (offset.energy_eigenstate(n), hw.energy_eigenstate(n));
+        for (x, t) in [(-1.3, 0.0), (0.4, 0.7), (1.1, 2.3)] {
+            let (density, expected) = (shifted.f(x, t).norm_sqr(), unshifted.f(x, t).norm_sqr());
+            assert!(
+                (density - expected).abs() < 1e-5,
+                "state {n} at ({x}, {t}): |psi|^2 = {density}, expected {expected}"
+            );
+        }
+    }
+    assert_phases(&offset, 1, 4, hbar, 0.7, &[-1.3, 0.4, 1.1]);
+}
+
+#[test]
+fn isotropic_harmonic_well_2d_first_excited_level_is_doubly_degenerate() {
+    // E = hbar omega (nx + ny + 1), so (1, 0) and (0, 1) share the level 2 hbar omega
+    let (omega, hbar) = (1.5, 0.8);
+    let hw = HarmonicWell2D::new(omega, omega, 1.0, hbar, 7.0);
+    assert!((hw.energy(1) - hbar * omega).abs() < 1e-6);
+    let mut excited = [hw.quantum_numbers(2), hw.quantum_numbers(3)];
+    excited.sort_unstable();
+    assert_eq!(excited, [(0, 1), (1, 0)]);
+    for n in [2, 3] {
+        assert!(
+            (hw.energy(n) - 2.0 * hbar * omega).abs() < 1e-6,
+            "state {n}"
+        );
+    }
+    assert!((hw.energy(4) - 3.0 * hbar * omega).abs() < 1e-6);
+}
+
+#[test]
+fn harmonic_well_2d_degeneracy_depends_on_anisotropy() {
+    // The level hbar omega (k + 1) of the isotropic well holds the k + 1 states with nx + ny = k
+    let isotropic = HarmonicWell2D::new(1.2, 1.2, 1.0, 1.0, 7.0);
+    assert_eq!(level_sizes(&isotropic, 10), [1, 2, 3, 4]);
+    // Frequencies in the ratio 1 : 2 only make (nx, ny) degenerate with (nx - 2, ny + 1), so the
+    // levels grow every other step
+    let commensurate = HarmonicWell2D::new(1.2, 2.4, 1.0, 1.0, 7.0);
+    assert_eq!(level_sizes(&commensurate, 9), [1, 1, 2, 2, 3]);
+    // Incommensurate frequencies lift every degeneracy
+    let incommensurate = HarmonicWell2D::new(1.2, 1.2 * 2.0f32.sqrt(), 1.0, 1.0, 7.0);
+    assert_eq!(level_sizes(&incommensurate, 10), [1; 10]);
+}
+
+#[test]
+fn harmonic_well_2d_states_are_indexed_by_energy_then_decreasing_nx() {
+    for (omega_x, omega_y) in [
+        (1.2, 1.2),
+        (1.2, 2.4),
+        (1.2, 1.2 * 2.0f32.sqrt()),
+        (2.5, 0.7),
+    ] {
+        let hw = HarmonicWell2D::new(omega_x, omega_y, 1.0, 1.0, 7.0);
+        let count = 60;
+        let mut expected: Vec<(i32, i32)> = (0..count)
+            .flat_map(|nx| (0..count).map(move |ny| (nx, ny)))
+            .collect();
+        expected.sort_by(|&(ax, ay), &(bx, by)| {
+            hw.energy_xy(ax, ay)
+                .total_cmp(&hw.energy_xy(bx, by))
+                .then(bx.cmp(&ax))
+        });
+        for (n, &state) in (1..=count).zip(&expected) {
+            assert_eq!(
+                hw.quantum_numbers(n),
+                state,
+                "state {n} of {omega_x}, {omega_y}"
+            );
+        }
+    }
+}
+
+#[test]
+fn harmonic_eigenstates_satisfy_virial_theorem() {
+    // For V ~ x^2, <T> = <V> = E_n / 2, which is hbar omega / 4 in the ground state
+    let (mass, omega, hbar) = (0.7, 1.3, 0.8);
+    let hw = HarmonicWell::new(omega, mass, hbar, 10.0);
+    for n in 1..=4 {
+        let state = hw.energy_eigenstate(n);
+        let breakdown =
+            EnergyBreakdown::new(&state, mass, hbar, |x| 0.5 * mass * (omega * x).powi(2));
+        let (kinetic, potential) = (
+            breakdown.kinetic(0.0, 0.005),
+            breakdown.potential(0.0, 0.005),
+        );
+        let expected = 0.5 * hbar * omega * (n as f32 - 0.5);
+        assert!(
+            (kinetic - expected).abs() < 1e-3 && (potential - expected).abs() < 1e-3,
+            "state {n}: <T> = {kinetic}, <V> = {potential}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn superposition_energy_is_weighted_average() {
+    let (mass, omega, hbar) = (1.0, 1.0, 1.0);
+    let hw = HarmonicWell::new(omega, mass, hbar, 10.0);
+    let terms = [
+        (Complex32::from(0.6), 1),
+        (Complex32::new(0.0, 0.48), 2),
+        (Complex32::from(0.64), 4),
+    ];
+    let state = hw.superposition(&terms);
+    let energy = EnergyBreakdown::new(&state, mass, hbar, |x| 0.5 * mass * (omega * x).powi(2))
+        .total(0.0, 0.005);
+    let expected: f32 = terms
+        .iter()
+        .map(|(c, n)| c.norm_sqr() * hw.energy(*n))
+        .sum();
+    assert!(
+        (energy - expected).abs() < 1e-3,
+        "<E> = {energy}, expected {expected}"
+    );
+}
+
+#[test]
+fn barely_bound_states_have_finite_subdomains() {
+    // A well of strength z0 = sqrt(2 V0) with unit width and mass binds a second state once z0
+    // passes pi / 2, but one bound by less than the bisection can resolve is not counted
+    let well = |z0: f32| FiniteSquareWell::new(0.5 * z0 * z0, 2.0, 1.0, 1.0);
+    assert_eq!(well(FRAC_PI_2 + 5e-5).bound_states(), 1);
+    let z0 = FRAC_PI_2 + 1e-3;
+    let fsw = well(z0);
+    assert_eq!(fsw.bound_states(), 2);
+    for n in 1..=2 {
+        let state = fsw.energy_eigenstate(n);
+        let (lower, upper) = (state.subdomain.lower, state.subdomain.upper);
+        assert!(
+            lower.is_finite() && upper.is_finite() && upper <= 21.0,
+            "state {n} on [{lower}, {upper}]"
+        );
+        assert!(fsw.energy(n) < 0.5 * z0 * z0, "state {n}");
+        let norm = state.norm_sqr(0.0, 0.01).re;
+        assert!(
+            norm.is_finite() && norm <= 1.0 + 1e-3,
+            "state {n}: norm {norm}"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Checks the momentum space representation of gaussian wavepackets against its known form.
+
+use std::f32::consts::PI;
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::{AbstractBra, AbstractKet, Ket},
+    core::domain::SubDomain1D,
+    wavefunction::Wavefunction,
+};
+
+/// The step size at which positions are sampled for the transform
+const STEP: f32 = 0.01;
+
+#[test]
+fn gaussian_transforms_to_minimum_uncertainty_gaussian() {
+    let (x0, k0, sigma) = (0.8, 2.5, 0.6);
+    // A wide subdomain finely resolves wavenumbers, as dk = 2 pi / (N dx)
+    let subdomain = SubDomain1D {
+        lower: x0 - 40.0 * sigma,
+        upper: x0 + 40.0 * sigma,
+    };
+    let packet = Ket::gaussian(x0, k0, sigma, Some(subdomain));
+    let momentum = packet.to_momentum_space(0.0, STEP);
+
+    // The transform is unitary, and centred on k0 with the width 1 / (2 sigma). Wavenumbers
+    // between samples are linearly interpolated, which loses a little of the norm.
+    let norm = Ket::adjoint(&momentum).apply(&momentum, 0.0, STEP).re;
+    assert!((norm - 1.0).abs() < 5e-3, "norm {norm}");
+    let mean = momentum.expectation_x(0.0, STEP);
+    assert!((mean - k0).abs() < 5e-3, "<k> = {mean}");
+    let sigma_k = momentum.uncertainty_x(0.0, STEP);
+    let product = sigma * sigma_k;
+    assert!((product - 0.5).abs() < 5e-3, "sigma_x sigma_k = {product}");
+
+    // The density is the gaussian |phi(k)|^2 = exp(-(k - k0)^2 / 2 sigma_k^2) / sqrt(2 pi sigma_k^2)
+    let expected_sigma_k = 0.5 / sigma;
+    for k in [k0 - 1.5, k0 - 0.4, k0, k0 + 0.9] {
+        let density = momentum.f(k, 0.0).norm_sqr();
+        let expected = (-(k - k0).powi(2) / (2.0 * expected_sigma_k.powi(2))).exp()
+            / (2.0 * PI * expected_sigma_k.powi(2)).sqrt();
+        assert!(
+            (density - expected).abs() < 5e-3,
+            "|phi({k})|^2 = {density}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn steps_not_dividing_the_subdomain_transform_evenly_spaced_samples() {
+    // The subdomain spans 20.3 steps, so the samples cover 21 evenly spaced points and a plane
+    // wave on one of the transformed wavenumbers is held in a single sample of the transform
+    let (step, n) = (0.1, 21);
+    let subdomain = SubDomain1D {
+        lower: -1.0,
+        upper: 1.03,
+    };
+    let dk = 2.0 * PI / (n as f32 * step);
+    let k = 3.0 * dk;
+    let wave = Ket::new(move |x, _| Complex32::cis(k * x), subdomain);
+    let momentum = wave.to_momentum_space(0.0, step);
+
+    // All N samples add in phase, giving |phi(k)| = N dx / sqrt(2 pi)
+    let peak = momentum.f(k, 0.0).norm();
+    let expected = n as f32 * step / (2.0 * PI).sqrt();
+    assert!(
+        (peak - expected).abs() < 1e-3,
+        "|phi({k})| = {peak}, expected {expected}"
+    );
+    for j in [-2.0, -1.0, 1.0, 2.0] {
+        let leaked = momentum.f(k + j * dk, 0.0).norm();
+        assert!(leaked < 1e-3 * peak, "|phi({})| = {leaked}", k + j * dk);
+    }
+}
+
+#[test]
+fn gaussian_with_uneven_step_is_centred_on_k0_with_reciprocal_width() {
+    let (x0, k0, sigma) = (0.4, -1.5, 0.5);
+    let subdomain = SubDomain1D {
+        lower: x0 - 30.0 * sigma,
+        upper: x0 + 30.0 * sigma,
+    };
+    let packet = Ket::gaussian(x0, k0, sigma, Some(subdomain));
+    // 0.013 does not divide the width of the subdomain
+    let momentum = packet.to_momentum_space(0.0, 0.013);
+
+    let mean = momentum.expectation_x(0.0, STEP);
+    assert!((mean - k0).abs() < 5e-3, "<k> = {mean}");
+    let product = sigma * momentum.uncertainty_x(0.0, STEP);
+    assert!((product - 0.5).abs() < 5e-3, "sigma_x sigma_k = {product}");
+}
+
+#[test]
+fn momentum_wavefunction_uses_hbar_convention() {
+    let (x0, k0, sigma, hbar) = (-0.3, 1.5, 0.5, 0.6);
+    let subdomain = SubDomain1D {
+        lower: x0 - 40.0 * sigma,
+        upper: x0 + 40.0 * sigma,
+    };
+    let packet = Ket::gaussian(x0, k0, sigma, Some(subdomain));
+
+    // |phi(p)|^2 is a gaussian centred on hbar k0 with the width sigma_p = hbar / (2 sigma)
+    let (p0, sigma_p) = (hbar * k0, 0.5 * hbar / sigma);
+    let all = SubDomain1D {
+        lower: p0 - 10.0 * sigma_p,
+        upper: p0 + 10.0 * sigma_p,
+    };
+    let momentum = packet.to_momentum_space_p(0.0, STEP, hbar, all);
+    let norm = momentum.norm_sqr(0.0, 0.1 * STEP).re;
+    assert!((norm - 1.0).abs() < 5e-3, "norm {norm}");
+    let product = sigma * momentum.uncertainty_x(0.0, 0.1 * STEP);
+    assert!(
+        (product - 0.5 * hbar).abs() < 5e-3,
+        "sigma_x sigma_p = {product}"
+    );
+    for p in [p0 - 0.8, p0 - 0.2, p0, p0 + 0.5] {
+        let density = momentum.f(p, 0.0).norm_sqr();
+        let expected = (-(p - p0).powi(2) / (2.0 * sigma_p.powi(2))).exp()
+            / (2.0 * PI * sigma_p.powi(2)).sqrt();
+        assert!(
+            (density - expected).abs() < 5e-3,
+            "|phi({p})|^2 = {density}, expected {expected}"
+        );
+    }
+
+    // Momenta above p0 hold half of the probability, and none is found outside of p_domain
+    let above = SubDomain1D {
+        lower: p0,
+        upper: p0 + 10.0 * sigma_p,
+    };
+    let upper_half = packet.to_momentum_space_p(0.0, STEP, hbar, above);
+    let probability = upper_half.norm_sqr(0.0, 0.1 * STEP).re;
+    assert!(
+        (probability - 0.5).abs() < 5e-3,
+        "P(p > p0) = {probability}"
+    );
+    assert_eq!(upper_half.f(p0 - 0.2, 0.0).norm(), 0.0);
+}
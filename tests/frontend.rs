@@ -0,0 +1,39 @@
+//! Checks that the frontend spawns wavefunctions with the physical constants it is given, without
+//! opening a window.
+
+use bevy::{
+    asset::AssetPlugin,
+    pbr::StandardMaterial,
+    prelude::{App, AssetApp, Mesh, MinimalPlugins, Startup},
+};
+use bevy_polyline::prelude::{Polyline, PolylineMaterial};
+use qwaviz::{
+    frontend::{PhysicalParams, add_viz_1d},
+    quantum_system::{DiscreteSystem, HarmonicWell},
+};
+
+#[test]
+fn viz_1d_threads_params_to_the_wavefunction() {
+    let params = PhysicalParams {
+        mass: 2.5,
+        hbar: 0.4,
+    };
+    let ket = HarmonicWell::new(1.0, params.mass, params.hbar, 5.0).energy_eigenstate(1);
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Polyline>()
+        .init_asset::<PolylineMaterial>();
+    add_viz_1d(&mut app, ket, params);
+    app.world_mut().run_schedule(Startup);
+
+    let world = app.world_mut();
+    let spawned: Vec<PhysicalParams> = world
+        .query::<&PhysicalParams>()
+        .iter(world)
+        .copied()
+        .collect();
+    assert_eq!(spawned, vec![params]);
+}
@@ -0,0 +1,41 @@
+//! Checks matrix elements of multiplicative operators against the known ladder structure of the
+//! harmonic well.
+
+use std::sync::Arc;
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{WFOperation, operator_matrix},
+        wavefunction::signature::Sign1D,
+    },
+    quantum_system::{DiscreteSystem, HarmonicWell},
+};
+
+#[test]
+fn harmonic_position_matrix_couples_neighbouring_states() {
+    let (omega, mass, hbar) = (1.3, 0.7, 0.8);
+    let hw = HarmonicWell::new(omega, mass, hbar, 10.0);
+    let basis: Vec<_> = (1..=6).map(|n| hw.energy_eigenstate(n)).collect();
+    let position = WFOperation::<Sign1D>::func(Arc::new(|x, _| Complex32::from(x)));
+    let matrix = operator_matrix(&basis, &position, 0.0, 0.005);
+
+    // Counting from the ground state at n = 1, |<n|x|n + 1>| = sqrt(n hbar / (2 m omega)), and x
+    // couples no other pair of states
+    let length = (hbar / (mass * omega)).sqrt();
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, element) in row.iter().enumerate() {
+            let expected = if i.abs_diff(j) == 1 {
+                length * (i.max(j) as f32 / 2.0).sqrt()
+            } else {
+                0.0
+            };
+            assert!(
+                (element.norm() - expected).abs() < 1e-3 && element.im.abs() < 1e-3,
+                "<{}|x|{}> = {element}, expected magnitude {expected}",
+                i + 1,
+                j + 1
+            );
+        }
+    }
+}
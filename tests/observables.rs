@@ -0,0 +1,92 @@
+//! Checks position and momentum statistics of eigenstates against their known values.
+
+use std::f32::consts::PI;
+
+use qwaviz::{
+    framework::{braket::AbstractKet, core::domain::SubDomain1D},
+    quantum_system::{DeltaWell, DiscreteSystem, HarmonicWell, InfiniteSquareWell},
+};
+
+/// The step size of every integral
+const STEP: f32 = 0.005;
+
+#[test]
+fn harmonic_ground_state_saturates_heisenberg_bound() {
+    let hbar = 0.8;
+    let hw = HarmonicWell::new(1.3, 0.7, hbar, 10.0);
+    let ground = hw.energy_eigenstate(1);
+    let product = ground.uncertainty_x(0.4, STEP) * ground.uncertainty_p(0.4, STEP, hbar);
+    assert!(
+        (product - 0.5 * hbar).abs() < 1e-3,
+        "dx dp = {product}, expected {}",
+        0.5 * hbar
+    );
+
+    // The nth eigenstate has dx dp = (n - 1/2) hbar
+    for n in 2..=5 {
+        let state = hw.energy_eigenstate(n);
+        let product = state.uncertainty_x(0.0, STEP) * state.uncertainty_p(0.0, STEP, hbar);
+        let expected = (n as f32 - 0.5) * hbar;
+        assert!(product > 0.5 * hbar, "state {n}: dx dp = {product}");
+        assert!(
+            (product - expected).abs() < 5e-3,
+            "state {n}: dx dp = {product}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn restricted_norm_is_probability_in_region() {
+    // The ground state of a unit square well is found in [0, a] with probability
+    // a - sin(2 pi a) / (2 pi)
+    let ground = InfiniteSquareWell::new(1.0, 1.0, 1.0).energy_eigenstate(1);
+    for a in [0.25, 0.5, 0.8] {
+        let region = SubDomain1D {
+            lower: 0.0,
+            upper: a,
+        };
+        let probability = ground.clone().restrict(region).norm_sqr(0.3, 0.001).re;
+        let expected = a - (2.0 * PI * a).sin() / (2.0 * PI);
+        assert!(
+            (probability - expected).abs() < 1e-4,
+            "P(0 < x < {a}) = {probability}, expected {expected}"
+        );
+    }
+
+    // Regions beyond the well hold no probability, and the harmonic ground state is even
+    let outside = SubDomain1D {
+        lower: 1.5,
+        upper: 3.0,
+    };
+    assert_eq!(ground.restrict(outside).norm_sqr(0.0, 0.001).re, 0.0);
+    let ground = HarmonicWell::new(1.3, 0.7, 0.8, 10.0).energy_eigenstate(1);
+    let right = SubDomain1D {
+        lower: 0.0,
+        upper: 10.0,
+    };
+    let probability = ground.restrict(right).norm_sqr(0.0, STEP).re;
+    assert!((probability - 0.5).abs() < 1e-3, "P(x > 0) = {probability}");
+}
+
+#[test]
+fn recommended_subdomains_hold_almost_all_probability() {
+    let hw = HarmonicWell::new(1.3, 0.7, 0.8, 20.0);
+    for n in 1..=10 {
+        let region = hw.recommended_subdomain(n);
+        let captured = hw
+            .energy_eigenstate(n)
+            .restrict(region)
+            .norm_sqr(0.0, STEP)
+            .re;
+        assert!(captured >= 0.99, "state {n}: captured {captured}");
+    }
+
+    let delta = DeltaWell::new(1.5, 1.0, 1.0, 20.0);
+    let region = delta.recommended_subdomain(1);
+    let captured = delta
+        .energy_eigenstate(1)
+        .restrict(region)
+        .norm_sqr(0.0, STEP)
+        .re;
+    assert!(captured >= 0.99, "captured {captured}");
+}
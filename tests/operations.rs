@@ -0,0 +1,191 @@
+//! Checks the values and structure of the wavefunction expression trees built by operations on
+//! kets, and that rewriting them preserves their values.
+
+use std::sync::Arc;
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::{AbstractKet, Bra, Ket, WFOperation},
+    core::{domain::SubDomain1D, vectorspace::VectorSpace},
+    wavefunction::{Wavefunction, signature::Sign1D},
+};
+
+/// Return the `k`th of a family of distinct wavefunctions
+fn term(k: usize) -> impl Fn(f32, f32) -> Complex32 + Send + Sync + 'static {
+    move |x, t| Complex32::new((k as f32 * x).sin(), x * t - k as f32)
+}
+
+#[test]
+fn simplify_flattens_chained_sums_without_changing_values() {
+    let subdomain = SubDomain1D {
+        lower: -1.0,
+        upper: 1.0,
+    };
+    for n in [2, 5, 20, 100] {
+        let chained = (1..n).fold(Ket::<Sign1D>::new(term(0), subdomain.clone()), |acc, k| {
+            acc + Ket::new(term(k), subdomain.clone())
+        });
+        let simplified = chained.clone().simplify();
+        for (x, t) in [(-0.8, 0.0), (0.1, 0.5), (0.7, 2.0)] {
+            let (before, after) = (chained.f(x, t), simplified.f(x, t));
+            assert!(
+                (before - after).norm() < 1e-4 * before.norm().max(1.0),
+                "{n} terms at ({x}, {t}): {before} became {after}"
+            );
+        }
+
+        // A chain of n terms is n deep until flattened into a single sum. Scalings and negations
+        // of the terms stay on each term of the flattened sum.
+        let op = |k| WFOperation::<Sign1D>::func(Arc::new(term(k)));
+        let chained = (1..n).fold(op(0), |acc, k| acc + op(k));
+        assert_eq!(chained.depth(), n, "{n} terms");
+        assert_eq!(chained.simplify().depth(), 2, "{n} terms");
+
+        let chained = (1..n).fold(op(0), |acc, k| match k % 2 {
+            0 => acc + WFOperation::scale(Complex32::new(0.5, k as f32), op(k)),
+            _ => acc + -op(k),
+        });
+        assert_eq!(chained.depth(), n + 1, "{n} terms");
+        assert_eq!(chained.simplify().depth(), 3, "{n} terms");
+    }
+}
+
+#[test]
+fn debug_output_counts_the_terms_of_sums() {
+    let op = |k| WFOperation::<Sign1D>::func(Arc::new(term(k)));
+    let superposition =
+        WFOperation::weighted_sum((0..50).map(|k| (Complex32::new(0.1, 0.0), op(k))).collect());
+    assert_eq!(format!("{superposition:?}"), "WeightedSum(50 terms)");
+
+    let nested = WFOperation::product(-superposition, WFOperation::sum(vec![op(0), op(1)]));
+    assert_eq!(
+        format!("{nested:?}"),
+        "Product(Neg(WeightedSum(50 terms)), Sum(2 terms))"
+    );
+}
+
+#[test]
+fn weighted_sums_sharing_a_subdomain_match_the_general_sum() {
+    let subdomain = SubDomain1D {
+        lower: -1.0,
+        upper: 1.0,
+    };
+    // The last summand of the general sum lies within the others, so combining the subdomains
+    // recovers the shared one
+    let inner = SubDomain1D {
+        lower: -1.0,
+        upper: 0.5,
+    };
+    let weights = [
+        Complex32::new(0.6, 0.0),
+        Complex32::new(0.0, -0.3),
+        Complex32::new(1.2, 0.5),
+    ];
+    let summands = |last: &SubDomain1D<f32>| {
+        (0..3)
+            .map(|k| {
+                let domain = if k == 2 { last } else { &subdomain };
+                (weights[k], Ket::<Sign1D>::new(term(k), domain.clone()))
+            })
+            .collect::<Vec<_>>()
+    };
+    let shared = Ket::weighted_sum(summands(&subdomain));
+    let general = Ket::weighted_sum(summands(&inner));
+    assert_eq!(shared.subdomain, subdomain);
+    assert_eq!(shared.subdomain, general.subdomain);
+
+    let bra_summands = |last: &SubDomain1D<f32>| {
+        summands(last)
+            .into_iter()
+            .map(|(c, ket)| (c, Ket::adjoint(&ket)))
+            .collect::<Vec<_>>()
+    };
+    let shared_bra = Bra::weighted_sum(bra_summands(&subdomain));
+    let general_bra = Bra::weighted_sum(bra_summands(&inner));
+    assert_eq!(format!("{shared_bra:?}"), format!("{general_bra:?}"));
+
+    for (x, t) in [(-1.5, 0.0), (-0.8, 0.0), (0.1, 0.5), (0.7, 2.0), (1.2, 1.0)] {
+        assert_eq!(shared.f(x, t), general.f(x, t), "ket at ({x}, {t})");
+        assert_eq!(shared_bra.f(x, t), general_bra.f(x, t), "bra at ({x}, {t})");
+    }
+}
+
+#[test]
+fn maps_apply_pointwise_and_compose() {
+    let subdomain = SubDomain1D {
+        lower: -1.0,
+        upper: 1.0,
+    };
+    let ket = Ket::<Sign1D>::new(term(3), subdomain);
+    let square = |z: Complex32| z * z;
+    let shift = |z: Complex32| z + Complex32::new(0.5, -1.0);
+    let mapped = ket.clone().map(square);
+    let chained = ket.clone().map(square).map(shift);
+    let composed = ket.clone().map(move |z| shift(square(z)));
+    for (x, t) in [(-0.8, 0.0), (0.1, 0.5), (0.7, 2.0)] {
+        let value = ket.f(x, t);
+        assert!((mapped.f(x, t) - value * value).norm() < 1e-5, "({x}, {t})");
+        assert!(
+            (chained.f(x, t) - composed.f(x, t)).norm() < 1e-5,
+            "({x}, {t})"
+        );
+    }
+}
+
+#[test]
+fn successive_translations_collapse_into_one() {
+    let op = || WFOperation::<Sign1D>::func(Arc::new(term(2)));
+    let space = WFOperation::translate_space(0.5, WFOperation::translate_space(-0.2, op()));
+    let time = WFOperation::translate_time(0.4, WFOperation::translate_time(1.1, op()));
+    assert_eq!(format!("{space:?}"), "TranslateSpace(Function)");
+    assert_eq!(format!("{time:?}"), "TranslateTime(Function)");
+    assert_eq!((space.depth(), time.depth()), (2, 2));
+
+    // Translating twice is the same as translating once by the summed offset
+    let subdomain = SubDomain1D {
+        lower: -1.0,
+        upper: 1.0,
+    };
+    let ket = Ket::<Sign1D>::new(term(2), subdomain);
+    let twice = ket.clone().translate_space(-0.2).translate_space(0.5);
+    let once = ket.clone().translate_space(0.3);
+    let (later, sooner) = (
+        ket.clone().translate_time(1.1).translate_time(0.4),
+        ket.translate_time(1.5),
+    );
+    for (x, t) in [(-0.6, 0.0), (0.1, 0.5), (0.9, 2.0)] {
+        assert!((twice.f(x, t) - once.f(x, t)).norm() < 1e-5, "({x}, {t})");
+        assert!((later.f(x, t) - sooner.f(x, t)).norm() < 1e-5, "({x}, {t})");
+    }
+}
+
+#[test]
+fn checked_kets_replace_non_finite_values_with_zero() {
+    let subdomain = SubDomain1D {
+        lower: 0.0,
+        upper: 1.0,
+    };
+    // A unit function, except for a band of NaNs around x = 0.5
+    let poisoned = Ket::<Sign1D>::new(
+        |x: f32, _| {
+            if (x - 0.5).abs() < 0.01 {
+                Complex32::new(f32::NAN, 0.0)
+            } else {
+                Complex32::ONE
+            }
+        },
+        subdomain,
+    );
+    assert!(poisoned.f(0.5, 0.0).re.is_nan());
+    assert!(poisoned.norm_sqr(0.0, 0.001).re.is_nan());
+
+    let checked = poisoned.checked();
+    assert_eq!(checked.f(0.5, 0.0), Complex32::ZERO);
+    assert_eq!(checked.f(0.2, 0.0), Complex32::ONE);
+    // Only the band of NaNs is lost from the integral
+    let norm_sqr = checked.norm_sqr(0.0, 0.001);
+    assert!(
+        norm_sqr.is_finite() && (norm_sqr.re - 0.98).abs() < 2e-3,
+        "norm {norm_sqr}"
+    );
+}
@@ -0,0 +1,304 @@
+//! Checks that the energy eigenstates of each solvable system form an orthonormal basis, and
+//! that states are recovered from their expansions in it.
+
+use num_complex::{Complex32, Complex64};
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, Ket, gram_schmidt},
+        core::{
+            domain::{Point2D, SubDomain1D},
+            vectorspace::VectorSpace,
+        },
+        wavefunction::signature::Sign1D,
+    },
+    quantum_system::{
+        DiscreteSystem, HarmonicWell, HarmonicWell2D, HydrogenRadial, InfiniteSquareWell,
+        ParticleOnRing, TwoState,
+    },
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// The largest deviation of an inner product of eigenstates from its expected value
+const TOL: f32 = 1e-3;
+
+/// Assert that `<m|n>` is within `TOL` of `1` for `m = n` and of `0` otherwise, for every pair of
+/// the eigenstates `min_n..=max_n` at time `t`
+fn assert_orthonormal<S, D>(system: &D, min_n: i32, max_n: i32, t: f32, step_size: S::Space)
+where
+    S: qwaviz::framework::wavefunction::signature::WFSignature<Time = f32, Out = Complex32>,
+    D: DiscreteSystem<S>,
+{
+    for m in min_n..=max_n {
+        let bra = Ket::adjoint(&system.energy_eigenstate(m));
+        for n in min_n..=max_n {
+            let overlap = bra.apply(&system.energy_eigenstate(n), t, step_size);
+            let expected = if m == n {
+                Complex32::ONE
+            } else {
+                Complex32::ZERO
+            };
+            assert!(
+                (overlap - expected).norm() < TOL,
+                "<{m}|{n}> = {overlap}, expected {expected}"
+            );
+        }
+    }
+}
+
+/// Return a gaussian wavepacket with a random centre, width and momentum, normalised on the
+/// given subdomain
+fn random_packet(rng: &mut StdRng, subdomain: SubDomain1D<f32>, step_size: f32) -> Ket<Sign1D> {
+    let centre = rng.random_range(0.3..0.7) * (subdomain.upper - subdomain.lower) + subdomain.lower;
+    let width = rng.random_range(0.05..0.15) * (subdomain.upper - subdomain.lower);
+    let momentum = rng.random_range(-3.0..3.0);
+    let packet = Ket::<Sign1D>::new(
+        move |x: f32, _| {
+            (-(0.5 * (x - centre) / width).powi(2)).exp() * Complex32::cis(momentum * x)
+        },
+        subdomain,
+    );
+    let norm = packet.norm_sqr(0.0, step_size).re.sqrt();
+    packet.map(move |psi| psi / norm)
+}
+
+#[test]
+fn harmonic_well_is_orthonormal() {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 10.0);
+    assert_orthonormal(&hw, 1, 12, 0.0, 0.01);
+    assert_orthonormal(&hw, 1, 12, 1.3, 0.01);
+}
+
+#[test]
+fn natural_systems_are_orthonormal() {
+    // The natural harmonic well has energies n - 1/2
+    let (hw, step_size) = HarmonicWell::natural(8, 2001);
+    assert_orthonormal(&hw, 1, 8, 0.0, step_size);
+    for n in 1..=8 {
+        let expected = n as f32 - 0.5;
+        assert!(
+            (hw.energy(n) - expected).abs() < 1e-6,
+            "E_{n} = {}",
+            hw.energy(n)
+        );
+    }
+
+    let (isw, step_size) = InfiniteSquareWell::natural(2.0, 2001);
+    assert_orthonormal(&isw, 1, 8, 0.0, step_size);
+}
+
+#[test]
+fn harmonic_well_is_orthonormal_in_f64() {
+    // Double precision samples, weights and sums hold orthonormality to within rounding error,
+    // far more tightly than single precision can
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 10.0);
+    for m in 1..=12 {
+        let bra = Ket::adjoint(&hw.energy_eigenstate_f64(m));
+        for n in 1..=12 {
+            let overlap = bra.apply(&hw.energy_eigenstate_f64(n), 1.3, 0.01);
+            let expected = if m == n { 1.0 } else { 0.0 };
+            assert!(
+                (overlap - expected).norm() < 1e-12,
+                "<{m}|{n}> = {overlap}, expected {expected}"
+            );
+        }
+    }
+
+    let state = hw.energy_eigenstate_f64(3);
+    let normalised = state
+        .clone()
+        .scale(Complex64::new(0.0, 3.0))
+        .normalize(0.2, 0.01);
+    let norm_sqr = normalised.norm_sqr(0.2, 0.01).re;
+    assert!((norm_sqr - 1.0).abs() < 1e-12, "norm {norm_sqr}");
+    assert!(
+        normalised.approx_eq(&state.scale(Complex64::I), 0.2, 0.01, 1e-12),
+        "normalising changed more than the norm"
+    );
+}
+
+#[test]
+fn harmonic_well_2d_is_orthonormal() {
+    // Inner products over the 2D grid are weighted by the area element dx dy
+    let hw = HarmonicWell2D::new(1.0, 1.5, 1.0, 1.0, 7.0).with_rotation(0.4);
+    assert_orthonormal(&hw, 1, 6, 0.8, Point2D::new(0.04, 0.04));
+    assert_orthonormal(&hw, 1, 3, 0.0, Point2D::new(0.02, 0.05));
+}
+
+#[test]
+fn infinite_square_well_is_orthonormal() {
+    let isw = InfiniteSquareWell::new(2.0, 1.0, 1.0);
+    assert_orthonormal(&isw, 1, 12, 0.0, 0.001);
+    assert_orthonormal(&isw, 1, 12, 0.7, 0.001);
+}
+
+#[test]
+fn particle_on_ring_is_orthonormal() {
+    // The plane waves e^(i n x / R) are orthonormal over one period of length 2 pi R
+    let ring = ParticleOnRing::new(1.5, 1.0, 1.0);
+    assert_orthonormal(&ring, -4, 4, 0.0, 0.001);
+    assert_orthonormal(&ring, -4, 4, 0.6, 0.001);
+}
+
+#[test]
+fn two_state_is_orthonormal() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..10 {
+        let system = TwoState::new(
+            rng.random_range(-2.0..2.0),
+            rng.random_range(-2.0..2.0),
+            Complex32::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)),
+            1.0,
+        );
+        assert_orthonormal(&system, 0, 1, 0.4, 1);
+    }
+}
+
+#[test]
+fn two_state_sums_ignore_step_size() {
+    let system = TwoState::new(0.5, -1.0, Complex32::new(0.3, 0.4), 1.0);
+    for step_size in [1, 2, 5] {
+        assert_orthonormal(&system, 0, 1, 0.4, step_size);
+    }
+}
+
+#[test]
+fn hydrogen_radial_is_orthonormal() {
+    let s_states = HydrogenRadial::new(1.0, 1.0, 1.0);
+    assert_orthonormal(&s_states, 1, 4, 0.0, 0.005);
+    let p_states = HydrogenRadial::new(1.0, 1.0, 1.0).with_l(1);
+    assert_orthonormal(&p_states, 2, 4, 0.0, 0.005);
+}
+
+#[test]
+fn harmonic_well_expansion_converges() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 10.0);
+    let subdomain = SubDomain1D {
+        lower: -4.0,
+        upper: 4.0,
+    };
+    for _ in 0..3 {
+        let packet = random_packet(&mut rng, subdomain.clone(), 0.01);
+        let captured: Vec<f32> = [5, 15, 40]
+            .iter()
+            .map(|&max_n| hw.evolution_checked(&packet, 0.0, 0.01, 1, max_n).1)
+            .collect();
+        assert!(
+            captured.windows(2).all(|pair| pair[0] <= pair[1] + TOL),
+            "captured norm {captured:?} does not increase with the basis size"
+        );
+        assert!(
+            (captured[2] - 1.0).abs() < 1e-2,
+            "captured norm {captured:?} does not approach 1"
+        );
+    }
+}
+
+#[test]
+fn infinite_square_well_expansion_converges() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let isw = InfiniteSquareWell::new(2.0, 1.0, 1.0);
+    let subdomain = SubDomain1D {
+        lower: 0.0,
+        upper: 2.0,
+    };
+    for _ in 0..3 {
+        let packet = random_packet(&mut rng, subdomain.clone(), 0.001);
+        let captured: Vec<f32> = [5, 15, 40]
+            .iter()
+            .map(|&max_n| isw.evolution_checked(&packet, 0.0, 0.001, 1, max_n).1)
+            .collect();
+        assert!(
+            captured.windows(2).all(|pair| pair[0] <= pair[1] + TOL),
+            "captured norm {captured:?} does not increase with the basis size"
+        );
+        assert!(
+            (captured[2] - 1.0).abs() < 1e-2,
+            "captured norm {captured:?} does not approach 1"
+        );
+    }
+}
+
+#[test]
+fn eigenstates_decompose_into_unit_vectors() {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 10.0);
+    for n in 1..=5 {
+        let coefficients = hw.decompose(&hw.energy_eigenstate(n), 0.6, 0.01, 1, 8);
+        for (m, c) in (1..=8).zip(coefficients) {
+            // |n(t0)> has the coefficient e^(-i E_n t0 / hbar) in |n>, of unit modulus
+            let expected = if m == n { 1.0 } else { 0.0 };
+            assert!(
+                (c.norm() - expected).abs() < TOL,
+                "<{m}|{n}> = {c}, expected modulus {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn two_state_expansion_is_complete() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let system = TwoState::new(0.5, -1.0, Complex32::new(0.3, 0.4), 1.0);
+    for _ in 0..10 {
+        let (a, b) = (
+            Complex32::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)),
+            Complex32::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)),
+        );
+        let state = Ket::new(
+            move |x: i32, _| if x == 0 { a } else { b },
+            system.energy_eigenstate(0).subdomain,
+        );
+        let (_, captured) = system.evolution_checked(&state, 0.0, 1, 0, 1);
+        assert!((captured - 1.0).abs() < TOL, "captured norm {captured}");
+    }
+}
+
+#[test]
+fn gram_schmidt_orthonormalises_and_drops_dependent_kets() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let subdomain = SubDomain1D {
+        lower: -4.0,
+        upper: 4.0,
+    };
+    let mut kets: Vec<Ket<Sign1D>> = (0..5)
+        .map(|_| random_packet(&mut rng, subdomain.clone(), 0.01))
+        .collect();
+    // A combination of earlier kets adds nothing to their span
+    kets.push(kets[0].clone() + kets[2].clone().scale(Complex32::new(0.0, 2.0)));
+
+    let basis = gram_schmidt(kets, 0.0, 0.01);
+    assert_eq!(basis.len(), 5);
+    for (i, bra) in basis.iter().enumerate() {
+        let bra = Ket::adjoint(bra);
+        for (j, ket) in basis.iter().enumerate() {
+            let overlap = bra.apply(ket, 0.0, 0.01);
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!(
+                (overlap - expected).norm() < TOL,
+                "<{i}|{j}> = {overlap}, expected {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn projecting_out_the_ground_state_leaves_no_overlap() {
+    let isw = InfiniteSquareWell::new(1.0, 1.0, 1.0);
+    let terms = [
+        (Complex32::new(0.6, 0.0), 1),
+        (Complex32::new(0.0, 0.64), 2),
+        (Complex32::new(0.48, 0.0), 3),
+    ];
+    let state = isw.superposition(&terms);
+    // The basis ket need not be normalised
+    let ground = isw.energy_eigenstate(1).scale(Complex32::new(0.0, 2.0));
+    let projected = state.project_out(&ground, 0.0, 0.001);
+
+    let overlap = Ket::adjoint(&ground).apply(&projected, 0.0, 0.001);
+    assert!(overlap.norm() < TOL, "<1|psi'> = {overlap}");
+    // The other components are untouched
+    for (c, n) in &terms[1..] {
+        let component = Ket::adjoint(&isw.energy_eigenstate(*n)).apply(&projected, 0.0, 0.001);
+        assert!((component - c).norm() < TOL, "<{n}|psi'> = {component}");
+    }
+}
@@ -0,0 +1,81 @@
+//! Checks perturbed systems, diagonalised in a truncated basis, against perturbation theory and
+//! exactly solvable perturbations.
+
+use std::sync::Arc;
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, Ket, WFOperation},
+        wavefunction::signature::Sign1D,
+    },
+    quantum_system::{DiscreteSystem, HarmonicWell, PerturbedPotential},
+};
+
+/// The step size of every integral
+const STEP: f32 = 0.01;
+
+/// The parameters `(omega, mass, hbar)` of the harmonic well being perturbed
+const WELL: (f32, f32, f32) = (1.2, 0.9, 1.0);
+
+/// Return the harmonic well being perturbed
+fn base() -> HarmonicWell {
+    let (omega, mass, hbar) = WELL;
+    HarmonicWell::new(omega, mass, hbar, 10.0)
+}
+
+/// Return the harmonic well perturbed by `v(x)`, diagonalised in its lowest 12 eigenstates
+fn perturbed(
+    v: impl Fn(f32) -> f32 + Send + Sync + 'static,
+) -> PerturbedPotential<Sign1D, HarmonicWell> {
+    let perturbation = WFOperation::func(Arc::new(move |x: f32, _| Complex32::from(v(x))));
+    PerturbedPotential::new(base(), perturbation, 1, 12, STEP, WELL.2)
+}
+
+#[test]
+fn linear_perturbation_shifts_harmonic_levels() {
+    // lambda x has no first order shift, as <n|x|n> = 0, and completing the square shows the
+    // levels are all shifted by exactly -lambda^2 / (2 m omega^2)
+    let (omega, mass, _) = WELL;
+    let lambda = 0.1;
+    let system = perturbed(move |x| lambda * x);
+    let shift = -lambda * lambda / (2.0 * mass * omega * omega);
+    for n in 1..=4 {
+        let expected = base().energy(n) + shift;
+        assert!(
+            (system.energy(n) - expected).abs() < 1e-4,
+            "E_{n} = {}, expected {expected}",
+            system.energy(n)
+        );
+    }
+}
+
+#[test]
+fn perturbed_eigenstates_are_orthonormal() {
+    let system = perturbed(|x| 0.3 * x + 0.05 * x.powi(4));
+    for m in 1..=4 {
+        let bra = Ket::adjoint(&system.energy_eigenstate(m));
+        for n in 1..=4 {
+            let overlap = bra.apply(&system.energy_eigenstate(n), 0.5, STEP);
+            let expected = if m == n { 1.0 } else { 0.0 };
+            assert!((overlap - expected).norm() < 1e-3, "<{m}|{n}> = {overlap}");
+        }
+    }
+}
+
+#[test]
+fn constant_perturbation_keeps_the_unperturbed_basis() {
+    // A constant is diagonal in any basis, so diagonalising leaves the eigenstates unchanged and
+    // shifts each level by the constant
+    let system = perturbed(|_| 0.7);
+    for n in 1..=6 {
+        assert!(
+            (system.energy(n) - base().energy(n) - 0.7).abs() < 1e-4,
+            "E_{n}"
+        );
+        let overlap = Ket::adjoint(&base().energy_eigenstate(n))
+            .apply(&system.energy_eigenstate(n), 0.0, STEP)
+            .norm();
+        assert!((overlap - 1.0).abs() < 1e-3, "|<{n}|{n}'>| = {overlap}");
+    }
+}
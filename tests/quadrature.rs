@@ -0,0 +1,227 @@
+//! Checks that the selectable quadrature rules converge at their expected orders, and that
+//! adaptive integration resolves sharp integrands cheaply.
+
+use std::{
+    f32::consts::PI,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, IntegrationMethod, Ket},
+        core::domain::{Point2D, SubDomain1D, SubDomain2D},
+        wavefunction::{
+            Wavefunction,
+            signature::{Sign1D, Sign2D},
+        },
+    },
+    quantum_system::{DiscreteSystem, InfiniteSquareWell},
+};
+
+#[test]
+fn constants_integrate_to_value_times_measure() {
+    let value = Complex32::new(2.0, -0.5);
+    let line = SubDomain1D {
+        lower: -0.3,
+        upper: 1.7,
+    };
+    let ket = Ket::<Sign1D>::new(move |_, _| value, line.clone());
+    // Including steps which do not divide the subdomain
+    for step in [0.01, 0.3, 0.35, 0.7] {
+        let integral = ket.integrate(0.0, step);
+        assert!(
+            (integral - 2.0 * value).norm() < 1e-5,
+            "step {step}: {integral}"
+        );
+    }
+
+    let rectangle = SubDomain2D {
+        x: line.clone(),
+        y: SubDomain1D {
+            lower: 0.0,
+            upper: 3.0,
+        },
+    };
+    let ket = Ket::<Sign2D>::new(move |_, _| value, rectangle);
+    let integral = ket.integrate(0.0, Point2D::new(0.05, 0.4));
+    assert!((integral - 6.0 * value).norm() < 1e-4, "{integral}");
+}
+
+#[test]
+fn rules_improve_on_non_vanishing_boundaries() {
+    // psi(x) = x on [0, 1] has <psi|psi> = 1/3, and is largest at a boundary, so the rules differ
+    let ket = Ket::<Sign1D>::new(
+        |x: f32, _| Complex32::new(x, 0.0),
+        SubDomain1D {
+            lower: 0.0,
+            upper: 1.0,
+        },
+    );
+    let bra = Ket::adjoint(&ket);
+    let error = |method| (bra.apply_with(&ket, 0.0, 0.1, method) - 1.0 / 3.0).norm();
+
+    let riemann = error(IntegrationMethod::Riemann);
+    let trapezoidal = error(IntegrationMethod::Trapezoidal);
+    let simpson = error(IntegrationMethod::Simpson);
+    assert!(trapezoidal < 0.1 * riemann, "{trapezoidal} vs {riemann}");
+    // Simpson's rule is exact for the quadratic |psi|^2, up to rounding
+    assert!(simpson < 1e-5, "{simpson}");
+}
+
+#[test]
+fn riemann_sums_sample_left_edges_of_panels() {
+    let subdomain = SubDomain1D {
+        lower: 0.0,
+        upper: 1.0,
+    };
+    let ket = Ket::<Sign1D>::new(|x: f32, _| Complex32::new(x, 0.0), subdomain.clone());
+    // The left sum of x^2 over ten panels is 0.1^3 (0 + 1 + ... + 81) = 0.285, without x = 1
+    let riemann = Ket::adjoint(&ket).apply_with(&ket, 0.0, 0.1, IntegrationMethod::Riemann);
+    assert!((riemann.re - 0.285).abs() < 1e-5, "{riemann}");
+
+    // A step of 0.3 leaves a short final panel [0.9, 1], which is weighted by its true width
+    let unit = Ket::<Sign1D>::new(|_, _| Complex32::ONE, subdomain);
+    let riemann = Ket::adjoint(&unit).apply_with(&unit, 0.0, 0.3, IntegrationMethod::Riemann);
+    assert!((riemann.re - 1.0).abs() < 1e-5, "{riemann}");
+}
+
+#[test]
+fn trapezoidal_rule_converges_faster_than_riemann_sums() {
+    // Half of the square well holds half of the probability of its ground state. The density
+    // peaks at the centre of the well, and none of the steps divide the half width.
+    let half = SubDomain1D {
+        lower: 0.0,
+        upper: 0.5,
+    };
+    let ket = InfiniteSquareWell::new(1.0, 1.0, 1.0)
+        .energy_eigenstate(1)
+        .restrict(half);
+    let trapezoidal = |step_size| (ket.norm_sqr(0.0, step_size).re - 0.5).abs();
+    // The left Riemann sum formerly used by `apply`, which misses part of the final panel
+    let riemann = |step_size: f32| {
+        let sum: f32 = (0..)
+            .map(|k| k as f32 * step_size)
+            .take_while(|&x| x < 0.5)
+            .map(|x| ket.p(x, 0.0).re)
+            .sum();
+        (sum * step_size - 0.5).abs()
+    };
+
+    for step_size in [0.03, 0.015, 0.0075] {
+        assert!(
+            trapezoidal(step_size) < 0.01 * riemann(step_size),
+            "step {step_size}: {} vs {}",
+            trapezoidal(step_size),
+            riemann(step_size)
+        );
+    }
+    // Quartering the step gains far more accuracy than the factor of 4 of the Riemann sum
+    assert!(trapezoidal(0.0075) < 0.1 * trapezoidal(0.03));
+    assert!(riemann(0.0075) > 0.2 * riemann(0.03));
+}
+
+#[test]
+fn adaptive_integration_resolves_sharp_packets_with_fewer_samples() {
+    // A normalised gaussian packet of width 0.01 on a subdomain 4000 times wider, which counts
+    // how many times it is evaluated
+    let sigma: f32 = 0.01;
+    let evaluations = Arc::new(AtomicUsize::new(0));
+    let counter = evaluations.clone();
+    let amplitude = (2.0 * PI * sigma.powi(2)).powf(-0.25);
+    let packet = Ket::<Sign1D>::new(
+        move |x: f32, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Complex32::from(amplitude * (-x.powi(2) / (4.0 * sigma.powi(2))).exp())
+        },
+        SubDomain1D {
+            lower: -20.0,
+            upper: 20.0,
+        },
+    );
+    let counted = |norm_sqr: &dyn Fn() -> f32| {
+        evaluations.store(0, Ordering::Relaxed);
+        let error = (norm_sqr() - 1.0).abs();
+        (error, evaluations.load(Ordering::Relaxed))
+    };
+
+    let (adaptive_error, adaptive_evaluations) = counted(&|| packet.norm_sqr_adaptive(0.0, 1e-4));
+    assert!(adaptive_error < 1e-4, "adaptive error {adaptive_error}");
+
+    // The uniform rule only resolves the peak to the same accuracy with a step about the width of
+    // the packet, spending most of its samples in the empty tails
+    let uniform = |step| counted(&|| Ket::adjoint(&packet).apply(&packet, 0.0, step).re);
+    let (coarse_error, coarse_evaluations) = uniform(0.02);
+    assert!(
+        coarse_error > 1e-4,
+        "uniform error {coarse_error} at step 0.02"
+    );
+    assert!(coarse_evaluations > 4 * adaptive_evaluations);
+    let (fine_error, fine_evaluations) = uniform(0.01);
+    assert!(fine_error < 1e-4, "uniform error {fine_error} at step 0.01");
+    assert!(
+        fine_evaluations > 10 * adaptive_evaluations,
+        "{fine_evaluations} uniform vs {adaptive_evaluations} adaptive evaluations"
+    );
+}
+
+#[test]
+fn adaptive_integration_terminates_when_tolerance_cannot_be_met() {
+    // A ket which counts its evaluations, and gives up long before an unbounded subdivision would
+    let evaluations = Arc::new(AtomicUsize::new(0));
+    let counted_ket = |value: fn(f32) -> Complex32, subdomain| {
+        let counter = evaluations.clone();
+        Ket::<Sign1D>::new(
+            move |x: f32, _| {
+                let count = counter.fetch_add(1, Ordering::Relaxed);
+                assert!(count < 10_000_000, "adaptive integration did not terminate");
+                value(x)
+            },
+            subdomain,
+        )
+    };
+
+    // Panels of a subdomain only a few dozen floats wide cannot be split for long, so even a
+    // tolerance of zero is reached
+    let (lower, upper): (f32, f32) = (1.0, 1.0 + 1e-5);
+    let narrow = counted_ket(Complex32::from, SubDomain1D { lower, upper });
+    let norm_sqr = narrow.norm_sqr_adaptive(0.0, 0.0);
+    let expected = (upper.powi(3) - lower.powi(3)) / 3.0;
+    assert!(
+        (norm_sqr - expected).abs() < 1e-9,
+        "norm squared {norm_sqr}, expected {expected}"
+    );
+    assert!(evaluations.load(Ordering::Relaxed) < 1_000);
+
+    // Over a wide subdomain, a tolerance of zero is only met once the estimates agree to rounding,
+    // or the subdivision reaches its maximum depth
+    evaluations.store(0, Ordering::Relaxed);
+    let wide = counted_ket(
+        |x| Complex32::from(x.exp()),
+        SubDomain1D {
+            lower: 0.0,
+            upper: 1.0,
+        },
+    );
+    let norm_sqr = wide.norm_sqr_adaptive(0.0, 0.0);
+    assert!(
+        (norm_sqr - 0.5 * (2.0f32.exp() - 1.0)).abs() < 1e-4,
+        "norm squared {norm_sqr}"
+    );
+    assert!(evaluations.load(Ordering::Relaxed) < 10_000);
+
+    // Non-finite values make for a non-finite error estimate, which halving never reduces
+    evaluations.store(0, Ordering::Relaxed);
+    let singular = counted_ket(
+        |x| Complex32::from(x.recip()),
+        SubDomain1D {
+            lower: 0.0,
+            upper: 1.0,
+        },
+    );
+    assert!(!singular.norm_sqr_adaptive(0.0, 1e-4).is_finite());
+    assert!(evaluations.load(Ordering::Relaxed) < 10_000);
+}
@@ -0,0 +1,92 @@
+//! Checks RK4 evolution against the exactly known evolution of harmonic well states, and the
+//! absorption of packets at absorbing edges.
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, Ket},
+        core::domain::SubDomain1D,
+        evolution::RK4Evolver,
+        wavefunction::{Wavefunction, signature::Sign1D},
+    },
+    quantum_system::{DiscreteSystem, HarmonicWell},
+};
+
+/// The spacing of the grid
+const STEP: f32 = 0.05;
+
+/// A time step within the stability limit `1.4 m dx^2 / hbar` for unit mass and hbar
+const DT: f32 = 0.002;
+
+/// The grid the wavefunctions are evolved on
+fn grid() -> SubDomain1D<f32> {
+    SubDomain1D {
+        lower: -8.0,
+        upper: 8.0,
+    }
+}
+
+/// Return the evolver of `initial` from `t = 0` in the unit harmonic well
+fn harmonic_evolver(initial: &Ket<Sign1D>) -> RK4Evolver {
+    RK4Evolver::new(initial, 0.0, grid(), STEP, |x, _| 0.5 * x * x, 1.0, 1.0)
+}
+
+#[test]
+fn ground_state_is_stationary_and_norm_is_preserved() {
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let ground = hw.energy_eigenstate(1);
+    let mut evolver = harmonic_evolver(&ground);
+    for _ in 0..1000 {
+        evolver.step(DT);
+    }
+    let state = evolver.state();
+
+    let norm = state.norm_sqr(0.0, STEP).re;
+    assert!((norm - 1.0).abs() < 1e-3, "norm {norm}");
+    let overlap = Ket::adjoint(&ground).apply(&state, 0.0, STEP).norm_sqr();
+    assert!((overlap - 1.0).abs() < 1e-3, "|<0|psi>|^2 = {overlap}");
+}
+
+#[test]
+fn absorbing_edges_remove_outgoing_packets() {
+    // A free packet moving right has reached the edge of the grid by t = 3, and would reflect off it
+    let packet = Ket::<Sign1D>::gaussian(0.0, 3.0, 0.7, Some(grid()));
+    let new_evolver = || RK4Evolver::new(&packet, 0.0, grid(), STEP, |_, _| 0.0, 1.0, 1.0);
+    let mut reflecting = new_evolver();
+    let mut absorbing = new_evolver().with_absorbing_edges(2.0, 5.0);
+    for _ in 0..3000 {
+        reflecting.step(DT);
+        absorbing.step(DT);
+    }
+
+    let reflected = reflecting.state().norm_sqr(0.0, STEP).re;
+    assert!((reflected - 1.0).abs() < 1e-2, "reflected norm {reflected}");
+    let absorbed = absorbing.state().norm_sqr(0.0, STEP).re;
+    assert!(absorbed < 0.05, "absorbed norm {absorbed}");
+}
+
+#[test]
+fn grids_not_divided_by_the_step_stay_evenly_spaced() {
+    // The step does not divide the width of the grid, and the wave is largest at its edges
+    let grid = SubDomain1D {
+        lower: 0.0,
+        upper: 1.03,
+    };
+    let wave = Ket::<Sign1D>::new(
+        |x: f32, _| Complex32::cis(4.0 * x),
+        SubDomain1D {
+            lower: -1.0,
+            upper: 2.0,
+        },
+    );
+    let evolver = RK4Evolver::new(&wave, 0.0, grid.clone(), 0.1, |_, _| 0.0, 1.0, 1.0);
+    let state = evolver.state();
+    for x in grid.uniform_points(0.1) {
+        assert!(
+            (state.f(x, 0.0) - wave.f(x, 0.0)).norm() < 1e-5,
+            "psi({x}) = {}, expected {}",
+            state.f(x, 0.0),
+            wave.f(x, 0.0)
+        );
+    }
+}
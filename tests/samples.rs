@@ -0,0 +1,54 @@
+//! Checks that kets built from samples reproduce and interpolate their samples.
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::{Interp, Ket},
+    wavefunction::Wavefunction,
+};
+
+/// The position of the first sample
+const LOWER: f32 = -0.5;
+
+/// The spacing between samples
+const STEP: f32 = 0.25;
+
+/// Return the samples of the ket under test
+fn samples() -> Vec<Complex32> {
+    vec![
+        Complex32::new(1.0, 0.0),
+        Complex32::new(-2.0, 0.5),
+        Complex32::new(0.5, 3.0),
+        Complex32::new(0.0, -1.0),
+    ]
+}
+
+#[test]
+fn samples_are_reproduced_on_the_grid() {
+    for interp in [Interp::Linear, Interp::Nearest] {
+        let ket = Ket::from_samples(LOWER, STEP, samples(), interp);
+        for (i, value) in samples().into_iter().enumerate() {
+            let x = LOWER + i as f32 * STEP;
+            assert!((ket.f(x, 0.7) - value).norm() < 1e-6, "{interp:?} at {x}");
+        }
+        // The ket vanishes outside of the samples
+        assert_eq!(ket.f(LOWER - STEP, 0.0), Complex32::ZERO);
+        assert_eq!(ket.f(LOWER + 4.0 * STEP, 0.0), Complex32::ZERO);
+    }
+}
+
+#[test]
+fn linear_interpolation_is_linear_between_samples() {
+    let ket = Ket::from_samples(LOWER, STEP, samples(), Interp::Linear);
+    for (i, pair) in samples().windows(2).enumerate() {
+        let left = LOWER + i as f32 * STEP;
+        for frac in [0.25, 0.5, 0.9] {
+            let expected = pair[0] * (1.0 - frac) + pair[1] * frac;
+            let value = ket.f(left + frac * STEP, 0.0);
+            assert!(
+                (value - expected).norm() < 1e-5,
+                "between samples {i} and {}: {value}, expected {expected}",
+                i + 1
+            );
+        }
+    }
+}
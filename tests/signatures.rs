@@ -0,0 +1,103 @@
+//! Checks the inner products and dimensions of kets of each wavefunction signature.
+
+use std::{f32::consts::PI, sync::Arc};
+
+use num_complex::Complex32;
+use qwaviz::framework::{
+    braket::{AbstractBra, AbstractKet, Bra, Ket, tensor},
+    core::domain::{SubDomain1D, tensor_domains::TensorPoint},
+    wavefunction::{
+        Wavefunction,
+        signature::{
+            SigFinite, SigRing, Sign1D, Sign1DF64, Sign1DReal, Sign2D, SignRadial, TensorSignature,
+            WFSignature,
+        },
+    },
+};
+
+#[test]
+fn real_string_mode_has_norm_one_half() {
+    // The fundamental mode sin(pi x) of a string on [0, 1] has <psi|psi> = 1/2, with no
+    // conjugation needed
+    let mode = Ket::<Sign1DReal>::new(
+        |x: f32, _| (PI * x).sin(),
+        SubDomain1D {
+            lower: 0.0,
+            upper: 1.0,
+        },
+    );
+    let norm_sqr: f32 = mode.norm_sqr(0.0, 0.001);
+    assert!((norm_sqr - 0.5).abs() < 1e-4, "norm {norm_sqr}");
+}
+
+#[test]
+fn product_state_norm_is_product_of_norms() {
+    // <a|a> = 1/3 and <b|b> = 26/3, neither of which is normalised
+    let a = Ket::<Sign1D>::new(
+        |x: f32, _| Complex32::from(x),
+        SubDomain1D {
+            lower: 0.0,
+            upper: 1.0,
+        },
+    );
+    let b = Ket::<Sign1D>::new(
+        |y: f32, t: f32| Complex32::cis(y - t) * (1.0 + y),
+        SubDomain1D {
+            lower: 0.0,
+            upper: 2.0,
+        },
+    );
+    let expected = a.norm_sqr(0.3, 0.001).re * b.norm_sqr(0.3, 0.001).re;
+    assert!(
+        (expected - 26.0 / 9.0).abs() < 1e-3,
+        "<a|a><b|b> = {expected}"
+    );
+
+    let norm_sqr = tensor(a, b).norm_sqr(0.3, TensorPoint(0.01, 0.01)).re;
+    assert!(
+        (norm_sqr - expected).abs() < 1e-3 * expected,
+        "<ab|ab> = {norm_sqr}, expected {expected}"
+    );
+}
+
+#[test]
+fn radial_inner_product_matches_integral_of_product() {
+    // The r^2 volume element must enter the inner product once, whether or not the sum over the
+    // domain is parallel
+    let subdomain = SubDomain1D {
+        lower: 0.0,
+        upper: 30.0,
+    };
+    let a = |r: f32| Complex32::from((-r).exp());
+    let b = |r: f32| Complex32::new(1.0, r) * (-0.5 * r).exp();
+    let bra = Ket::<SignRadial>::new(move |r, _| a(r), subdomain.clone()).to_adjoint();
+    let ket = Ket::<SignRadial>::new(move |r, _| b(r), subdomain.clone());
+    let product = Bra::<SignRadial>::new(Arc::new(move |r, _| a(r).conj() * b(r)), subdomain);
+
+    // The integral of r^2 (1 + ir) e^(-3r/2) is 2 / 1.5^3 + 6i / 1.5^4
+    let expected = Complex32::new(2.0 / 1.5f32.powi(3), 6.0 / 1.5f32.powi(4));
+    let (applied, integrated) = (bra.apply(&ket, 0.0, 0.001), product.integrate(0.0, 0.001));
+    assert!(
+        (applied - integrated).norm() < 1e-5,
+        "<a|b> = {applied}, integral of a* b = {integrated}"
+    );
+    assert!(
+        (applied - expected).norm() < 1e-4,
+        "<a|b> = {applied}, expected {expected}"
+    );
+}
+
+#[test]
+fn signatures_report_their_spatial_dimensions() {
+    assert_eq!(Sign1D::SPATIAL_DIMS, 1);
+    assert_eq!(Sign1DF64::SPATIAL_DIMS, 1);
+    assert_eq!(Sign1DReal::SPATIAL_DIMS, 1);
+    assert_eq!(SigRing::SPATIAL_DIMS, 1);
+    assert_eq!(SignRadial::SPATIAL_DIMS, 1);
+    assert_eq!(Sign2D::SPATIAL_DIMS, 2);
+    // Discrete domains are summed over, so have no continuous dimensions
+    assert_eq!(SigFinite::SPATIAL_DIMS, 0);
+    // Tensor products add the dimensions of their factors
+    assert_eq!(TensorSignature::<Sign1D, Sign2D>::SPATIAL_DIMS, 3);
+    assert_eq!(TensorSignature::<Sign1D, SigFinite>::SPATIAL_DIMS, 1);
+}
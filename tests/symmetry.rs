@@ -0,0 +1,77 @@
+//! Checks parity, reflections and dilations of kets against the symmetries of solvable systems.
+
+use num_complex::Complex32;
+use qwaviz::{
+    framework::{
+        braket::{AbstractBra, AbstractKet, Ket},
+        core::vectorspace::VectorSpace,
+        wavefunction::{Wavefunction, signature::Sign1D},
+    },
+    quantum_system::{DiscreteSystem, InfiniteSquareWell},
+};
+
+/// The step size of every integral
+const STEP: f32 = 0.001;
+
+/// The width of the square well
+const WIDTH: f32 = 2.0;
+
+/// Return the `n`th eigenstate of the square well, translated so that the well is centred on the
+/// origin
+fn centred_eigenstate(n: i32) -> Ket<Sign1D> {
+    InfiniteSquareWell::new(WIDTH, 1.0, 1.0)
+        .energy_eigenstate(n)
+        .translate_space(-0.5 * WIDTH)
+}
+
+#[test]
+fn centred_square_well_eigenstates_alternate_parity() {
+    for n in 1..=6 {
+        let parity = centred_eigenstate(n).parity(0.3, STEP);
+        let expected = if n % 2 == 1 { 1.0 } else { -1.0 };
+        assert!(
+            (parity.re - expected).abs() < 1e-3 && parity.im.abs() < 1e-3,
+            "state {n}: parity {parity}"
+        );
+    }
+}
+
+#[test]
+fn even_and_odd_parts_split_a_mixed_state() {
+    // The even part of a mix of the ground and first excited states is the ground state alone
+    let (ground, excited) = (centred_eigenstate(1), centred_eigenstate(2));
+    let mixed = ground.clone() + excited.clone();
+    let (even, odd) = (mixed.clone().even_part(), mixed.odd_part());
+    for x in [-0.7, -0.1, 0.4, 0.9] {
+        assert!((even.f(x, 0.0) - ground.f(x, 0.0)).norm() < 1e-5, "x = {x}");
+        assert!((odd.f(x, 0.0) - excited.f(x, 0.0)).norm() < 1e-5, "x = {x}");
+    }
+    assert!(Ket::adjoint(&even).apply(&odd, 0.0, STEP).norm() < 1e-3);
+}
+
+#[test]
+fn reflection_negates_odd_eigenstates() {
+    let odd = centred_eigenstate(2);
+    let reflected = odd.clone().reflect_space();
+    for x in [-0.8, -0.3, 0.2, 0.65] {
+        assert!(
+            (reflected.f(x, 0.4) + odd.f(x, 0.4)).norm() < 1e-5,
+            "x = {x}"
+        );
+    }
+}
+
+#[test]
+fn dilation_with_amplitude_factor_preserves_norm() {
+    let ground = centred_eigenstate(1);
+    let factor: f32 = 2.5;
+    let dilated = ground.scale_space(factor);
+    // Stretching alone scales the norm by the factor, which the amplitude factor undoes
+    let stretched = dilated.norm_sqr(0.0, STEP).re;
+    assert!((stretched - factor).abs() < 1e-3, "norm {stretched}");
+    let normalised = dilated
+        .scale(Complex32::from(factor.powf(-0.5)))
+        .norm_sqr(0.0, STEP)
+        .re;
+    assert!((normalised - 1.0).abs() < 1e-3, "norm {normalised}");
+}
@@ -0,0 +1,52 @@
+//! Checks the Wigner functions of harmonic oscillator eigenstates against their known forms.
+
+use std::f32::consts::PI;
+
+use qwaviz::quantum_system::{DiscreteSystem, HarmonicWell};
+
+/// The step size of the integral over `y`
+const STEP: f32 = 0.01;
+
+/// Return `n` evenly spaced points from `-extent` to `extent`
+fn grid(extent: f32, n: usize) -> Vec<f32> {
+    (0..n)
+        .map(|i| -extent + 2.0 * extent * i as f32 / (n - 1) as f32)
+        .collect()
+}
+
+#[test]
+fn harmonic_ground_state_is_positive_gaussian() {
+    // With m = omega = hbar = 1, the ground state has W(x, p) = exp(-x^2 - p^2) / pi
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let ground = hw.energy_eigenstate(1);
+    let (xs, ps) = (grid(3.0, 13), grid(3.0, 13));
+    let wigner = ground.wigner(&xs, &ps, 0.0, STEP, 1.0);
+
+    for (row, &x) in wigner.iter().zip(&xs) {
+        for (&w, &p) in row.iter().zip(&ps) {
+            let expected = (-x * x - p * p).exp() / PI;
+            assert!(w > -1e-4, "W({x}, {p}) = {w} is negative");
+            assert!(
+                (w - expected).abs() < 2e-3,
+                "W({x}, {p}) = {w}, expected {expected}"
+            );
+        }
+    }
+
+    // The blob is centred at the origin, where it peaks
+    let peak = wigner[6][6];
+    assert!(wigner.iter().flatten().all(|&w| w <= peak));
+}
+
+#[test]
+fn harmonic_first_excited_state_is_negative_at_origin() {
+    // The first excited state has W(0, 0) = -1 / (pi hbar)
+    let hw = HarmonicWell::new(1.0, 1.0, 1.0, 8.0);
+    let excited = hw.energy_eigenstate(2);
+    let wigner = excited.wigner(&[0.0], &[0.0], 0.0, STEP, 1.0);
+    assert!(
+        (wigner[0][0] + 1.0 / PI).abs() < 2e-3,
+        "W(0, 0) = {}",
+        wigner[0][0]
+    );
+}